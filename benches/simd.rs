@@ -0,0 +1,84 @@
+// Compares the `Vector4f`, `Vector3f` and `Matrix4f` operators against plain
+// scalar code written out below, which matches the crate's portable path.
+//
+//     cargo bench --bench simd                  # portable on both sides
+//     cargo bench --bench simd --features simd  # SSE2 against scalar
+//
+// Criterion baselines compare the two builds directly:
+//
+//     cargo bench --bench simd -- --save-baseline portable
+//     cargo bench --bench simd --features simd -- --baseline portable
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ember_math::{Matrix4f, Rad, Vector3f, Vector4f};
+
+fn scalar_add4(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]]
+}
+
+fn scalar_dot4(a: [f32; 4], b: [f32; 4]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3]
+}
+
+fn scalar_mat4_mul(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let mut out = [0.0; 16];
+    for i in 0..4 {
+        for j in 0..4 {
+            out[4 * i + j] = a[4 * i] * b[j] + a[4 * i + 1] * b[4 + j] + a[4 * i + 2] * b[8 + j] + a[4 * i + 3] * b[12 + j];
+        }
+    }
+    out
+}
+
+fn scalar_mat4_transform(m: &[f32; 16], v: [f32; 4]) -> [f32; 4] {
+    let mut out = [0.0; 4];
+    for (i, o) in out.iter_mut().enumerate() {
+        *o = m[4 * i] * v[0] + m[4 * i + 1] * v[1] + m[4 * i + 2] * v[2] + m[4 * i + 3] * v[3];
+    }
+    out
+}
+
+fn matrices() -> (Matrix4f, Matrix4f) {
+    let a = Matrix4f::from_translation(Vector3f::new(1.0, 2.0, 3.0)) * Matrix4f::from_angle_y(Rad(0.7));
+    let b = Matrix4f::from_scale(2.0) * Matrix4f::from_angle_x(Rad(-0.3));
+    (a, b)
+}
+
+fn bench_vector4(c: &mut Criterion) {
+    let a = Vector4f::new(1.0, -2.0, 3.5, 0.25);
+    let b = Vector4f::new(0.5, 4.0, -1.0, 2.0);
+
+    let mut g = c.benchmark_group("vector4f");
+    g.bench_function("add/ember", |bn| bn.iter(|| black_box(a) + black_box(b)));
+    g.bench_function("add/scalar", |bn| bn.iter(|| scalar_add4(black_box(a.to_array()), black_box(b.to_array()))));
+    g.bench_function("dot/ember", |bn| bn.iter(|| black_box(a).dot(black_box(b))));
+    g.bench_function("dot/scalar", |bn| bn.iter(|| scalar_dot4(black_box(a.to_array()), black_box(b.to_array()))));
+    g.bench_function("normalize/ember", |bn| bn.iter(|| black_box(a).normalize()));
+    g.finish();
+}
+
+fn bench_vector3(c: &mut Criterion) {
+    let a = Vector3f::new(1.0, -2.0, 3.5);
+    let b = Vector3f::new(0.5, 4.0, -1.0);
+
+    let mut g = c.benchmark_group("vector3f");
+    g.bench_function("add/ember", |bn| bn.iter(|| black_box(a) + black_box(b)));
+    g.bench_function("dot/ember", |bn| bn.iter(|| black_box(a).dot(black_box(b))));
+    g.bench_function("cross/ember", |bn| bn.iter(|| black_box(a).cross(black_box(b))));
+    g.finish();
+}
+
+fn bench_matrix4(c: &mut Criterion) {
+    let (a, b) = matrices();
+    let v = Vector4f::new(1.0, -2.0, 3.5, 1.0);
+
+    let mut g = c.benchmark_group("matrix4f");
+    g.bench_function("mul/ember", |bn| bn.iter(|| black_box(a) * black_box(b)));
+    g.bench_function("mul/scalar", |bn| bn.iter(|| scalar_mat4_mul(black_box(&a.data), black_box(&b.data))));
+    g.bench_function("transform/ember", |bn| bn.iter(|| black_box(a).transform(black_box(v))));
+    g.bench_function("transform/scalar", |bn| bn.iter(|| scalar_mat4_transform(black_box(&a.data), black_box(v.to_array()))));
+    g.finish();
+}
+
+criterion_group!(benches, bench_vector4, bench_vector3, bench_matrix4);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+// Generates the swizzle methods (`xy()`, `zyx()`, `wwww()`, ...) included by
+// `src/core/swizzle.rs`: every combination of two, three and four components
+// of each vector type.
+fn main() {
+    let vectors: [(&str, &[&str]); 3] = [
+        ("Vector2", &["x", "y"]),
+        ("Vector3", &["x", "y", "z"]),
+        ("Vector4", &["x", "y", "z", "w"]),
+    ];
+
+    let mut out = String::new();
+    for (name, fields) in vectors.iter() {
+        writeln!(out, "impl<T: Scalar> {}<T> {{", name).unwrap();
+        for len in 2..=4 {
+            let mut combos: Vec<Vec<&str>> = vec![vec![]];
+            for _ in 0..len {
+                combos = combos
+                    .iter()
+                    .flat_map(|c| fields.iter().map(move |f| {
+                        let mut next = c.clone();
+                        next.push(f);
+                        next
+                    }))
+                    .collect();
+            }
+            for combo in combos {
+                let args: Vec<String> = combo.iter().map(|f| format!("self.{}", f)).collect();
+                writeln!(
+                    out,
+                    "    pub fn {}(&self) -> Vector{}<T> {{\n        Vector{}::new({})\n    }}\n",
+                    combo.concat(),
+                    len,
+                    len,
+                    args.join(", ")
+                ).unwrap();
+            }
+        }
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("swizzles.rs");
+    fs::write(dest, out).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
\ No newline at end of file
@@ -0,0 +1,237 @@
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::vector3f::Vector3;
+use crate::core::vector2f::Vector2;
+use crate::core::matrix4f::Matrix4;
+use crate::core::matrix3f::Matrix3;
+use crate::core::approx::ApproxEq;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// Axis-aligned box spanning `min` to `max` inclusive. A box with any
+/// `min` component greater than the matching `max` component is empty;
+/// `Aabb::empty()` is the identity for `union`.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Aabb<T: Scalar>{
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+pub type Aabbf = Aabb<f32>;
+pub type Aabbd = Aabb<f64>;
+
+/// The 2D counterpart of `Aabb`.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Aabb2<T: Scalar>{
+    pub min: Vector2<T>,
+    pub max: Vector2<T>,
+}
+
+pub type Aabb2f = Aabb2<f32>;
+pub type Aabb2d = Aabb2<f64>;
+
+// Operations shared by `Aabb` and `Aabb2`.
+macro_rules! impl_aabb {
+    ($name:ident, $vec:ident) => {
+        impl<T: Float> Default for $name<T> {
+            fn default() -> Self {
+                $name::empty()
+            }
+        }
+
+        impl<T: Float> $name<T> {
+            pub fn new(min: $vec<T>, max: $vec<T>) -> Self {
+                $name{
+                    min,
+                    max,
+                }
+            }
+
+            /// The box containing nothing, with `min` at +inf and `max` at -inf.
+            pub fn empty() -> Self {
+                $name{
+                    min: $vec::one() * T::INFINITY,
+                    max: $vec::one() * T::NEG_INFINITY,
+                }
+            }
+
+            /// The box containing everything, with `min` at -inf and `max`
+            /// at +inf.
+            pub fn unbounded() -> Self {
+                $name{
+                    min: $vec::one() * T::NEG_INFINITY,
+                    max: $vec::one() * T::INFINITY,
+                }
+            }
+
+            pub fn from_center_extents(center: $vec<T>, extents: $vec<T>) -> Self {
+                $name::new(center - extents, center + extents)
+            }
+
+            /// The smallest box containing every point, or an empty box if
+            /// there are none.
+            pub fn from_points(points: &[$vec<T>]) -> Self {
+                points.iter().fold($name::empty(), |b, &p| b.expand_to_point(p))
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.min.iter().zip(self.max.iter()).any(|(lo, hi)| lo > hi)
+            }
+
+            /// Whether the box is non-empty and reaches infinity along some
+            /// axis.
+            pub fn is_unbounded(&self) -> bool {
+                !self.is_empty() && !self.min.iter().chain(self.max.iter()).all(|v| v.is_finite())
+            }
+
+            pub fn center(&self) -> $vec<T> {
+                (self.min + self.max) * T::HALF
+            }
+
+            /// Half the size along each axis.
+            pub fn extents(&self) -> $vec<T> {
+                (self.max - self.min) * T::HALF
+            }
+
+            pub fn size(&self) -> $vec<T> {
+                self.max - self.min
+            }
+
+            pub fn union(&self, other: &Self) -> Self {
+                $name::new(self.min.min(other.min), self.max.max(other.max))
+            }
+
+            /// The overlap of the two boxes, or `None` if they are disjoint.
+            /// Boxes that only touch intersect in a flat box.
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                let b = $name::new(self.min.max(other.min), self.max.min(other.max));
+                if b.is_empty() { None } else { Some(b) }
+            }
+
+            pub fn intersects(&self, other: &Self) -> bool {
+                self.intersection(other).is_some()
+            }
+
+            pub fn contains_point(&self, p: $vec<T>) -> bool {
+                self.min.iter().zip(p.iter()).all(|(lo, v)| lo <= v)
+                    && p.iter().zip(self.max.iter()).all(|(v, hi)| v <= hi)
+            }
+
+            /// Whether `other` lies entirely inside `self`. Every box
+            /// contains the empty box.
+            pub fn contains(&self, other: &Self) -> bool {
+                other.is_empty() || (self.contains_point(other.min) && self.contains_point(other.max))
+            }
+
+            /// Grows the box by `margin` on every side; a negative margin
+            /// shrinks it.
+            pub fn expand(&self, margin: T) -> Self {
+                let m = $vec::one() * margin;
+                $name::new(self.min - m, self.max + m)
+            }
+
+            /// The smallest box containing `self` and `p`.
+            pub fn expand_to_point(&self, p: $vec<T>) -> Self {
+                $name::new(self.min.min(p), self.max.max(p))
+            }
+        }
+
+        impl_approx_eq!($name { min, max });
+    };
+}
+
+impl_aabb!(Aabb, Vector3);
+impl_aabb!(Aabb2, Vector2);
+
+impl<T: Float> Aabb<T> {
+    pub fn corners(&self) -> [Vector3<T>; 8] {
+        let (a, b) = (self.min, self.max);
+        [
+            Vector3::new(a.x, a.y, a.z),
+            Vector3::new(b.x, a.y, a.z),
+            Vector3::new(a.x, b.y, a.z),
+            Vector3::new(b.x, b.y, a.z),
+            Vector3::new(a.x, a.y, b.z),
+            Vector3::new(b.x, a.y, b.z),
+            Vector3::new(a.x, b.y, b.z),
+            Vector3::new(b.x, b.y, b.z),
+        ]
+    }
+
+    /// The smallest box containing the transformed box. Affine matrices
+    /// move the center and bound the extents by the absolute linear part;
+    /// projective ones bound the projected corners. A projective image is
+    /// only bounded while the whole box stays on one side of the `w = 0`
+    /// plane, so a box that reaches it, such as one straddling a
+    /// perspective camera, yields the unbounded box. An unbounded input
+    /// also yields the unbounded box.
+    pub fn transform(&self, m: &Matrix4<T>) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+        if self.is_unbounded() {
+            return Aabb::unbounded();
+        }
+        let d = &m.data;
+        if d[12] == T::ZERO && d[13] == T::ZERO && d[14] == T::ZERO && d[15] == T::ONE {
+            let linear = Matrix3::from_array(m.to_matrix3().data.map(|v| v.abs()));
+            Aabb::from_center_extents(m.transform_point3(self.center()), linear.transform(self.extents()))
+        } else {
+            let clip = self.corners().map(|c| m.transform(c.extend(T::ONE)));
+            if !clip.iter().all(|c| c.w > T::ZERO) && !clip.iter().all(|c| c.w < T::ZERO) {
+                return Aabb::unbounded();
+            }
+            clip.iter().fold(Aabb::empty(), |b, &c| b.expand_to_point(c.truncate() / c.w))
+        }
+    }
+}
+
+impl<T: Float> Aabb2<T> {
+    pub fn corners(&self) -> [Vector2<T>; 4] {
+        let (a, b) = (self.min, self.max);
+        [
+            Vector2::new(a.x, a.y),
+            Vector2::new(b.x, a.y),
+            Vector2::new(a.x, b.y),
+            Vector2::new(b.x, b.y),
+        ]
+    }
+
+    /// `Aabb::transform` for a 2D homogeneous transform, unbounded in the
+    /// same way when the box reaches the `w = 0` line or is unbounded.
+    pub fn transform(&self, m: &Matrix3<T>) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+        if self.is_unbounded() {
+            return Aabb2::unbounded();
+        }
+        let d = &m.data;
+        if d[6] == T::ZERO && d[7] == T::ZERO && d[8] == T::ONE {
+            let c = self.center();
+            let e = self.extents();
+            let center = Vector2::new(d[0]*c.x + d[1]*c.y + d[2], d[3]*c.x + d[4]*c.y + d[5]);
+            let extents = Vector2::new(
+                d[0].abs()*e.x + d[1].abs()*e.y,
+                d[3].abs()*e.x + d[4].abs()*e.y
+            );
+            Aabb2::from_center_extents(center, extents)
+        } else {
+            let clip = self.corners().map(|c| m.transform(c.extend(T::ONE)));
+            if !clip.iter().all(|c| c.z > T::ZERO) && !clip.iter().all(|c| c.z < T::ZERO) {
+                return Aabb2::unbounded();
+            }
+            clip.iter().fold(Aabb2::empty(), |b, &c| b.expand_to_point(c.truncate() / c.z))
+        }
+    }
+}
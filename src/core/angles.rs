@@ -1,11 +1,296 @@
-use std::f32::consts::PI;
+use std::ops::{
+    Add,
+    AddAssign,
+    Sub,
+    SubAssign,
+    Mul,
+    MulAssign,
+    Div,
+    DivAssign,
+    Neg,
+    Rem,
+};
 
-pub fn deg(rad: f32) -> f32 {
-    let rtd = 180.0 / PI;
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+pub fn deg<T: Float>(rad: T) -> T {
+    let rtd = T::from_f64(180.0) / T::PI;
     rad * rtd
 }
 
-pub fn rad(deg: f32) -> f32 {
-    let dtr = PI / 180.0;
+pub fn rad<T: Float>(deg: T) -> T {
+    let dtr = T::PI / T::from_f64(180.0);
     deg * dtr
-}
\ No newline at end of file
+}
+
+/// An angle in radians. Rotation constructors take `impl Into<Rad<T>>`, so
+/// they accept a `Rad` or a `Deg`; a bare float must be wrapped in `Rad`
+/// first.
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(transparent)]
+pub struct Rad<T: Scalar>(pub T);
+
+/// An angle in degrees.
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(transparent)]
+pub struct Deg<T: Scalar>(pub T);
+
+pub type Radf = Rad<f32>;
+pub type Radd = Rad<f64>;
+pub type Degf = Deg<f32>;
+pub type Degd = Deg<f64>;
+
+impl<T: Float> Rad<T> {
+    pub fn turn() -> Self {
+        Rad(T::TWO * T::PI)
+    }
+
+    pub fn half_turn() -> Self {
+        Rad(T::PI)
+    }
+
+    pub fn to_deg(self) -> Deg<T> {
+        Deg(deg(self.0))
+    }
+
+    pub fn sin(self) -> T {
+        self.0.sin()
+    }
+
+    pub fn cos(self) -> T {
+        self.0.cos()
+    }
+
+    pub fn tan(self) -> T {
+        self.0.tan()
+    }
+
+    pub fn sin_cos(self) -> (T, T) {
+        self.0.sin_cos()
+    }
+
+    pub fn asin(v: T) -> Self {
+        Rad(v.asin())
+    }
+
+    pub fn acos(v: T) -> Self {
+        Rad(v.acos())
+    }
+
+    pub fn atan(v: T) -> Self {
+        Rad(v.atan())
+    }
+
+    pub fn atan2(y: T, x: T) -> Self {
+        Rad(y.atan2(x))
+    }
+}
+
+impl<T: Float> Deg<T> {
+    pub fn turn() -> Self {
+        Deg(T::from_f64(360.0))
+    }
+
+    pub fn half_turn() -> Self {
+        Deg(T::from_f64(180.0))
+    }
+
+    pub fn to_rad(self) -> Rad<T> {
+        Rad(rad(self.0))
+    }
+
+    pub fn sin(self) -> T {
+        self.to_rad().sin()
+    }
+
+    pub fn cos(self) -> T {
+        self.to_rad().cos()
+    }
+
+    pub fn tan(self) -> T {
+        self.to_rad().tan()
+    }
+
+    pub fn sin_cos(self) -> (T, T) {
+        self.to_rad().sin_cos()
+    }
+
+    pub fn asin(v: T) -> Self {
+        Rad::asin(v).to_deg()
+    }
+
+    pub fn acos(v: T) -> Self {
+        Rad::acos(v).to_deg()
+    }
+
+    pub fn atan(v: T) -> Self {
+        Rad::atan(v).to_deg()
+    }
+
+    pub fn atan2(y: T, x: T) -> Self {
+        Rad::atan2(y, x).to_deg()
+    }
+}
+
+// Operations shared by `Rad` and `Deg`, which differ only in the size of a
+// full turn.
+macro_rules! impl_angle {
+    ($name:ident) => {
+        impl<T: Float> $name<T> {
+            /// Wraps into `[0, turn)`.
+            pub fn normalize(self) -> Self {
+                let turn = Self::turn().0;
+                let mut r = self.0 % turn;
+                if r < T::ZERO {
+                    r += turn;
+                }
+                // `r + turn` rounds up to `turn` when `r` is tiny and negative.
+                if r >= turn {
+                    r = T::ZERO;
+                }
+                $name(r)
+            }
+
+            /// Wraps into `(-half_turn, half_turn]`.
+            pub fn normalize_signed(self) -> Self {
+                let r = self.normalize().0;
+                if r > Self::half_turn().0 { $name(r - Self::turn().0) } else { $name(r) }
+            }
+
+            /// The signed angle with the smallest magnitude that rotates
+            /// `self` onto `other`, in `(-half_turn, half_turn]`.
+            pub fn shortest_difference(self, other: Self) -> Self {
+                (other - self).normalize_signed()
+            }
+        }
+
+        impl<T: Scalar> Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $name(self.0 + other.0)
+            }
+        }
+
+        impl<T: Scalar> AddAssign for $name<T> {
+            fn add_assign(&mut self, other: Self) {
+                self.0 += other.0;
+            }
+        }
+
+        impl<T: Scalar> Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $name(self.0 - other.0)
+            }
+        }
+
+        impl<T: Scalar> SubAssign for $name<T> {
+            fn sub_assign(&mut self, other: Self) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl<T: Float> Neg for $name<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $name(-self.0)
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, s: T) -> Self {
+                $name(self.0 * s)
+            }
+        }
+
+        impl<T: Scalar> MulAssign<T> for $name<T> {
+            fn mul_assign(&mut self, s: T) {
+                self.0 *= s;
+            }
+        }
+
+        impl<T: Scalar> Div<T> for $name<T> {
+            type Output = Self;
+
+            fn div(self, s: T) -> Self {
+                $name(self.0 / s)
+            }
+        }
+
+        impl<T: Scalar> DivAssign<T> for $name<T> {
+            fn div_assign(&mut self, s: T) {
+                self.0 /= s;
+            }
+        }
+
+        /// Ratio of two angles.
+        impl<T: Scalar> Div for $name<T> {
+            type Output = T;
+
+            fn div(self, other: Self) -> T {
+                self.0 / other.0
+            }
+        }
+
+        impl<T: Scalar> Rem for $name<T> {
+            type Output = Self;
+
+            fn rem(self, other: Self) -> Self {
+                $name(self.0 % other.0)
+            }
+        }
+
+        impl Mul<$name<f32>> for f32 {
+            type Output = $name<f32>;
+
+            fn mul(self, a: $name<f32>) -> $name<f32> {
+                $name(self * a.0)
+            }
+        }
+
+        impl Mul<$name<f64>> for f64 {
+            type Output = $name<f64>;
+
+            fn mul(self, a: $name<f64>) -> $name<f64> {
+                $name(self * a.0)
+            }
+        }
+
+        impl From<$name<f32>> for $name<f64> {
+            fn from(a: $name<f32>) -> $name<f64> {
+                $name(f64::from(a.0))
+            }
+        }
+    };
+}
+
+impl_angle!(Rad);
+impl_angle!(Deg);
+
+impl<T: Float> From<Deg<T>> for Rad<T> {
+    fn from(d: Deg<T>) -> Rad<T> {
+        d.to_rad()
+    }
+}
+
+impl<T: Float> From<Rad<T>> for Deg<T> {
+    fn from(r: Rad<T>) -> Deg<T> {
+        r.to_deg()
+    }
+}
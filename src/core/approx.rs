@@ -0,0 +1,194 @@
+/// Approximate equality for floats and every float-valued type in `core`.
+///
+/// Three modes are available:
+/// - `abs_diff_eq`: `|a - b| <= epsilon`.
+/// - `relative_eq`: `|a - b| <= max(|a|, |b|) * max_relative`, with `epsilon`
+///   as an absolute floor for values near zero.
+/// - `ulps_eq`: `a` and `b` are at most `max_ulps` representable floats
+///   apart, again with `epsilon` as an absolute floor.
+///
+/// Composite types compare component-wise and are equal only if every
+/// component is.
+pub trait ApproxEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+    fn default_max_relative() -> Self::Epsilon;
+    fn default_max_ulps() -> u32;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    /// `relative_eq` with the default tolerances.
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, Self::default_epsilon(), Self::default_max_relative())
+    }
+}
+
+macro_rules! impl_approx_eq_float {
+    ($($t:ident),*) => {
+        $(
+            impl ApproxEq for $t {
+                type Epsilon = $t;
+
+                fn default_epsilon() -> $t {
+                    <$t>::EPSILON
+                }
+
+                fn default_max_relative() -> $t {
+                    <$t>::EPSILON
+                }
+
+                fn default_max_ulps() -> u32 {
+                    4
+                }
+
+                fn abs_diff_eq(&self, other: &$t, epsilon: $t) -> bool {
+                    (self - other).abs() <= epsilon
+                }
+
+                fn relative_eq(&self, other: &$t, epsilon: $t, max_relative: $t) -> bool {
+                    // Also handles infinities of the same sign.
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+
+                    let diff = (self - other).abs();
+                    if diff <= epsilon {
+                        return true;
+                    }
+                    diff <= self.abs().max(other.abs()) * max_relative
+                }
+
+                fn ulps_eq(&self, other: &$t, epsilon: $t, max_ulps: u32) -> bool {
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true;
+                    }
+                    if self.is_nan() || other.is_nan() || self.signum() != other.signum() {
+                        return false;
+                    }
+
+                    // Floats of the same sign order the same way as their bits.
+                    let a = self.to_bits() as i128;
+                    let b = other.to_bits() as i128;
+                    (a - b).unsigned_abs() <= max_ulps as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_float!(f32, f64);
+
+#[doc(hidden)]
+pub fn __default_epsilon<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::default_epsilon()
+}
+
+#[doc(hidden)]
+pub fn __default_max_relative<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::default_max_relative()
+}
+
+#[doc(hidden)]
+pub fn __default_max_ulps<T: ApproxEq>(_: &T) -> u32 {
+    T::default_max_ulps()
+}
+
+/// Asserts that two values are equal with `ApproxEq::relative_eq`, printing
+/// both on failure. `epsilon` and `max_relative` fall back to the type's
+/// defaults when omitted.
+///
+/// ```
+/// use ember_math::{assert_approx_eq, Vector3f};
+///
+/// let v = Vector3f::new(0.1, 0.2, 0.3) * 3.0;
+/// assert_approx_eq!(v, Vector3f::new(0.3, 0.6, 0.9));
+/// assert_approx_eq!(v.x, 0.3, epsilon = 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::__assert_approx!(relative_eq, $a, $b, |a| (
+            $crate::core::approx::__default_epsilon(a),
+            $crate::core::approx::__default_max_relative(a)
+        ))
+    };
+    ($a:expr, $b:expr, epsilon = $eps:expr $(,)?) => {
+        $crate::__assert_approx!(relative_eq, $a, $b, |a| (
+            $eps,
+            $crate::core::approx::__default_max_relative(a)
+        ))
+    };
+    ($a:expr, $b:expr, max_relative = $rel:expr $(,)?) => {
+        $crate::__assert_approx!(relative_eq, $a, $b, |a| (
+            $crate::core::approx::__default_epsilon(a),
+            $rel
+        ))
+    };
+    ($a:expr, $b:expr, epsilon = $eps:expr, max_relative = $rel:expr $(,)?) => {
+        $crate::__assert_approx!(relative_eq, $a, $b, |_a| ($eps, $rel))
+    };
+}
+
+/// Asserts that two values are equal with `ApproxEq::abs_diff_eq`.
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::__assert_approx!(abs_diff_eq, $a, $b, |a| (
+            $crate::core::approx::__default_epsilon(a),
+        ))
+    };
+    ($a:expr, $b:expr, epsilon = $eps:expr $(,)?) => {
+        $crate::__assert_approx!(abs_diff_eq, $a, $b, |_a| ($eps,))
+    };
+}
+
+/// Asserts that two values are equal with `ApproxEq::ulps_eq`.
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::__assert_approx!(ulps_eq, $a, $b, |a| (
+            $crate::core::approx::__default_epsilon(a),
+            $crate::core::approx::__default_max_ulps(a)
+        ))
+    };
+    ($a:expr, $b:expr, max_ulps = $ulps:expr $(,)?) => {
+        $crate::__assert_approx!(ulps_eq, $a, $b, |a| (
+            $crate::core::approx::__default_epsilon(a),
+            $ulps
+        ))
+    };
+    ($a:expr, $b:expr, epsilon = $eps:expr, max_ulps = $ulps:expr $(,)?) => {
+        $crate::__assert_approx!(ulps_eq, $a, $b, |_a| ($eps, $ulps))
+    };
+}
+
+// The trailing `|x| (...)` binds the left value to `x` before the tolerances
+// are evaluated, so defaults can be looked up from its type without
+// evaluating `$a` twice.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx {
+    ($method:ident, $a:expr, $b:expr, |$x:ident| ($($arg:expr),* $(,)?)) => {
+        match (&$a, &$b) {
+            (left, right) => {
+                let $x = left;
+                if !$crate::core::approx::ApproxEq::$method(left, right, $($arg),*) {
+                    panic!(
+                        "assertion failed: `{}({}, {})`\n  left: `{:?}`\n right: `{:?}`",
+                        stringify!($method),
+                        stringify!($a),
+                        stringify!($b),
+                        left,
+                        right
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,211 @@
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::vector3f::Vector3;
+use crate::core::segment::Segment;
+use crate::core::sphere::Sphere;
+use crate::core::aabb::Aabb;
+use crate::core::approx::ApproxEq;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// Solid of points within `radius` of `segment`: a cylinder capped with
+/// two half spheres.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Capsule<T: Scalar>{
+    pub segment: Segment<T>,
+    pub radius: T,
+}
+
+pub type Capsulef = Capsule<f32>;
+pub type Capsuled = Capsule<f64>;
+
+/// How two overlapping shapes touch. Moving the second shape by
+/// `normal * depth`, or the first by the opposite, separates them.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+pub struct Contact<T: Scalar>{
+    /// Unit vector pointing from the first shape toward the second.
+    pub normal: Vector3<T>,
+    /// Non-negative overlap along `normal`.
+    pub depth: T,
+}
+
+impl<T: Float> Capsule<T>{
+    pub fn new(start: Vector3<T>, end: Vector3<T>, radius: T) -> Self {
+        Capsule{
+            segment: Segment::new(start, end),
+            radius,
+        }
+    }
+
+    pub fn contains_point(&self, p: Vector3<T>) -> bool {
+        self.segment.distance_squared_to_point(p) <= self.radius * self.radius
+    }
+
+    pub fn to_aabb(&self) -> Aabb<T> {
+        let r = Vector3::one() * self.radius;
+        let (a, b) = (self.segment.start, self.segment.end);
+        Aabb::new(a.min(b) - r, a.max(b) + r)
+    }
+
+    /// The contact with another capsule, or `None` if they are apart.
+    pub fn contact(&self, other: &Self) -> Option<Contact<T>> {
+        let (p, q) = self.segment.closest_points(&other.segment);
+        let fallback = || {
+            let n = self.segment.direction().cross(other.segment.direction());
+            if n.magnitude() > T::tolerance() { n.normalize() } else { perpendicular(self.segment.direction()) }
+        };
+        contact_between(p, q, self.radius + other.radius, fallback)
+    }
+
+    pub fn contact_sphere(&self, sphere: &Sphere<T>) -> Option<Contact<T>> {
+        let p = self.segment.closest_point(sphere.center);
+        contact_between(p, sphere.center, self.radius + sphere.radius, || perpendicular(self.segment.direction()))
+    }
+
+    /// When the capsule's segment passes through the box, the contact
+    /// pushes the capsule out through whichever face needs the smallest
+    /// move.
+    pub fn contact_aabb(&self, aabb: &Aabb<T>) -> Option<Contact<T>> {
+        if aabb.is_empty() {
+            return None;
+        }
+        let p = self.segment.at(closest_parameter_to_aabb(&self.segment, aabb));
+        let q = p.max(aabb.min).min(aabb.max);
+        let dist2 = (q - p).magnitude_squared();
+        if dist2 > self.radius * self.radius {
+            return None;
+        }
+        if dist2 > T::ZERO {
+            let dist = dist2.sqrt();
+            return Some(Contact{
+                normal: (q - p) / dist,
+                depth: self.radius - dist,
+            });
+        }
+
+        let (a, b) = (self.segment.start.to_array(), self.segment.end.to_array());
+        let (lo, hi) = (aabb.min.to_array(), aabb.max.to_array());
+        let mut best = Contact{
+            normal: Vector3::zero(),
+            depth: T::INFINITY,
+        };
+        for i in 0..3 {
+            let mut normal = [T::ZERO; 3];
+            // out through the max face, then the min face
+            let up = hi[i] + self.radius - a[i].partial_min(b[i]);
+            let down = a[i].partial_max(b[i]) - lo[i] + self.radius;
+            for (depth, sign) in [(up, -T::ONE), (down, T::ONE)] {
+                if depth < best.depth {
+                    normal[i] = sign;
+                    best = Contact{
+                        normal: Vector3::from(normal),
+                        depth,
+                    };
+                }
+            }
+        }
+        Some(best)
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        let (p, q) = self.segment.closest_points(&other.segment);
+        let r = self.radius + other.radius;
+        (q - p).magnitude_squared() <= r * r
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        let r = self.radius + sphere.radius;
+        self.segment.distance_squared_to_point(sphere.center) <= r * r
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        self.contact_aabb(aabb).is_some()
+    }
+}
+
+// The contact between balls of total radius `radius` around `p` and `q`.
+// `fallback` picks the normal when the centers coincide.
+fn contact_between<T: Float>(p: Vector3<T>, q: Vector3<T>, radius: T, fallback: impl Fn() -> Vector3<T>) -> Option<Contact<T>> {
+    let dist2 = (q - p).magnitude_squared();
+    if dist2 > radius * radius {
+        return None;
+    }
+    let dist = dist2.sqrt();
+    Some(Contact{
+        normal: if dist > T::ZERO { (q - p) / dist } else { fallback() },
+        depth: radius - dist,
+    })
+}
+
+// A unit vector perpendicular to `v`, or +y if `v` is zero.
+fn perpendicular<T: Float>(v: Vector3<T>) -> Vector3<T> {
+    let a = v.abs();
+    let axis = if a.x <= a.y && a.x <= a.z {
+        Vector3::new(T::ONE, T::ZERO, T::ZERO)
+    } else if a.y <= a.z {
+        Vector3::new(T::ZERO, T::ONE, T::ZERO)
+    } else {
+        Vector3::new(T::ZERO, T::ZERO, T::ONE)
+    };
+    let n = v.cross(axis);
+    if n.magnitude() > T::ZERO { n.normalize() } else { Vector3::new(T::ZERO, T::ONE, T::ZERO) }
+}
+
+// The parameter of the point on `segment` closest to `aabb`. The squared
+// distance is a quadratic in the parameter between the points where the
+// segment crosses a face plane, so each piece is minimized exactly.
+fn closest_parameter_to_aabb<T: Float>(segment: &Segment<T>, aabb: &Aabb<T>) -> T {
+    let s = segment.start.to_array();
+    let d = segment.direction().to_array();
+    let (lo, hi) = (aabb.min.to_array(), aabb.max.to_array());
+
+    let mut breaks = [T::ZERO; 8];
+    breaks[1] = T::ONE;
+    let mut count = 2;
+    for i in 0..3 {
+        if d[i] == T::ZERO {
+            continue;
+        }
+        for bound in [lo[i], hi[i]] {
+            let t = (bound - s[i]) / d[i];
+            if t > T::ZERO && t < T::ONE {
+                breaks[count] = t;
+                count += 1;
+            }
+        }
+    }
+    let breaks = &mut breaks[..count];
+    breaks.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut best = (T::INFINITY, T::ZERO);
+    for pair in breaks.windows(2) {
+        let (t0, t1) = (pair[0], pair[1]);
+        let mid = (t0 + t1) * T::HALF;
+        // the axes outside their slab on this piece, and the face beyond
+        let (mut num, mut den) = (T::ZERO, T::ZERO);
+        for i in 0..3 {
+            let x = s[i] + d[i] * mid;
+            let bound = if x < lo[i] { lo[i] } else if x > hi[i] { hi[i] } else { continue };
+            num += d[i] * (s[i] - bound);
+            den += d[i] * d[i];
+        }
+        let t = if den > T::ZERO { (-num / den).clamp(t0, t1) } else { t0 };
+        let p = segment.at(t);
+        let dist2 = (p.max(aabb.min).min(aabb.max) - p).magnitude_squared();
+        if dist2 < best.0 {
+            best = (dist2, t);
+        }
+    }
+    best.1
+}
+
+impl_approx_eq!(Capsule { segment, radius });
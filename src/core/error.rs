@@ -0,0 +1,32 @@
+use std::fmt;
+
+/// Default tolerance used to decide whether a determinant, length or
+/// projection extent is too close to zero to divide by. Lengths and extents
+/// compare against it directly; determinants compare against it scaled by
+/// the product of the matrix's row lengths.
+pub const DEFAULT_TOLERANCE: f32 = 1e-6;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MathError {
+    /// The matrix determinant is within tolerance of zero.
+    SingularMatrix,
+    /// A vector or quaternion with (near) zero length cannot be normalized.
+    ZeroLengthVector,
+    /// The projection or view parameters describe an empty or infinite volume.
+    DegenerateProjection,
+    /// The points are collinear or coincident, so they do not span the shape.
+    DegenerateGeometry,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::SingularMatrix => write!(f, "matrix is singular"),
+            MathError::ZeroLengthVector => write!(f, "cannot normalize a zero-length vector"),
+            MathError::DegenerateProjection => write!(f, "projection parameters are degenerate"),
+            MathError::DegenerateGeometry => write!(f, "points do not span the shape"),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
@@ -0,0 +1,82 @@
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// Order of the three rotations in a set of Euler angles.
+///
+/// Intrinsic orders (`XYZ`, ...) rotate about the axes of the rotating frame:
+/// `XYZ` is a rotation about x, then about the new y, then about the new z,
+/// i.e. `Rx * Ry * Rz`. Extrinsic orders (`XYZEx`, ...) rotate about the fixed
+/// world axes, so `XYZEx` is `Rz * Ry * Rx` and equals intrinsic `ZYX` with
+/// the angles reversed. Angles are always given in the order the axes are
+/// named.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect, FromReflect)]
+pub enum EulerRot {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYZEx,
+    XZYEx,
+    YXZEx,
+    YZXEx,
+    ZXYEx,
+    ZYXEx,
+}
+
+impl EulerRot {
+    pub const ALL: [EulerRot; 12] = [
+        EulerRot::XYZ, EulerRot::XZY, EulerRot::YXZ, EulerRot::YZX, EulerRot::ZXY, EulerRot::ZYX,
+        EulerRot::XYZEx, EulerRot::XZYEx, EulerRot::YXZEx, EulerRot::YZXEx, EulerRot::ZXYEx, EulerRot::ZYXEx,
+    ];
+
+    pub fn is_extrinsic(self) -> bool {
+        matches!(
+            self,
+            EulerRot::XYZEx | EulerRot::XZYEx | EulerRot::YXZEx | EulerRot::YZXEx | EulerRot::ZXYEx | EulerRot::ZYXEx
+        )
+    }
+
+    /// The same rotation sequence seen from the other kind of frame, with the
+    /// angles in reverse order.
+    pub fn reversed(self) -> EulerRot {
+        match self {
+            EulerRot::XYZ => EulerRot::ZYXEx,
+            EulerRot::XZY => EulerRot::YZXEx,
+            EulerRot::YXZ => EulerRot::ZXYEx,
+            EulerRot::YZX => EulerRot::XZYEx,
+            EulerRot::ZXY => EulerRot::YXZEx,
+            EulerRot::ZYX => EulerRot::XYZEx,
+            EulerRot::XYZEx => EulerRot::ZYX,
+            EulerRot::XZYEx => EulerRot::YZX,
+            EulerRot::YXZEx => EulerRot::ZXY,
+            EulerRot::YZXEx => EulerRot::XZY,
+            EulerRot::ZXYEx => EulerRot::YXZ,
+            EulerRot::ZYXEx => EulerRot::XYZ,
+        }
+    }
+
+    /// Axis indices of the equivalent intrinsic order, left to right.
+    pub(crate) fn intrinsic_axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerRot::XYZ | EulerRot::ZYXEx => (0, 1, 2),
+            EulerRot::XZY | EulerRot::YZXEx => (0, 2, 1),
+            EulerRot::YXZ | EulerRot::ZXYEx => (1, 0, 2),
+            EulerRot::YZX | EulerRot::XZYEx => (1, 2, 0),
+            EulerRot::ZXY | EulerRot::YXZEx => (2, 0, 1),
+            EulerRot::ZYX | EulerRot::XYZEx => (2, 1, 0),
+        }
+    }
+
+    /// Whether the intrinsic axes are an even permutation of x, y, z.
+    pub(crate) fn is_cyclic(self) -> bool {
+        matches!(self.intrinsic_axes(), (0, 1, 2) | (1, 2, 0) | (2, 0, 1))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::vector4f::Vector4;
+use crate::core::vector3f::Vector3;
+use crate::core::matrix4f::Matrix4;
+use crate::core::aabb::Aabb;
+use crate::core::plane::Plane;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// Result of testing a volume against a `Frustum`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect, FromReflect)]
+pub enum Containment {
+    Outside,
+    Intersecting,
+    Inside,
+}
+
+/// The six clipping planes of a view-projection matrix, in the order left,
+/// right, bottom, top, near, far. Every normal points into the frustum, so
+/// a point is inside when its signed distance to each plane is
+/// non-negative. A reversed-Z matrix must be read with
+/// `from_matrix_reversed_z` to keep that order.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+pub struct Frustum<T: Scalar>{
+    pub planes: [Plane<T>; 6],
+}
+
+pub type Frustumf = Frustum<f32>;
+pub type Frustumd = Frustum<f64>;
+
+impl<T: Float> Frustum<T>{
+    /// Extracts the planes of a view-projection matrix with OpenGL's -1..1
+    /// depth range, such as `perspective_gl(..) * look_at_rh(..)`, using the
+    /// Gribb–Hartmann method. Planes in world space come from a
+    /// view-projection matrix and planes in view space from a projection.
+    pub fn from_matrix(m: &Matrix4<T>) -> Self {
+        let [r0, r1, r2, r3] = Frustum::rows(m);
+        Frustum::from_planes([r3 + r0, r3 - r0, r3 + r1, r3 - r1, r3 + r2, r3 - r2])
+    }
+
+    /// `from_matrix` for a 0..1 depth range, as produced by `perspective_rh`
+    /// and the other suffixed projections.
+    pub fn from_matrix_zero_to_one(m: &Matrix4<T>) -> Self {
+        let [r0, r1, r2, r3] = Frustum::rows(m);
+        Frustum::from_planes([r3 + r0, r3 - r0, r3 + r1, r3 - r1, r2, r3 - r2])
+    }
+
+    /// `from_matrix` for a reversed 1..0 depth range, as produced by
+    /// `perspective_reversed_rh` and the other reversed projections. The near
+    /// plane is at depth 1, so it is taken from the opposite side of the
+    /// range than in `from_matrix_zero_to_one`.
+    pub fn from_matrix_reversed_z(m: &Matrix4<T>) -> Self {
+        let [r0, r1, r2, r3] = Frustum::rows(m);
+        Frustum::from_planes([r3 + r0, r3 - r0, r3 + r1, r3 - r1, r3 - r2, r2])
+    }
+
+    fn rows(m: &Matrix4<T>) -> [Vector4<T>; 4] {
+        let d = &m.data;
+        [0, 4, 8, 12].map(|i| Vector4::new(d[i], d[i + 1], d[i + 2], d[i + 3]))
+    }
+
+    // Normalizes each plane. A plane with a zero normal, such as the far
+    // plane of an infinite projection, is kept unnormalized; it accepts
+    // every point when its distance is positive.
+    fn from_planes(planes: [Vector4<T>; 6]) -> Self {
+        Frustum{
+            planes: planes.map(|p| {
+                Plane::try_new(p.truncate(), p.w).unwrap_or(Plane{
+                    normal: p.truncate(),
+                    distance: p.w,
+                })
+            }),
+        }
+    }
+
+    pub fn contains_point(&self, p: Vector3<T>) -> bool {
+        self.planes.iter().all(|plane| plane.signed_distance(p) >= T::ZERO)
+    }
+
+    pub fn classify_sphere(&self, center: Vector3<T>, radius: T) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in self.planes.iter() {
+            let d = plane.signed_distance(center);
+            if d < -radius {
+                return Containment::Outside;
+            }
+            if d < radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Tests the box corners nearest to and farthest from each plane. Like
+    /// most plane-based culling this is conservative: a box outside the
+    /// frustum but near one of its edges may be reported as intersecting.
+    pub fn classify_aabb(&self, aabb: &Aabb<T>) -> Containment {
+        if aabb.is_empty() {
+            return Containment::Outside;
+        }
+        let mut result = Containment::Inside;
+        for plane in self.planes.iter() {
+            let n = plane.normal;
+            // the corner farthest along the normal, and the one opposite
+            let pick = |a: T, b: T, s: T| if s >= T::ZERO { (b, a) } else { (a, b) };
+            let (px, nx) = pick(aabb.min.x, aabb.max.x, n.x);
+            let (py, ny) = pick(aabb.min.y, aabb.max.y, n.y);
+            let (pz, nz) = pick(aabb.min.z, aabb.max.z, n.z);
+            if plane.signed_distance(Vector3::new(px, py, pz)) < T::ZERO {
+                return Containment::Outside;
+            }
+            if plane.signed_distance(Vector3::new(nx, ny, nz)) < T::ZERO {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    pub fn intersects_sphere(&self, center: Vector3<T>, radius: T) -> bool {
+        self.classify_sphere(center, radius) != Containment::Outside
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        self.classify_aabb(aabb) != Containment::Outside
+    }
+}
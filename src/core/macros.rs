@@ -0,0 +1,245 @@
+// Reference forms of operators, forwarded to the by-value impls. Every type
+// in `core` is `Copy`, so these only exist so that `&a + &b`, `a * &b` and
+// `a += &b` compile alongside the by-value forms. The bracketed list holds
+// the impl's generic parameters and may be empty.
+
+macro_rules! forward_ref_unop {
+    (impl [$($g:tt)*] $imp:ident, $method:ident for $t:ty) => {
+        impl<$($g)*> $imp for &$t {
+            type Output = <$t as $imp>::Output;
+
+            fn $method(self) -> <$t as $imp>::Output {
+                $imp::$method(*self)
+            }
+        }
+    };
+}
+
+macro_rules! forward_ref_binop {
+    (impl [$($g:tt)*] $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<'a, $($g)*> $imp<$u> for &'a $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, other: $u) -> <$t as $imp<$u>>::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<'a, $($g)*> $imp<&'a $u> for $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, other: &'a $u) -> <$t as $imp<$u>>::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<'a, 'b, $($g)*> $imp<&'a $u> for &'b $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, other: &'a $u) -> <$t as $imp<$u>>::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+    };
+}
+
+macro_rules! forward_ref_op_assign {
+    (impl [$($g:tt)*] $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<'a, $($g)*> $imp<&'a $u> for $t {
+            fn $method(&mut self, other: &'a $u) {
+                $imp::$method(self, *other);
+            }
+        }
+    };
+}
+
+// `s * v` for each concrete scalar type; a blanket impl over `T` is not
+// allowed by the orphan rules.
+macro_rules! impl_scalar_lhs_mul {
+    ($name:ident $fields:tt: $($s:ty),*) => {
+        $(impl_scalar_lhs_mul!(@one $name $fields $s);)*
+    };
+    (@one $name:ident { $($field:ident),* } $s:ty) => {
+        impl Mul<$name<$s>> for $s {
+            type Output = $name<$s>;
+
+            fn mul(self, v: $name<$s>) -> $name<$s> {
+                $name {
+                    $($field: self * v.$field),*
+                }
+            }
+        }
+
+        forward_ref_binop!(impl [] Mul, mul for $s, $name<$s>);
+    };
+}
+
+macro_rules! impl_scalar_lhs_mul_matrix {
+    ($name:ident: $($s:ty),*) => {
+        $(
+            impl Mul<$name<$s>> for $s {
+                type Output = $name<$s>;
+
+                fn mul(self, m: $name<$s>) -> $name<$s> {
+                    m.scale(self)
+                }
+            }
+
+            forward_ref_binop!(impl [] Mul, mul for $s, $name<$s>);
+        )*
+    };
+}
+
+// Widening conversions that can never lose precision, such as `f32` to
+// `f64`. Narrowing conversions go through `cast`.
+macro_rules! impl_lossless_from {
+    ($name:ident $fields:tt: $($from:ty => $to:ty),*) => {
+        $(impl_lossless_from!(@one $name $fields $from => $to);)*
+    };
+    (@one $name:ident { $($field:ident),* } $from:ty => $to:ty) => {
+        impl From<$name<$from>> for $name<$to> {
+            fn from(v: $name<$from>) -> $name<$to> {
+                $name {
+                    $($field: <$to>::from(v.$field)),*
+                }
+            }
+        }
+    };
+}
+
+// `ApproxEq` for the float instantiations of a type, comparing either named
+// fields or every element of a `data` array. Fields may be scalars or other
+// `ApproxEq` types with the same epsilon, such as vectors.
+macro_rules! impl_approx_eq {
+    ($name:ident { $($field:ident),* }) => {
+        impl_approx_eq!(@impl $name, a, b,
+            abs_diff_eq(epsilon) => true $(&& a.$field.abs_diff_eq(&b.$field, epsilon))*,
+            relative_eq(epsilon, max_relative) => true $(&& a.$field.relative_eq(&b.$field, epsilon, max_relative))*,
+            ulps_eq(epsilon, max_ulps) => true $(&& a.$field.ulps_eq(&b.$field, epsilon, max_ulps))*
+        );
+    };
+    ($name:ident [$data:ident]) => {
+        impl_approx_eq!(@impl $name, a, b,
+            abs_diff_eq(epsilon) => a.$data.iter().zip(b.$data.iter()).all(|(x, y)| x.abs_diff_eq(y, epsilon)),
+            relative_eq(epsilon, max_relative) => a.$data.iter().zip(b.$data.iter()).all(|(x, y)| x.relative_eq(y, epsilon, max_relative)),
+            ulps_eq(epsilon, max_ulps) => a.$data.iter().zip(b.$data.iter()).all(|(x, y)| x.ulps_eq(y, epsilon, max_ulps))
+        );
+    };
+    (@impl $name:ident, $a:ident, $b:ident,
+        abs_diff_eq($eps:ident) => $abs:expr,
+        relative_eq($eps2:ident, $rel:ident) => $relative:expr,
+        ulps_eq($eps3:ident, $ulps:ident) => $ulps_body:expr
+    ) => {
+        impl<T: Float + ApproxEq<Epsilon = T>> ApproxEq for $name<T> {
+            type Epsilon = T;
+
+            fn default_epsilon() -> T {
+                T::default_epsilon()
+            }
+
+            fn default_max_relative() -> T {
+                T::default_max_relative()
+            }
+
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, $eps: T) -> bool {
+                let ($a, $b) = (self, other);
+                $abs
+            }
+
+            fn relative_eq(&self, other: &Self, $eps2: T, $rel: T) -> bool {
+                let ($a, $b) = (self, other);
+                $relative
+            }
+
+            fn ulps_eq(&self, other: &Self, $eps3: T, $ulps: u32) -> bool {
+                let ($a, $b) = (self, other);
+                $ulps_body
+            }
+        }
+    };
+}
+
+// Component access shared by the vector types: indexing, array and tuple
+// conversions and iteration. `$n` is the component count and `$tuple` the
+// matching tuple type.
+macro_rules! impl_vector_components {
+    ($name:ident, $n:literal, $tuple:ty { $($field:ident: $i:tt),* }) => {
+        impl<T: Scalar> $name<T> {
+            pub fn to_array(&self) -> [T; $n] {
+                [$(self.$field),*]
+            }
+
+            pub fn iter(&self) -> std::array::IntoIter<T, $n> {
+                self.to_array().into_iter()
+            }
+
+            pub fn iter_mut(&mut self) -> std::array::IntoIter<&mut T, $n> {
+                [$(&mut self.$field),*].into_iter()
+            }
+        }
+
+        impl<T: Scalar> std::ops::Index<usize> for $name<T> {
+            type Output = T;
+
+            fn index(&self, i: usize) -> &T {
+                match i {
+                    $($i => &self.$field,)*
+                    _ => panic!("index out of bounds: {} has {} components but the index is {}", stringify!($name), $n, i),
+                }
+            }
+        }
+
+        impl<T: Scalar> std::ops::IndexMut<usize> for $name<T> {
+            fn index_mut(&mut self, i: usize) -> &mut T {
+                match i {
+                    $($i => &mut self.$field,)*
+                    _ => panic!("index out of bounds: {} has {} components but the index is {}", stringify!($name), $n, i),
+                }
+            }
+        }
+
+        impl<T: Scalar> From<[T; $n]> for $name<T> {
+            fn from(a: [T; $n]) -> $name<T> {
+                $name {
+                    $($field: a[$i]),*
+                }
+            }
+        }
+
+        impl<T: Scalar> From<$tuple> for $name<T> {
+            fn from(t: $tuple) -> $name<T> {
+                $name {
+                    $($field: t.$i),*
+                }
+            }
+        }
+
+        impl<T: Scalar> From<$name<T>> for $tuple {
+            fn from(v: $name<T>) -> $tuple {
+                ($(v.$field),*)
+            }
+        }
+
+        impl<T: Scalar> IntoIterator for $name<T> {
+            type Item = T;
+            type IntoIter = std::array::IntoIter<T, $n>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.to_array().into_iter()
+            }
+        }
+
+        impl<'a, T: Scalar> IntoIterator for &'a mut $name<T> {
+            type Item = &'a mut T;
+            type IntoIter = std::array::IntoIter<&'a mut T, $n>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+    };
+}
@@ -4,10 +4,27 @@ use std::ops::{
     Sub,
     SubAssign,
     Mul,
-    MulAssign
+    MulAssign,
+    Div,
+    DivAssign,
+    Neg,
 };
 
-use crate::core::vector3f::Vector3f;
+use crate::core::vector3f::Vector3;
+use crate::core::vector2f::Vector2;
+use crate::core::matrix4f::{
+    Matrix4,
+    assert_batch_len,
+};
+use crate::core::scalar::{
+    Scalar,
+    SignedScalar,
+    Float,
+};
+use crate::core::error::MathError;
+use crate::core::euler::EulerRot;
+use crate::core::angles::Rad;
+use crate::core::approx::ApproxEq;
 use serde::{
     Serialize,
     Deserialize,
@@ -19,26 +36,32 @@ use bevy_reflect::{
 use bevy_ecs::prelude::Resource;
 
 
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect, FromReflect, Resource)]
+/// Elements are stored inline in row-major order, so `data[1]` is row 0,
+/// column 1. The struct is `Copy` and has the same layout as `[T; 9]`.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Reflect, FromReflect, Resource)]
 #[repr(C)]
-pub struct Matrix3f{
-    pub data: Vec<f32>,
+pub struct Matrix3<T: Scalar>{
+    pub data: [T; 9],
 }
 
-impl Default for Matrix3f {
+pub type Matrix3f = Matrix3<f32>;
+pub type Matrix3d = Matrix3<f64>;
+
+impl<T: Scalar> Default for Matrix3<T> {
     fn default() -> Self {
-        Matrix3f::identity()
+        Matrix3::identity()
     }
 }
 
-impl Matrix3f{
+impl<T: Scalar> Matrix3<T>{
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        m00: f32, m01: f32, m02: f32,
-        m10: f32, m11: f32, m12: f32,
-        m20: f32, m21: f32, m22: f32
+        m00: T, m01: T, m02: T,
+        m10: T, m11: T, m12: T,
+        m20: T, m21: T, m22: T
     ) -> Self {
-        Matrix3f{
-            data: vec![
+        Matrix3{
+            data: [
                 m00, m01, m02,
                 m10, m11, m12,
                 m20, m21, m22
@@ -46,39 +69,73 @@ impl Matrix3f{
         }
     }
 
+    pub fn from_array(data: [T; 9]) -> Self {
+        Matrix3{
+            data
+        }
+    }
+
+    /// Builds a matrix from the first 9 elements of `data` in row-major
+    /// order. Panics if the slice is too short.
+    pub fn from_slice(data: &[T]) -> Self {
+        let mut m = Matrix3::zero();
+        m.data.copy_from_slice(&data[..9]);
+        m
+    }
+
+    pub fn to_array(&self) -> [T; 9] {
+        self.data
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Converts each element with `as` semantics, e.g. `Matrix3d` to `Matrix3f`
+    /// rounds to the nearest `f32`.
+    pub fn cast<U: Scalar>(&self) -> Matrix3<U> {
+        Matrix3{
+            data: self.data.map(|v| U::from_f64(v.to_f64()))
+        }
+    }
+
     pub fn one() -> Self{
-        Matrix3f{
-            data: vec![
-                1.0, 1.0, 1.0,
-                1.0, 1.0, 1.0,
-                1.0, 1.0, 1.0
+        Matrix3{
+            data: [
+                T::ONE, T::ONE, T::ONE,
+                T::ONE, T::ONE, T::ONE,
+                T::ONE, T::ONE, T::ONE
             ]
         }
     }
 
     pub fn zero() -> Self {
-        Matrix3f{
-            data: vec![
-                0.0, 0.0, 0.0,
-                0.0, 0.0, 0.0,
-                0.0, 0.0, 0.0
+        Matrix3{
+            data: [
+                T::ZERO, T::ZERO, T::ZERO,
+                T::ZERO, T::ZERO, T::ZERO,
+                T::ZERO, T::ZERO, T::ZERO
             ]
         }
     }
 
     pub fn identity() -> Self{
-        Matrix3f{
-            data: vec![
-                1.0, 0.0, 0.0,
-                0.0, 1.0, 0.0,
-                0.0, 0.0, 1.0
+        Matrix3{
+            data: [
+                T::ONE, T::ZERO, T::ZERO,
+                T::ZERO, T::ONE, T::ZERO,
+                T::ZERO, T::ZERO, T::ONE
             ]
         }
     }
 
-    pub fn scale(&self, s: f32) -> Self{
-        Matrix3f{
-            data: vec![
+    pub fn scale(&self, s: T) -> Self{
+        Matrix3{
+            data: [
                 self.data[0] * s, self.data[1] * s, self.data[2] * s,
                 self.data[3] * s, self.data[4] * s, self.data[5] * s,
                 self.data[6] * s, self.data[7] * s, self.data[8] * s
@@ -87,8 +144,8 @@ impl Matrix3f{
     }
 
     pub fn transpose(&self) -> Self{
-        Matrix3f{
-            data: vec![
+        Matrix3{
+            data: [
                 self.data[0], self.data[3], self.data[6],
                 self.data[1], self.data[4], self.data[7],
                 self.data[2], self.data[5], self.data[8]
@@ -96,15 +153,57 @@ impl Matrix3f{
         }
     }
 
-    pub fn transform(&self, other: Vector3f) -> Vector3f {
-        Vector3f{
+    pub fn transform(&self, other: Vector3<T>) -> Vector3<T> {
+        Vector3{
             x: self.data[0]*other.x + self.data[1]*other.y + self.data[2]*other.z,
             y: self.data[3]*other.x + self.data[4]*other.y + self.data[5]*other.z,
             z: self.data[6]*other.x + self.data[7]*other.y + self.data[8]*other.z
         }
     }
 
-    pub fn determinant(&self) -> f32 {
+    /// Embeds the matrix as the upper-left 3x3 block of an otherwise
+    /// identity `Matrix4`.
+    pub fn to_matrix4(&self) -> Matrix4<T> {
+        let d = &self.data;
+        Matrix4::new(
+            d[0], d[1], d[2], T::ZERO,
+            d[3], d[4], d[5], T::ZERO,
+            d[6], d[7], d[8], T::ZERO,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE
+        )
+    }
+
+    /// Treats the matrix as a 2D homogeneous transform: transforms each point
+    /// in `points` as `(x, y, 1)`, ignoring the bottom row, and writes the
+    /// results to `out`. Panics if the slices differ in length.
+    pub fn transform_points(&self, points: &[Vector2<T>], out: &mut [Vector2<T>]) {
+        assert_batch_len(points.len(), out.len());
+        let [m0, m1, m2, m3, m4, m5, ..] = self.data;
+        for (p, o) in points.iter().zip(out.iter_mut()) {
+            *o = Vector2{
+                x: m0*p.x + m1*p.y + m2,
+                y: m3*p.x + m4*p.y + m5
+            };
+        }
+    }
+
+    /// Transforms each 2D direction in `vectors` as `(x, y, 0)`, so
+    /// translation is ignored, and writes the results to `out`. Panics if the
+    /// slices differ in length.
+    pub fn transform_vectors(&self, vectors: &[Vector2<T>], out: &mut [Vector2<T>]) {
+        assert_batch_len(vectors.len(), out.len());
+        let [m0, m1, _, m3, m4, ..] = self.data;
+        for (v, o) in vectors.iter().zip(out.iter_mut()) {
+            *o = Vector2{
+                x: m0*v.x + m1*v.y,
+                y: m3*v.x + m4*v.y
+            };
+        }
+    }
+}
+
+impl<T: SignedScalar> Matrix3<T>{
+    pub fn determinant(&self) -> T {
         let a = self.data[0] * (self.data[4]*self.data[8] - self.data[5]*self.data[7]);
         let b = self.data[1] * (self.data[3]*self.data[8] - self.data[5]*self.data[6]);
         let c = self.data[2] * (self.data[3]*self.data[7] - self.data[4]*self.data[6]);
@@ -124,7 +223,7 @@ impl Matrix3f{
         let m21 = self.data[0]*self.data[5] - self.data[2]*self.data[3];
         let m22 = self.data[0]*self.data[4] - self.data[1]*self.data[3];
         
-        Matrix3f::new(
+        Matrix3::new(
             m00, -m01, m02,
             -m10, m11, -m12,
             m20, -m21, m22
@@ -134,20 +233,69 @@ impl Matrix3f{
     pub fn adjugate(&self) -> Self {
         self.cofactor().transpose()
     }
+}
 
+impl<T: Float> Matrix3<T>{
     pub fn inverse(&self) -> Self {
-        self.adjugate().scale(1.0 / self.determinant())
+        self.adjugate().scale(T::ONE / self.determinant())
     }
 
+    /// Whether the determinant is larger than `T::tolerance()` times the
+    /// product of the row lengths. That product bounds the determinant, so
+    /// the test does not depend on the scale of the matrix.
     pub fn invertible(&self) -> bool {
-        self.determinant() > 1e-6
+        self.invertible_with_tolerance(self.singular_tolerance())
+    }
+
+    /// Compares the determinant against the absolute `tolerance`.
+    pub fn invertible_with_tolerance(&self, tolerance: T) -> bool {
+        self.determinant().abs() > tolerance
+    }
+
+    /// Fails with `MathError::SingularMatrix` unless `invertible`.
+    pub fn try_inverse(&self) -> Result<Self, MathError> {
+        self.try_inverse_with_tolerance(self.singular_tolerance())
+    }
+
+    /// Fails with `MathError::SingularMatrix` when the absolute value of the
+    /// determinant is not greater than `tolerance`.
+    pub fn try_inverse_with_tolerance(&self, tolerance: T) -> Result<Self, MathError> {
+        let det = self.determinant();
+        if det.abs() <= tolerance {
+            return Err(MathError::SingularMatrix);
+        }
+        Ok(self.adjugate().scale(T::ONE / det))
+    }
+
+    // `T::tolerance()` scaled by the largest determinant rows of these
+    // lengths can have.
+    fn singular_tolerance(&self) -> T {
+        self.data.chunks(3)
+            .map(|row| row.iter().fold(T::ZERO, |s, &v| s + v * v).sqrt())
+            .fold(T::tolerance(), |t, n| t * n)
     }
 
-    pub fn from_axis_angle(axis_in: Vector3f, angle: f32) -> Matrix3f {
+    /// Transforms each homogeneous 2D point in `points` by the full matrix
+    /// and divides by the resulting z. Panics if the slices differ in length.
+    pub fn transform_homogeneous(&self, points: &[Vector3<T>], out: &mut [Vector2<T>]) {
+        assert_batch_len(points.len(), out.len());
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = self.data;
+        for (p, o) in points.iter().zip(out.iter_mut()) {
+            let w = m6*p.x + m7*p.y + m8*p.z;
+            let inv_w = T::ONE / w;
+            *o = Vector2{
+                x: (m0*p.x + m1*p.y + m2*p.z) * inv_w,
+                y: (m3*p.x + m4*p.y + m5*p.z) * inv_w
+            };
+        }
+    }
+
+    pub fn from_axis_angle(axis_in: Vector3<T>, angle: impl Into<Rad<T>>) -> Matrix3<T> {
+        let angle = angle.into().0;
         let axis = axis_in.normalize();
         let cosr = angle.cos();
         let sinr = angle.sin();
-        let omc = 1.0 - cosr;
+        let omc = T::ONE - cosr;
         let r0c0 = cosr + (axis.x * axis.x * omc);
         let r0c1 = (axis.x * axis.y * omc) - (axis.z * sinr);
         let r0c2 = (axis.x * axis.z * omc) + (axis.y * sinr);
@@ -157,21 +305,96 @@ impl Matrix3f{
         let r2c0 = (axis.z * axis.x * omc) - (axis.y * sinr);
         let r2c1 = (axis.z * axis.y * omc) + (axis.x * sinr);
         let r2c2 = cosr + (axis.z * axis.z * omc);
-        Matrix3f::new(
+        Matrix3::new(
             r0c0, r0c1, r0c2,
             r1c0, r1c1, r1c2,
             r2c0, r2c1, r2c2
         )
     }
 
+    pub fn from_angle_x(r: impl Into<Rad<T>>) -> Matrix3<T> {
+        let r = r.into().0;
+        Matrix3{
+            data: [
+                T::ONE, T::ZERO, T::ZERO,
+                T::ZERO, r.cos(), -r.sin(),
+                T::ZERO, r.sin(), r.cos()
+            ]
+        }
+    }
+
+    pub fn from_angle_y(r: impl Into<Rad<T>>) -> Matrix3<T> {
+        let r = r.into().0;
+        Matrix3{
+            data: [
+                r.cos(), T::ZERO, r.sin(),
+                T::ZERO, T::ONE, T::ZERO,
+                -r.sin(), T::ZERO, r.cos()
+            ]
+        }
+    }
+
+    pub fn from_angle_z(r: impl Into<Rad<T>>) -> Matrix3<T> {
+        let r = r.into().0;
+        Matrix3{
+            data: [
+                r.cos(), -r.sin(), T::ZERO,
+                r.sin(), r.cos(), T::ZERO,
+                T::ZERO, T::ZERO, T::ONE
+            ]
+        }
+    }
+
+    /// Rotation from Euler angles `a`, `b` and `c` about the axes of `order`,
+    /// in the order they are named.
+    pub fn from_euler(order: EulerRot, a: impl Into<Rad<T>>, b: impl Into<Rad<T>>, c: impl Into<Rad<T>>) -> Matrix3<T> {
+        let (a, b, c) = (a.into().0, b.into().0, c.into().0);
+        let (i, j, k) = order.intrinsic_axes();
+        let (a, c) = if order.is_extrinsic() { (c, a) } else { (a, c) };
+        Matrix3::from_angle_axis_index(i, a) * Matrix3::from_angle_axis_index(j, b) * Matrix3::from_angle_axis_index(k, c)
+    }
+
+    /// Euler angles `(a, b, c)` in radians for `order` such that
+    /// `from_euler(order, Rad(a), Rad(b), Rad(c))` reproduces this rotation.
+    /// The middle angle is in `-pi/2..=pi/2`. At gimbal lock (middle angle of
+    /// +-pi/2) only the sum or difference of the outer angles is defined, and
+    /// the angle about the last intrinsic axis is reported as zero (the first
+    /// angle for extrinsic orders).
+    pub fn to_euler(&self, order: EulerRot) -> (T, T, T) {
+        let (i, j, k) = order.intrinsic_axes();
+        let s = if order.is_cyclic() { T::ONE } else { -T::ONE };
+        let m = |r: usize, c: usize| self.data[r * 3 + c];
+
+        let sin_b = (s * m(i, k)).clamp(-T::ONE, T::ONE);
+        let b = sin_b.asin();
+        let (a, c) = if T::ONE - sin_b.abs() > T::from_f64(GIMBAL_LOCK_EPSILON) {
+            ((-s * m(j, k)).atan2(m(k, k)), (-s * m(i, j)).atan2(m(i, i)))
+        } else {
+            ((s * m(k, j)).atan2(m(j, j)), T::ZERO)
+        };
+
+        if order.is_extrinsic() { (c, b, a) } else { (a, b, c) }
+    }
+
+    fn from_angle_axis_index(axis: usize, r: T) -> Matrix3<T> {
+        match axis {
+            0 => Matrix3::from_angle_x(Rad(r)),
+            1 => Matrix3::from_angle_y(Rad(r)),
+            _ => Matrix3::from_angle_z(Rad(r)),
+        }
+    }
 }
 
-impl Add for Matrix3f {
+// How close `|sin(b)|` may get to 1 before the outer angles are treated as
+// coupled. Below this `cos(b)` is too small for `atan2` to separate them.
+const GIMBAL_LOCK_EPSILON: f64 = 1e-6;
+
+impl<T: Scalar> Add for Matrix3<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
         Self {
-            data: vec![
+            data: [
                 self.data[0] + other.data[0], self.data[1] + other.data[1], self.data[2] + other.data[2],
                 self.data[3] + other.data[3], self.data[4] + other.data[4], self.data[5] + other.data[5],
                 self.data[6] + other.data[6], self.data[7] + other.data[7], self.data[8] + other.data[8]
@@ -180,10 +403,10 @@ impl Add for Matrix3f {
     }
 }
 
-impl AddAssign for Matrix3f {
+impl<T: Scalar> AddAssign for Matrix3<T> {
     fn add_assign(&mut self, other: Self) {
         *self = Self {
-            data: vec![
+            data: [
                 self.data[0] + other.data[0], self.data[1] + other.data[1], self.data[2] + other.data[2],
                 self.data[3] + other.data[3], self.data[4] + other.data[4], self.data[5] + other.data[5],
                 self.data[6] + other.data[6], self.data[7] + other.data[7], self.data[8] + other.data[8]
@@ -192,12 +415,12 @@ impl AddAssign for Matrix3f {
     }
 }
 
-impl Sub for Matrix3f {
+impl<T: Scalar> Sub for Matrix3<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
         Self {
-            data: vec![
+            data: [
                 self.data[0] - other.data[0], self.data[1] - other.data[1], self.data[2] - other.data[2],
                 self.data[3] - other.data[3], self.data[4] - other.data[4], self.data[5] - other.data[5],
                 self.data[6] - other.data[6], self.data[7] - other.data[7], self.data[8] - other.data[8]
@@ -206,10 +429,10 @@ impl Sub for Matrix3f {
     }
 }
 
-impl SubAssign for Matrix3f {
+impl<T: Scalar> SubAssign for Matrix3<T> {
     fn sub_assign(&mut self, other: Self) {
         *self = Self {
-            data: vec![
+            data: [
                 self.data[0] - other.data[0], self.data[1] - other.data[1], self.data[2] - other.data[2],
                 self.data[3] - other.data[3], self.data[4] - other.data[4], self.data[5] - other.data[5],
                 self.data[6] - other.data[6], self.data[7] - other.data[7], self.data[8] - other.data[8]
@@ -218,12 +441,12 @@ impl SubAssign for Matrix3f {
     }
 }
 
-impl Mul for Matrix3f {
+impl<T: Scalar> Mul for Matrix3<T> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
         Self {
-            data: vec![
+            data: [
                 (self.data[0]*other.data[0]) + (self.data[1]*other.data[3]) + (self.data[2]*other.data[6]),
                 (self.data[0]*other.data[1]) + (self.data[1]*other.data[4]) + (self.data[2]*other.data[7]),
                 (self.data[0]*other.data[2]) + (self.data[1]*other.data[5]) + (self.data[2]*other.data[8]),
@@ -238,10 +461,10 @@ impl Mul for Matrix3f {
     }
 }
 
-impl MulAssign for Matrix3f {
+impl<T: Scalar> MulAssign for Matrix3<T> {
     fn mul_assign(&mut self, other: Self) {
         *self = Self {
-            data: vec![
+            data: [
                 (self.data[0]*other.data[0]) + (self.data[1]*other.data[3]) + (self.data[2]*other.data[6]),
                 (self.data[0]*other.data[1]) + (self.data[1]*other.data[4]) + (self.data[2]*other.data[7]),
                 (self.data[0]*other.data[2]) + (self.data[1]*other.data[5]) + (self.data[2]*other.data[8]),
@@ -256,8 +479,79 @@ impl MulAssign for Matrix3f {
     }
 }
 
-impl From<Matrix3f> for [[f32; 3]; 3] {
-    fn from(m: Matrix3f) -> [[f32; 3]; 3] {
+impl<T: SignedScalar> Neg for Matrix3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.scale(-T::ONE)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Matrix3<T> {
+    type Output = Self;
+
+    fn mul(self, s: T) -> Self {
+        self.scale(s)
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Matrix3<T> {
+    fn mul_assign(&mut self, s: T) {
+        *self = self.scale(s);
+    }
+}
+
+impl<T: Scalar> Div<T> for Matrix3<T> {
+    type Output = Self;
+
+    fn div(self, s: T) -> Self {
+        Self {
+            data: self.data.map(|v| v / s)
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Matrix3<T> {
+    fn div_assign(&mut self, s: T) {
+        *self = *self / s;
+    }
+}
+
+impl<T: Scalar> Mul<Vector3<T>> for Matrix3<T> {
+    type Output = Vector3<T>;
+
+    fn mul(self, v: Vector3<T>) -> Vector3<T> {
+        self.transform(v)
+    }
+}
+
+forward_ref_unop!(impl [T: SignedScalar] Neg, neg for Matrix3<T>);
+forward_ref_binop!(impl [T: Scalar] Add, add for Matrix3<T>, Matrix3<T>);
+forward_ref_binop!(impl [T: Scalar] Sub, sub for Matrix3<T>, Matrix3<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Matrix3<T>, Matrix3<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Matrix3<T>, T);
+forward_ref_binop!(impl [T: Scalar] Div, div for Matrix3<T>, T);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Matrix3<T>, Vector3<T>);
+forward_ref_op_assign!(impl [T: Scalar] AddAssign, add_assign for Matrix3<T>, Matrix3<T>);
+forward_ref_op_assign!(impl [T: Scalar] SubAssign, sub_assign for Matrix3<T>, Matrix3<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Matrix3<T>, Matrix3<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Matrix3<T>, T);
+forward_ref_op_assign!(impl [T: Scalar] DivAssign, div_assign for Matrix3<T>, T);
+
+impl_scalar_lhs_mul_matrix!(Matrix3: f32, f64);
+
+impl_approx_eq!(Matrix3 [data]);
+
+impl From<Matrix3<f32>> for Matrix3<f64> {
+    fn from(m: Matrix3<f32>) -> Matrix3<f64> {
+        Matrix3{
+            data: m.data.map(f64::from)
+        }
+    }
+}
+
+impl<T: Scalar> From<Matrix3<T>> for [[T; 3]; 3] {
+    fn from(m: Matrix3<T>) -> [[T; 3]; 3] {
         [
             [m.data[0], m.data[1], m.data[2]],
             [m.data[3], m.data[4], m.data[5]],
@@ -266,13 +560,26 @@ impl From<Matrix3f> for [[f32; 3]; 3] {
     }
 }
 
-impl PartialEq for Matrix3f {
+impl<T: Scalar> From<[T; 9]> for Matrix3<T> {
+    fn from(data: [T; 9]) -> Matrix3<T> {
+        Matrix3::from_array(data)
+    }
+}
+
+impl<T: Scalar> From<Matrix3<T>> for [T; 9] {
+    fn from(m: Matrix3<T>) -> [T; 9] {
+        m.data
+    }
+}
+
+impl<T: Scalar> AsRef<[T]> for Matrix3<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T: Scalar> PartialEq for Matrix3<T> {
     fn eq(&self, other: &Self) -> bool {
-        for i in 0..9 {
-            if self.data[i] != other.data[i] {
-                return false;
-            }
-        }
-        return true;
+        self.data == other.data
     }
-}
\ No newline at end of file
+}
@@ -4,13 +4,29 @@ use std::ops::{
     Sub,
     SubAssign,
     Mul,
-    MulAssign
+    MulAssign,
+    Div,
+    DivAssign,
+    Neg,
 };
-use std::f32::consts::PI;
 
-use crate::core::vector4f::Vector4f;
-use crate::core::vector3f::Vector3f;
+use crate::core::vector4f::Vector4;
+use crate::core::vector3f::Vector3;
+use crate::core::matrix3f::Matrix3;
+use crate::core::quaternion::Quat;
+use crate::core::euler::EulerRot;
+use crate::core::angles::{
+    Rad,
+    Deg,
+};
 
+use crate::core::scalar::{
+    Scalar,
+    SignedScalar,
+    Float,
+};
+use crate::core::error::MathError;
+use crate::core::approx::ApproxEq;
 use serde::{
     Serialize,
     Deserialize,
@@ -22,27 +38,36 @@ use bevy_reflect::{
 use bevy_ecs::prelude::Resource;
 
 
-#[derive(Debug, Clone, Serialize, Deserialize, Reflect, FromReflect, Resource)]
+/// Elements are stored inline in row-major order, so `data[1]` is row 0,
+/// column 1. The struct is `Copy` and has the same layout as `[T; 16]`.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Reflect, FromReflect, Resource)]
 #[repr(C)]
-pub struct Matrix4f{
-    pub data: Vec<f32>,
+pub struct Matrix4<T: Scalar>{
+    pub data: [T; 16],
 }
 
-impl Default for Matrix4f {
+pub type Matrix4f = Matrix4<f32>;
+pub type Matrix4d = Matrix4<f64>;
+
+/// `(translation, scale, rotation)` as returned by `Matrix4::decompose`.
+pub type Decomposition<T> = (Vector3<T>, Vector3<T>, Matrix3<T>);
+
+impl<T: Scalar> Default for Matrix4<T> {
     fn default() -> Self {
-        Matrix4f::identity()
+        Matrix4::identity()
     }
 }
 
-impl Matrix4f{
+impl<T: Scalar> Matrix4<T>{
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        m00: f32, m01: f32, m02: f32, m03: f32,
-        m10: f32, m11: f32, m12: f32, m13: f32,
-        m20: f32, m21: f32, m22: f32, m23: f32,
-        m30: f32, m31: f32, m32: f32, m33: f32
+        m00: T, m01: T, m02: T, m03: T,
+        m10: T, m11: T, m12: T, m13: T,
+        m20: T, m21: T, m22: T, m23: T,
+        m30: T, m31: T, m32: T, m33: T
     ) -> Self {
-        Matrix4f{
-            data: vec![
+        Matrix4{
+            data: [
                 m00, m01, m02, m03,
                 m10, m11, m12, m13,
                 m20, m21, m22, m23,
@@ -51,31 +76,71 @@ impl Matrix4f{
         }
     }
 
+    pub fn from_array(data: [T; 16]) -> Self {
+        Matrix4{
+            data
+        }
+    }
+
+    /// Builds a matrix from the first 16 elements of `data` in row-major
+    /// order. Panics if the slice is too short.
+    pub fn from_slice(data: &[T]) -> Self {
+        let mut m = Matrix4::zero();
+        m.data.copy_from_slice(&data[..16]);
+        m
+    }
+
+    pub fn to_array(&self) -> [T; 16] {
+        self.data
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Converts each element with `as` semantics, e.g. `Matrix4d` to `Matrix4f`
+    /// rounds to the nearest `f32`.
+    pub fn cast<U: Scalar>(&self) -> Matrix4<U> {
+        Matrix4{
+            data: self.data.map(|v| U::from_f64(v.to_f64()))
+        }
+    }
+
     pub fn one() -> Self{
-        Matrix4f{
-            data: vec![
-                1.0, 1.0, 1.0, 1.0, 
-                1.0, 1.0, 1.0, 1.0,
-                1.0, 1.0, 1.0, 1.0,
-                1.0, 1.0, 1.0, 1.0
+        Matrix4{
+            data: [
+                T::ONE, T::ONE, T::ONE, T::ONE, 
+                T::ONE, T::ONE, T::ONE, T::ONE,
+                T::ONE, T::ONE, T::ONE, T::ONE,
+                T::ONE, T::ONE, T::ONE, T::ONE
             ]
         }
     }
 
+    pub fn zero() -> Self {
+        Matrix4{
+            data: [T::ZERO; 16]
+        }
+    }
+
     pub fn identity() -> Self{
-        Matrix4f{
-            data: vec![
-                1.0, 0.0, 0.0, 0.0,
-                0.0, 1.0, 0.0, 0.0,
-                0.0, 0.0, 1.0, 0.0,
-                0.0, 0.0, 0.0, 1.0
+        Matrix4{
+            data: [
+                T::ONE, T::ZERO, T::ZERO, T::ZERO,
+                T::ZERO, T::ONE, T::ZERO, T::ZERO,
+                T::ZERO, T::ZERO, T::ONE, T::ZERO,
+                T::ZERO, T::ZERO, T::ZERO, T::ONE
             ]
         }
     }
 
-    pub fn scale(&self, s: f32) -> Self{
-        Matrix4f{
-            data: vec![
+    pub fn scale(&self, s: T) -> Self{
+        Matrix4{
+            data: [
                 self.data[0] * s, self.data[1] * s, self.data[2] * s, self.data[3] * s,
                 self.data[4] * s, self.data[5] * s, self.data[6] * s, self.data[7] * s,
                 self.data[8] * s, self.data[9] * s, self.data[10] * s, self.data[11] * s,
@@ -85,8 +150,8 @@ impl Matrix4f{
     }
 
     pub fn transpose(&self) -> Self{
-        Matrix4f{
-            data: vec![
+        Matrix4{
+            data: [
                 self.data[0], self.data[4], self.data[8], self.data[12],
                 self.data[1], self.data[5], self.data[9], self.data[13],
                 self.data[2], self.data[6], self.data[10], self.data[14],
@@ -95,8 +160,11 @@ impl Matrix4f{
         }
     }
 
-    pub fn transform(&self, other: Vector4f) -> Vector4f {
-        Vector4f{
+    pub fn transform(&self, other: Vector4<T>) -> Vector4<T> {
+        if let Some([x, y, z, w]) = T::simd_mat4_transform(&self.data, other.to_array()) {
+            return Vector4::new(x, y, z, w);
+        }
+        Vector4{
             x: self.data[0]*other.x + self.data[1]*other.y + self.data[2]*other.z + self.data[3]*other.w,
             y: self.data[4]*other.x + self.data[5]*other.y + self.data[6]*other.z + self.data[7]*other.w,
             z: self.data[8]*other.x + self.data[9]*other.y + self.data[10]*other.z + self.data[11]*other.w,
@@ -104,98 +172,409 @@ impl Matrix4f{
         }
     }
 
-    pub fn from_translation(t: Vector3f) -> Matrix4f {
-        Matrix4f{
-            data: vec![
-                1.0, 0.0, 0.0, t.x,
-                0.0, 1.0, 0.0, t.y,
-                0.0, 0.0, 1.0, t.z,
-                0.0, 0.0, 0.0, 1.0
+    /// The upper-left 3x3 block, i.e. the linear part of an affine transform.
+    pub fn to_matrix3(&self) -> Matrix3<T> {
+        Matrix3::new(
+            self.data[0], self.data[1], self.data[2],
+            self.data[4], self.data[5], self.data[6],
+            self.data[8], self.data[9], self.data[10]
+        )
+    }
+
+    /// Transforms `p` as the point `(x, y, z, 1)`, ignoring the bottom row.
+    /// Use `project_point3` for projective matrices.
+    pub fn transform_point3(&self, p: Vector3<T>) -> Vector3<T> {
+        Vector3{
+            x: self.data[0]*p.x + self.data[1]*p.y + self.data[2]*p.z + self.data[3],
+            y: self.data[4]*p.x + self.data[5]*p.y + self.data[6]*p.z + self.data[7],
+            z: self.data[8]*p.x + self.data[9]*p.y + self.data[10]*p.z + self.data[11]
+        }
+    }
+
+    /// Transforms `v` as the direction `(x, y, z, 0)`, so translation is
+    /// ignored.
+    pub fn transform_vector3(&self, v: Vector3<T>) -> Vector3<T> {
+        Vector3{
+            x: self.data[0]*v.x + self.data[1]*v.y + self.data[2]*v.z,
+            y: self.data[4]*v.x + self.data[5]*v.y + self.data[6]*v.z,
+            z: self.data[8]*v.x + self.data[9]*v.y + self.data[10]*v.z
+        }
+    }
+
+    /// Transforms each point in `points` as `(x, y, z, 1)`, ignoring the
+    /// bottom row, and writes the results to `out`. Panics if the slices
+    /// differ in length.
+    pub fn transform_points(&self, points: &[Vector3<T>], out: &mut [Vector3<T>]) {
+        assert_batch_len(points.len(), out.len());
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8, m9, m10, m11, ..] = self.data;
+        for (p, o) in points.iter().zip(out.iter_mut()) {
+            *o = Vector3{
+                x: m0*p.x + m1*p.y + m2*p.z + m3,
+                y: m4*p.x + m5*p.y + m6*p.z + m7,
+                z: m8*p.x + m9*p.y + m10*p.z + m11
+            };
+        }
+    }
+
+    /// Transforms each direction in `vectors` as `(x, y, z, 0)`, so
+    /// translation is ignored, and writes the results to `out`. Panics if the
+    /// slices differ in length.
+    pub fn transform_vectors(&self, vectors: &[Vector3<T>], out: &mut [Vector3<T>]) {
+        assert_batch_len(vectors.len(), out.len());
+        let [m0, m1, m2, _, m4, m5, m6, _, m8, m9, m10, ..] = self.data;
+        for (v, o) in vectors.iter().zip(out.iter_mut()) {
+            *o = Vector3{
+                x: m0*v.x + m1*v.y + m2*v.z,
+                y: m4*v.x + m5*v.y + m6*v.z,
+                z: m8*v.x + m9*v.y + m10*v.z
+            };
+        }
+    }
+}
+
+impl<T: SignedScalar> Matrix4<T>{
+    pub fn determinant(&self) -> T {
+        let d = &self.data;
+        let s0 = d[0]*d[5] - d[4]*d[1];
+        let s1 = d[0]*d[6] - d[4]*d[2];
+        let s2 = d[0]*d[7] - d[4]*d[3];
+        let s3 = d[1]*d[6] - d[5]*d[2];
+        let s4 = d[1]*d[7] - d[5]*d[3];
+        let s5 = d[2]*d[7] - d[6]*d[3];
+
+        let c5 = d[10]*d[15] - d[14]*d[11];
+        let c4 = d[9]*d[15] - d[13]*d[11];
+        let c3 = d[9]*d[14] - d[13]*d[10];
+        let c2 = d[8]*d[15] - d[12]*d[11];
+        let c1 = d[8]*d[14] - d[12]*d[10];
+        let c0 = d[8]*d[13] - d[12]*d[9];
+
+        s0*c5 - s1*c4 + s2*c3 + s3*c2 - s4*c1 + s5*c0
+    }
+
+    pub fn cofactor(&self) -> Self {
+        // expansion by 2x2 minors of the top two and bottom two rows
+        let d = &self.data;
+        let s0 = d[0]*d[5] - d[4]*d[1];
+        let s1 = d[0]*d[6] - d[4]*d[2];
+        let s2 = d[0]*d[7] - d[4]*d[3];
+        let s3 = d[1]*d[6] - d[5]*d[2];
+        let s4 = d[1]*d[7] - d[5]*d[3];
+        let s5 = d[2]*d[7] - d[6]*d[3];
+
+        let c5 = d[10]*d[15] - d[14]*d[11];
+        let c4 = d[9]*d[15] - d[13]*d[11];
+        let c3 = d[9]*d[14] - d[13]*d[10];
+        let c2 = d[8]*d[15] - d[12]*d[11];
+        let c1 = d[8]*d[14] - d[12]*d[10];
+        let c0 = d[8]*d[13] - d[12]*d[9];
+
+        let m00 = d[5]*c5 - d[6]*c4 + d[7]*c3;
+        let m01 = -d[4]*c5 + d[6]*c2 - d[7]*c1;
+        let m02 = d[4]*c4 - d[5]*c2 + d[7]*c0;
+        let m03 = -d[4]*c3 + d[5]*c1 - d[6]*c0;
+
+        let m10 = -d[1]*c5 + d[2]*c4 - d[3]*c3;
+        let m11 = d[0]*c5 - d[2]*c2 + d[3]*c1;
+        let m12 = -d[0]*c4 + d[1]*c2 - d[3]*c0;
+        let m13 = d[0]*c3 - d[1]*c1 + d[2]*c0;
+
+        let m20 = d[13]*s5 - d[14]*s4 + d[15]*s3;
+        let m21 = -d[12]*s5 + d[14]*s2 - d[15]*s1;
+        let m22 = d[12]*s4 - d[13]*s2 + d[15]*s0;
+        let m23 = -d[12]*s3 + d[13]*s1 - d[14]*s0;
+
+        let m30 = -d[9]*s5 + d[10]*s4 - d[11]*s3;
+        let m31 = d[8]*s5 - d[10]*s2 + d[11]*s1;
+        let m32 = -d[8]*s4 + d[9]*s2 - d[11]*s0;
+        let m33 = d[8]*s3 - d[9]*s1 + d[10]*s0;
+
+        Matrix4::new(
+            m00, m01, m02, m03,
+            m10, m11, m12, m13,
+            m20, m21, m22, m23,
+            m30, m31, m32, m33
+        )
+    }
+
+    pub fn adjugate(&self) -> Self {
+        self.cofactor().transpose()
+    }
+}
+
+impl<T: Float> Matrix4<T>{
+    pub fn inverse(&self) -> Self {
+        self.adjugate().scale(T::ONE / self.determinant())
+    }
+
+    /// Whether the determinant is larger than `T::tolerance()` times the
+    /// product of the row lengths. That product bounds the determinant, so
+    /// the test does not depend on the scale of the matrix.
+    pub fn invertible(&self) -> bool {
+        self.invertible_with_tolerance(self.singular_tolerance())
+    }
+
+    /// Compares the determinant against the absolute `tolerance`.
+    pub fn invertible_with_tolerance(&self, tolerance: T) -> bool {
+        self.determinant().abs() > tolerance
+    }
+
+    /// Fails with `MathError::SingularMatrix` unless `invertible`.
+    pub fn try_inverse(&self) -> Result<Self, MathError> {
+        self.try_inverse_with_tolerance(self.singular_tolerance())
+    }
+
+    /// Fails with `MathError::SingularMatrix` when the absolute value of the
+    /// determinant is not greater than `tolerance`.
+    pub fn try_inverse_with_tolerance(&self, tolerance: T) -> Result<Self, MathError> {
+        let det = self.determinant();
+        if det.abs() <= tolerance {
+            return Err(MathError::SingularMatrix);
+        }
+        Ok(self.adjugate().scale(T::ONE / det))
+    }
+
+    // `T::tolerance()` scaled by the largest determinant rows of these
+    // lengths can have.
+    fn singular_tolerance(&self) -> T {
+        self.data.chunks(4)
+            .map(|row| row.iter().fold(T::ZERO, |s, &v| s + v * v).sqrt())
+            .fold(T::tolerance(), |t, n| t * n)
+    }
+
+    /// Inverse of an affine transform (bottom row `0 0 0 1`), such as any
+    /// combination of translation, rotation and scale. Only the upper 3x3 is
+    /// inverted, which is cheaper and more accurate than `inverse`.
+    pub fn inverse_affine(&self) -> Self {
+        let linear = self.to_matrix3();
+        let det = linear.determinant();
+        Matrix4::affine_from_inverse(self, &linear.adjugate().scale(T::ONE / det))
+    }
+
+    /// Fails with `MathError::SingularMatrix` unless the upper 3x3 is
+    /// `invertible`.
+    pub fn try_inverse_affine(&self) -> Result<Self, MathError> {
+        let inv = self.to_matrix3().try_inverse()?;
+        Ok(Matrix4::affine_from_inverse(self, &inv))
+    }
+
+    /// Like `inverse_affine` but fails when the upper 3x3 is singular, e.g.
+    /// for a transform with a zero scale.
+    pub fn try_inverse_affine_with_tolerance(&self, tolerance: T) -> Result<Self, MathError> {
+        let inv = self.to_matrix3().try_inverse_with_tolerance(tolerance)?;
+        Ok(Matrix4::affine_from_inverse(self, &inv))
+    }
+
+    fn affine_from_inverse(m: &Matrix4<T>, inv: &Matrix3<T>) -> Matrix4<T> {
+        let t = inv.transform(Vector3::new(m.data[3], m.data[7], m.data[11]));
+        Matrix4::new(
+            inv.data[0], inv.data[1], inv.data[2], -t.x,
+            inv.data[3], inv.data[4], inv.data[5], -t.y,
+            inv.data[6], inv.data[7], inv.data[8], -t.z,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE
+        )
+    }
+
+    /// Transforms `p` as the point `(x, y, z, 1)` by the full matrix and
+    /// divides by the resulting w.
+    pub fn project_point3(&self, p: Vector3<T>) -> Vector3<T> {
+        let v = self.transform(p.extend(T::ONE));
+        v.truncate() / v.w
+    }
+
+    /// Transforms the surface normal `n` by the inverse transpose of the
+    /// upper 3x3 block, which keeps it perpendicular to transformed surfaces
+    /// under non-uniform scale. The result is not normalized.
+    pub fn transform_normal(&self, n: Vector3<T>) -> Vector3<T> {
+        self.to_matrix3().inverse().transpose().transform(n)
+    }
+
+    pub fn try_transform_normal(&self, n: Vector3<T>) -> Result<Vector3<T>, MathError> {
+        Ok(self.to_matrix3().try_inverse()?.transpose().transform(n))
+    }
+
+    /// Transforms each homogeneous point in `points` by the full matrix and
+    /// divides by the resulting w, e.g. to take clip-space positions to NDC.
+    /// Panics if the slices differ in length.
+    pub fn transform_homogeneous(&self, points: &[Vector4<T>], out: &mut [Vector3<T>]) {
+        assert_batch_len(points.len(), out.len());
+        let [m0, m1, m2, m3, m4, m5, m6, m7, m8, m9, m10, m11, m12, m13, m14, m15] = self.data;
+        for (p, o) in points.iter().zip(out.iter_mut()) {
+            let w = m12*p.x + m13*p.y + m14*p.z + m15*p.w;
+            let inv_w = T::ONE / w;
+            *o = Vector3{
+                x: (m0*p.x + m1*p.y + m2*p.z + m3*p.w) * inv_w,
+                y: (m4*p.x + m5*p.y + m6*p.z + m7*p.w) * inv_w,
+                z: (m8*p.x + m9*p.y + m10*p.z + m11*p.w) * inv_w
+            };
+        }
+    }
+
+    pub fn from_translation(t: Vector3<T>) -> Matrix4<T> {
+        Matrix4{
+            data: [
+                T::ONE, T::ZERO, T::ZERO, t.x,
+                T::ZERO, T::ONE, T::ZERO, t.y,
+                T::ZERO, T::ZERO, T::ONE, t.z,
+                T::ZERO, T::ZERO, T::ZERO, T::ONE
             ]
         }
     }
 
-    pub fn from_scale(s: f32) -> Matrix4f {
-        Matrix4f{
-            data: vec![
-                s, 0.0, 0.0, 0.0,
-                0.0, s, 0.0, 0.0,
-                0.0, 0.0, s, 0.0,
-                0.0, 0.0, 0.0, 1.0,
+    pub fn from_scale(s: T) -> Matrix4<T> {
+        Matrix4{
+            data: [
+                s, T::ZERO, T::ZERO, T::ZERO,
+                T::ZERO, s, T::ZERO, T::ZERO,
+                T::ZERO, T::ZERO, s, T::ZERO,
+                T::ZERO, T::ZERO, T::ZERO, T::ONE,
             ]
         }
     }
 
-    pub fn from_scale_vec(s: Vector3f) -> Matrix4f {
-        Matrix4f{
-            data: vec![
-                s.x, 0.0, 0.0, 0.0,
-                0.0, s.y, 0.0, 0.0,
-                0.0, 0.0, s.z, 0.0,
-                0.0, 0.0, 0.0, 1.0
+    pub fn from_scale_vec(s: Vector3<T>) -> Matrix4<T> {
+        Matrix4{
+            data: [
+                s.x, T::ZERO, T::ZERO, T::ZERO,
+                T::ZERO, s.y, T::ZERO, T::ZERO,
+                T::ZERO, T::ZERO, s.z, T::ZERO,
+                T::ZERO, T::ZERO, T::ZERO, T::ONE
             ]
         }
     }
 
-    pub fn from_angle_x_deg(r: f32) -> Matrix4f {
-        let b = r * (PI / 180.0);
-        Matrix4f::from_angle_x(b)
+    /// Equivalent to `from_translation(t) * rotation * from_scale_vec(s)`:
+    /// scales first, then rotates, then translates.
+    pub fn from_scale_rotation_translation(scale: Vector3<T>, rotation: Quat<T>, translation: Vector3<T>) -> Matrix4<T> {
+        let r = rotation.to_matrix3();
+        Matrix4::new(
+            r.data[0] * scale.x, r.data[1] * scale.y, r.data[2] * scale.z, translation.x,
+            r.data[3] * scale.x, r.data[4] * scale.y, r.data[5] * scale.z, translation.y,
+            r.data[6] * scale.x, r.data[7] * scale.y, r.data[8] * scale.z, translation.z,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE
+        )
+    }
+
+    /// Splits an affine transform into `(translation, scale, rotation)`, the
+    /// inverse of `from_scale_rotation_translation`. A reflection is returned
+    /// as a negative `scale.x`. Shear cannot be represented and leaves the
+    /// rotation non-orthogonal, and a zero scale leaves it undefined; see
+    /// `try_decompose`.
+    pub fn decompose(&self) -> Decomposition<T> {
+        let linear = self.to_matrix3();
+        let translation = Vector3::new(self.data[3], self.data[7], self.data[11]);
+
+        let mut scale = Vector3::new(
+            Vector3::new(linear.data[0], linear.data[3], linear.data[6]).magnitude(),
+            Vector3::new(linear.data[1], linear.data[4], linear.data[7]).magnitude(),
+            Vector3::new(linear.data[2], linear.data[5], linear.data[8]).magnitude()
+        );
+        if linear.determinant() < T::ZERO {
+            scale.x = -scale.x;
+        }
+
+        let inv = Vector3::new(T::ONE / scale.x, T::ONE / scale.y, T::ONE / scale.z);
+        let rotation = Matrix3::new(
+            linear.data[0] * inv.x, linear.data[1] * inv.y, linear.data[2] * inv.z,
+            linear.data[3] * inv.x, linear.data[4] * inv.y, linear.data[5] * inv.z,
+            linear.data[6] * inv.x, linear.data[7] * inv.y, linear.data[8] * inv.z
+        );
+        (translation, scale, rotation)
+    }
+
+    /// Like `decompose` but fails with `MathError::SingularMatrix` when any
+    /// axis has zero scale.
+    pub fn try_decompose(&self) -> Result<Decomposition<T>, MathError> {
+        let (translation, scale, rotation) = self.decompose();
+        if scale.x.abs() <= T::tolerance() || scale.y.abs() <= T::tolerance() || scale.z.abs() <= T::tolerance() {
+            return Err(MathError::SingularMatrix);
+        }
+        Ok((translation, scale, rotation))
+    }
+
+    /// `decompose` with the rotation as a quaternion.
+    pub fn decompose_quat(&self) -> (Vector3<T>, Vector3<T>, Quat<T>) {
+        let (translation, scale, rotation) = self.decompose();
+        (translation, scale, Quat::from_rotation_matrix(&rotation))
     }
 
-    pub fn from_angle_x(r: f32) -> Matrix4f {
-        Matrix4f{
-            data: vec![
-                1.0, 0.0, 0.0, 0.0,
-                0.0, r.cos(), -r.sin(), 0.0,
-                0.0, r.sin(), r.cos(), 0.0,
-                0.0, 0.0, 0.0, 1.0
+    pub fn from_angle_x_deg(r: T) -> Matrix4<T> {
+        Matrix4::from_angle_x(Deg(r))
+    }
+
+    pub fn from_angle_x(r: impl Into<Rad<T>>) -> Matrix4<T> {
+        let r = r.into().0;
+        Matrix4{
+            data: [
+                T::ONE, T::ZERO, T::ZERO, T::ZERO,
+                T::ZERO, r.cos(), -r.sin(), T::ZERO,
+                T::ZERO, r.sin(), r.cos(), T::ZERO,
+                T::ZERO, T::ZERO, T::ZERO, T::ONE
             ]
         }
     }
 
-    pub fn from_angle_y_deg(r: f32) -> Matrix4f {
-        let b = r * (PI / 180.0);
-        Matrix4f::from_angle_y(b)
+    pub fn from_angle_y_deg(r: T) -> Matrix4<T> {
+        Matrix4::from_angle_y(Deg(r))
     }
 
-    pub fn from_angle_y(r: f32) -> Matrix4f {
-        Matrix4f{
-            data: vec![
-                r.cos(), 0.0, r.sin(), 0.0,
-                0.0, 1.0, 0.0, 0.0,
-                -r.sin(), 0.0, r.cos(), 0.0,
-                0.0, 0.0, 0.0, 1.0
+    pub fn from_angle_y(r: impl Into<Rad<T>>) -> Matrix4<T> {
+        let r = r.into().0;
+        Matrix4{
+            data: [
+                r.cos(), T::ZERO, r.sin(), T::ZERO,
+                T::ZERO, T::ONE, T::ZERO, T::ZERO,
+                -r.sin(), T::ZERO, r.cos(), T::ZERO,
+                T::ZERO, T::ZERO, T::ZERO, T::ONE
             ]
         }
     }
 
-    pub fn from_angle_z_deg(r: f32) -> Matrix4f {
-        let b = r * (PI / 180.0);
-        Matrix4f::from_angle_z(b)
+    pub fn from_angle_z_deg(r: T) -> Matrix4<T> {
+        Matrix4::from_angle_z(Deg(r))
     }
 
-    pub fn from_angle_z(r: f32) -> Matrix4f {
-        Matrix4f{
-            data: vec![
-                r.cos(), -r.sin(), 0.0, 0.0,
-                r.sin(), r.cos(), 0.0, 0.0,
-                0.0, 0.0, 1.0, 0.0,
-                0.0, 0.0, 0.0, 1.0,
+    pub fn from_angle_z(r: impl Into<Rad<T>>) -> Matrix4<T> {
+        let r = r.into().0;
+        Matrix4{
+            data: [
+                r.cos(), -r.sin(), T::ZERO, T::ZERO,
+                r.sin(), r.cos(), T::ZERO, T::ZERO,
+                T::ZERO, T::ZERO, T::ONE, T::ZERO,
+                T::ZERO, T::ZERO, T::ZERO, T::ONE,
             ]
         }
     }
 
-    pub fn from_axis_angle(axis: Vector3f, r: f32) -> Matrix4f {
-        Matrix4f::from_axis_angle_comp(axis.x, axis.y, axis.z, r)
+    /// See `Matrix3::from_euler`.
+    pub fn from_euler(order: EulerRot, a: impl Into<Rad<T>>, b: impl Into<Rad<T>>, c: impl Into<Rad<T>>) -> Matrix4<T> {
+        let r = Matrix3::from_euler(order, a, b, c);
+        Matrix4::new(
+            r.data[0], r.data[1], r.data[2], T::ZERO,
+            r.data[3], r.data[4], r.data[5], T::ZERO,
+            r.data[6], r.data[7], r.data[8], T::ZERO,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE
+        )
+    }
+
+    /// Euler angles of the upper 3x3, which must be a pure rotation; use
+    /// `decompose` first for scaled transforms. See `Matrix3::to_euler`.
+    pub fn to_euler(&self, order: EulerRot) -> (T, T, T) {
+        self.to_matrix3().to_euler(order)
     }
 
-    pub fn from_axis_angle_comp(x: f32, y: f32, z: f32, r: f32) -> Matrix4f {
+    pub fn from_axis_angle(axis: Vector3<T>, r: impl Into<Rad<T>>) -> Matrix4<T> {
+        Matrix4::from_axis_angle_comp(axis.x, axis.y, axis.z, r)
+    }
+
+    pub fn from_axis_angle_comp(x: T, y: T, z: T, r: impl Into<Rad<T>>) -> Matrix4<T> {
+        let r = r.into().0;
         let cosr = r.cos();
         let sinr = r.sin();
-        let a = 1.0 - cosr;
+        let a = T::ONE - cosr;
         let xsin = x * sinr;
         let ysin = y * sinr;
-        let zsin = y * sinr;
+        let zsin = z * sinr;
         let xy = x * y;
         let xz = x * z;
         let yz = y * z;
@@ -203,72 +582,267 @@ impl Matrix4f{
         let yy = y * y;
         let zz = z * z;
 
-        Matrix4f{
-            data: vec![
-                cosr + xx*a, xy*a - z*sinr, xz*a + ysin, 0.0,
-                xy*a + zsin, cosr + yy*a, yz*a - xsin, 0.0, 
-                xz*a - ysin, yz*a + xsin, cosr + zz*a, 0.0,
-                0.0, 0.0, 0.0, 1.0
+        Matrix4{
+            data: [
+                cosr + xx*a, xy*a - zsin, xz*a + ysin, T::ZERO,
+                xy*a + zsin, cosr + yy*a, yz*a - xsin, T::ZERO, 
+                xz*a - ysin, yz*a + xsin, cosr + zz*a, T::ZERO,
+                T::ZERO, T::ZERO, T::ZERO, T::ONE
             ]
         }
     }
 
-    pub fn look_at_rh(eye: Vector3f, center: Vector3f, up: Vector3f) -> Matrix4f {
+    pub fn look_at_rh(eye: Vector3<T>, center: Vector3<T>, up: Vector3<T>) -> Matrix4<T> {
         let dir = center - eye;
         let zaxis = dir.normalize();
         let xaxis = zaxis.cross(up).normalize();
         let yaxis = xaxis.cross(zaxis).normalize();
 
-        Matrix4f::new(
+        Matrix4::new(
             xaxis.x, xaxis.y, xaxis.z, -eye.dot(xaxis),
             yaxis.x, yaxis.y, yaxis.z, -eye.dot(yaxis), 
             -zaxis.x, -zaxis.y, -zaxis.z, eye.dot(zaxis),
-            0.0, 0.0, 0.0, 1.0
-        ).transpose()
+            T::ZERO, T::ZERO, T::ZERO, T::ONE
+        )
+    }
+
+    /// Left-handed view matrix: the camera looks down +z.
+    pub fn look_at_lh(eye: Vector3<T>, center: Vector3<T>, up: Vector3<T>) -> Matrix4<T> {
+        let dir = center - eye;
+        let zaxis = dir.normalize();
+        let xaxis = up.cross(zaxis).normalize();
+        let yaxis = zaxis.cross(xaxis).normalize();
+
+        Matrix4::new(
+            xaxis.x, xaxis.y, xaxis.z, -eye.dot(xaxis),
+            yaxis.x, yaxis.y, yaxis.z, -eye.dot(yaxis),
+            zaxis.x, zaxis.y, zaxis.z, -eye.dot(zaxis),
+            T::ZERO, T::ZERO, T::ZERO, T::ONE
+        )
+    }
+
+    /// Fails with `MathError::DegenerateProjection` when `eye` and `center`
+    /// coincide or `up` is parallel to the view direction.
+    pub fn try_look_at_rh(eye: Vector3<T>, center: Vector3<T>, up: Vector3<T>) -> Result<Matrix4<T>, MathError> {
+        let dir = center - eye;
+        if dir.magnitude() <= T::tolerance() || dir.cross(up).magnitude() <= T::tolerance() {
+            return Err(MathError::DegenerateProjection);
+        }
+        Ok(Matrix4::look_at_rh(eye, center, up))
     }
 
-    pub fn perspective(fovy: f32, aspect: f32, near: f32, far: f32) -> Matrix4f {
-        let c = 1.0 / (fovy / 2.0).tan();
+    /// `perspective_gl` that fails with `MathError::DegenerateProjection`
+    /// unless `0 < fovy < pi`, `aspect` is non-zero and `near` and `far` are
+    /// distinct.
+    pub fn try_perspective_gl(fovy: T, aspect: T, near: T, far: T) -> Result<Matrix4<T>, MathError> {
+        if fovy <= T::ZERO || fovy >= T::PI || aspect.abs() <= T::tolerance() || (far - near).abs() <= T::tolerance() {
+            return Err(MathError::DegenerateProjection);
+        }
+        Ok(Matrix4::perspective_gl(fovy, aspect, near, far))
+    }
+
+    /// Right-handed perspective projection with OpenGL's -1..1 depth range.
+    pub fn perspective_gl(fovy: T, aspect: T, near: T, far: T) -> Matrix4<T> {
+        let c = T::ONE / (fovy / T::TWO).tan();
         let fp = far;
         let np = near;
 
         let c0r0 = c / aspect;
-        let c0r1 = 0.0;
-        let c0r2 = 0.0;
-        let c0r3 = 0.0;
+        let c0r1 = T::ZERO;
+        let c0r2 = T::ZERO;
+        let c0r3 = T::ZERO;
 
-        let c1r0 = 0.0;
+        let c1r0 = T::ZERO;
         let c1r1 = c;
-        let c1r2 = 0.0;
-        let c1r3 = 0.0;
+        let c1r2 = T::ZERO;
+        let c1r3 = T::ZERO;
 
-        let c2r0 = 0.0;
-        let c2r1 = 0.0;
+        let c2r0 = T::ZERO;
+        let c2r1 = T::ZERO;
         // let c2r2 = -(fp + np) / (fp - np);
         let c2r2 = (fp + np) / (np - fp);  // from cgmath
-        let c2r3 = -1.0;
+        let c2r3 = -T::ONE;
 
-        let c3r0 = 0.0;
-        let c3r1 = 0.0;
+        let c3r0 = T::ZERO;
+        let c3r1 = T::ZERO;
         // let c3r2 = -(2.0 * fp * np) / (fp - np);
-        let c3r2 = (2.0 * fp * np) / (np - fp);  // from cgmath
-        let c3r3 = 0.0;
+        let c3r2 = (T::TWO * fp * np) / (np - fp);  // from cgmath
+        let c3r3 = T::ZERO;
 
-        Matrix4f::new(
+        Matrix4::new(
             c0r0, c1r0, c2r0, c3r0,
             c0r1, c1r1, c2r1, c3r1,
             c0r2, c1r2, c2r2, c3r2,
             c0r3, c1r3, c2r3, c3r3,
-        ).transpose()
+        )
+    }
+
+    // Projection builders follow one scheme. `rh` views look down -z and
+    // `lh` views down +z. `_gl` builders use OpenGL's -1..1 depth range and
+    // are right-handed; `_rh` and `_lh` map depth to 0..1 as in wgpu,
+    // Vulkan, Metal and D3D. `reversed` maps near to 1 and far to 0, and
+    // `infinite` pushes the far plane to infinity.
+
+    /// Right-handed perspective projection with 0..1 depth.
+    pub fn perspective_rh(fovy: T, aspect: T, near: T, far: T) -> Matrix4<T> {
+        let (a, b) = Matrix4::depth_zero_to_one(near, far);
+        Matrix4::perspective_fov(fovy, aspect, near, -T::ONE, a, b)
+    }
+
+    /// Left-handed perspective projection with 0..1 depth.
+    pub fn perspective_lh(fovy: T, aspect: T, near: T, far: T) -> Matrix4<T> {
+        let (a, b) = Matrix4::depth_zero_to_one(near, far);
+        Matrix4::perspective_fov(fovy, aspect, near, T::ONE, a, b)
+    }
+
+    /// Right-handed perspective projection with reversed 1..0 depth.
+    pub fn perspective_reversed_rh(fovy: T, aspect: T, near: T, far: T) -> Matrix4<T> {
+        let (a, b) = Matrix4::depth_zero_to_one(far, near);
+        Matrix4::perspective_fov(fovy, aspect, near, -T::ONE, a, b)
+    }
+
+    /// Left-handed perspective projection with reversed 1..0 depth.
+    pub fn perspective_reversed_lh(fovy: T, aspect: T, near: T, far: T) -> Matrix4<T> {
+        let (a, b) = Matrix4::depth_zero_to_one(far, near);
+        Matrix4::perspective_fov(fovy, aspect, near, T::ONE, a, b)
+    }
+
+    /// Right-handed perspective projection with 0..1 depth and no far plane.
+    pub fn perspective_infinite_rh(fovy: T, aspect: T, near: T) -> Matrix4<T> {
+        Matrix4::perspective_fov(fovy, aspect, near, -T::ONE, T::ONE, -near)
+    }
+
+    /// Left-handed perspective projection with 0..1 depth and no far plane.
+    pub fn perspective_infinite_lh(fovy: T, aspect: T, near: T) -> Matrix4<T> {
+        Matrix4::perspective_fov(fovy, aspect, near, T::ONE, T::ONE, -near)
+    }
+
+    /// Right-handed perspective projection with reversed depth, from 1 at
+    /// `near` to 0 at infinity.
+    pub fn perspective_infinite_reversed_rh(fovy: T, aspect: T, near: T) -> Matrix4<T> {
+        Matrix4::perspective_fov(fovy, aspect, near, -T::ONE, T::ZERO, near)
+    }
+
+    /// Left-handed perspective projection with reversed depth, from 1 at
+    /// `near` to 0 at infinity.
+    pub fn perspective_infinite_reversed_lh(fovy: T, aspect: T, near: T) -> Matrix4<T> {
+        Matrix4::perspective_fov(fovy, aspect, near, T::ONE, T::ZERO, near)
+    }
+
+    /// Off-center perspective projection through the near-plane rectangle
+    /// `left..right`, `bottom..top`, like `glFrustum`. Right-handed with
+    /// -1..1 depth.
+    pub fn frustum_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        let a = (far + near) / (far - near);
+        let b = T::TWO * far * near / (near - far);
+        Matrix4::frustum_with_depth(left, right, bottom, top, near, -T::ONE, a, b)
+    }
+
+    /// Right-handed off-center perspective projection with 0..1 depth.
+    pub fn frustum_rh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        let (a, b) = Matrix4::depth_zero_to_one(near, far);
+        Matrix4::frustum_with_depth(left, right, bottom, top, near, -T::ONE, a, b)
     }
+
+    /// Left-handed off-center perspective projection with 0..1 depth.
+    pub fn frustum_lh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        let (a, b) = Matrix4::depth_zero_to_one(near, far);
+        Matrix4::frustum_with_depth(left, right, bottom, top, near, T::ONE, a, b)
+    }
+
+    /// Orthographic projection of the box `left..right`, `bottom..top`,
+    /// `near..far`, like `glOrtho`. Right-handed with -1..1 depth.
+    pub fn orthographic_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        let a = T::TWO / (far - near);
+        let b = -(far + near) / (far - near);
+        Matrix4::orthographic_with_depth(left, right, bottom, top, -T::ONE, a, b)
+    }
+
+    /// Right-handed orthographic projection with 0..1 depth.
+    pub fn orthographic_rh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        let a = T::ONE / (far - near);
+        let b = -near / (far - near);
+        Matrix4::orthographic_with_depth(left, right, bottom, top, -T::ONE, a, b)
+    }
+
+    /// Left-handed orthographic projection with 0..1 depth.
+    pub fn orthographic_lh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        let a = T::ONE / (far - near);
+        let b = -near / (far - near);
+        Matrix4::orthographic_with_depth(left, right, bottom, top, T::ONE, a, b)
+    }
+
+    #[deprecated(note = "renamed to `try_perspective_gl`")]
+    pub fn try_perspective(fovy: T, aspect: T, near: T, far: T) -> Result<Matrix4<T>, MathError> {
+        Matrix4::try_perspective_gl(fovy, aspect, near, far)
+    }
+
+    #[deprecated(note = "renamed to `perspective_gl`, which has -1..1 depth unlike `perspective_rh`")]
+    pub fn perspective(fovy: T, aspect: T, near: T, far: T) -> Matrix4<T> {
+        Matrix4::perspective_gl(fovy, aspect, near, far)
+    }
+
+    #[deprecated(note = "renamed to `frustum_gl`, which has -1..1 depth unlike `frustum_rh`")]
+    pub fn frustum(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        Matrix4::frustum_gl(left, right, bottom, top, near, far)
+    }
+
+    #[deprecated(note = "renamed to `orthographic_gl`, which has -1..1 depth unlike `orthographic_rh`")]
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Matrix4<T> {
+        Matrix4::orthographic_gl(left, right, bottom, top, near, far)
+    }
+
+    // Coefficients `(a, b)` of `z_ndc = a + b / d` mapping view distance `d`
+    // to 0 at `zero` and 1 at `one`.
+    fn depth_zero_to_one(zero: T, one: T) -> (T, T) {
+        let b = zero * one / (zero - one);
+        (one / (one - zero), b)
+    }
+
+    fn perspective_fov(fovy: T, aspect: T, near: T, handedness: T, a: T, b: T) -> Matrix4<T> {
+        let top = near * (fovy * T::HALF).tan();
+        let right = top * aspect;
+        Matrix4::frustum_with_depth(-right, right, -top, top, near, handedness, a, b)
+    }
+
+    // `handedness` is -1 for right-handed and 1 for left-handed views, so the
+    // view distance is `d = handedness * z` and becomes clip `w`; clip `z` is
+    // `a * d + b`.
+    #[allow(clippy::too_many_arguments)]
+    fn frustum_with_depth(left: T, right: T, bottom: T, top: T, near: T, handedness: T, a: T, b: T) -> Matrix4<T> {
+        let w = right - left;
+        let h = top - bottom;
+        Matrix4::new(
+            T::TWO * near / w, T::ZERO, -handedness * (right + left) / w, T::ZERO,
+            T::ZERO, T::TWO * near / h, -handedness * (top + bottom) / h, T::ZERO,
+            T::ZERO, T::ZERO, handedness * a, b,
+            T::ZERO, T::ZERO, handedness, T::ZERO
+        )
+    }
+
+    // As `frustum_with_depth`, but linear: clip `z` is `a * d + b` and `w` is 1.
+    fn orthographic_with_depth(left: T, right: T, bottom: T, top: T, handedness: T, a: T, b: T) -> Matrix4<T> {
+        let w = right - left;
+        let h = top - bottom;
+        Matrix4::new(
+            T::TWO / w, T::ZERO, T::ZERO, -(right + left) / w,
+            T::ZERO, T::TWO / h, T::ZERO, -(top + bottom) / h,
+            T::ZERO, T::ZERO, handedness * a, b,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE
+        )
+    }
+}
+
+pub(crate) fn assert_batch_len(input: usize, output: usize) {
+    assert_eq!(input, output, "batch transform: input has {} elements but output has {}", input, output);
 }
 
-impl Add for Matrix4f {
+impl<T: Scalar> Add for Matrix4<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
         Self {
-            data: vec![
+            data: [
                 self.data[0] + other.data[0], self.data[1] + other.data[1], self.data[2] + other.data[2], self.data[3] + other.data[3],
                 self.data[4] + other.data[4], self.data[5] + other.data[5], self.data[6] + other.data[6], self.data[7] + other.data[7],
                 self.data[8] + other.data[8], self.data[9] + other.data[9], self.data[10] + other.data[10], self.data[11] + other.data[11],
@@ -278,10 +852,10 @@ impl Add for Matrix4f {
     }
 }
 
-impl AddAssign for Matrix4f {
+impl<T: Scalar> AddAssign for Matrix4<T> {
     fn add_assign(&mut self, other: Self) {
         *self = Self {
-            data: vec![
+            data: [
                 self.data[0] + other.data[0], self.data[1] + other.data[1], self.data[2] + other.data[2], self.data[3] + other.data[3],
                 self.data[4] + other.data[4], self.data[5] + other.data[5], self.data[6] + other.data[6], self.data[7] + other.data[7],
                 self.data[8] + other.data[8], self.data[9] + other.data[9], self.data[10] + other.data[10], self.data[11] + other.data[11],
@@ -291,12 +865,12 @@ impl AddAssign for Matrix4f {
     }
 }
 
-impl Sub for Matrix4f {
+impl<T: Scalar> Sub for Matrix4<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
         Self {
-            data: vec![
+            data: [
                 self.data[0] - other.data[0], self.data[1] - other.data[1], self.data[2] - other.data[2], self.data[3] - other.data[3],
                 self.data[4] - other.data[4], self.data[5] - other.data[5], self.data[6] - other.data[6], self.data[7] - other.data[7],
                 self.data[8] - other.data[8], self.data[9] - other.data[9], self.data[10] - other.data[10], self.data[11] - other.data[11],
@@ -306,10 +880,10 @@ impl Sub for Matrix4f {
     }
 }
 
-impl SubAssign for Matrix4f {
+impl<T: Scalar> SubAssign for Matrix4<T> {
     fn sub_assign(&mut self, other: Self) {
         *self = Self {
-            data: vec![
+            data: [
                 self.data[0] - other.data[0], self.data[1] - other.data[1], self.data[2] - other.data[2], self.data[3] - other.data[3],
                 self.data[4] - other.data[4], self.data[5] - other.data[5], self.data[6] - other.data[6], self.data[7] - other.data[7],
                 self.data[8] - other.data[8], self.data[9] - other.data[9], self.data[10] - other.data[10], self.data[11] - other.data[11],
@@ -319,66 +893,118 @@ impl SubAssign for Matrix4f {
     }
 }
 
-impl Mul for Matrix4f {
+impl<T: Scalar> Mul for Matrix4<T> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
+        if let Some(data) = T::simd_mat4_mul(&self.data, &other.data) {
+            return Self { data };
+        }
         Self {
-            data: vec![
-                (self.data[0]*other.data[0]) + (self.data[1]*other.data[4]) + (self.data[2]*other.data[8]) + (self.data[3]*self.data[12]),
-                (self.data[0]*other.data[1]) + (self.data[1]*other.data[5]) + (self.data[2]*other.data[9]) + (self.data[3]*self.data[13]),
-                (self.data[0]*other.data[2]) + (self.data[1]*other.data[6]) + (self.data[2]*other.data[10]) + (self.data[3]*self.data[14]),
-                (self.data[0]*other.data[3]) + (self.data[1]*other.data[7]) + (self.data[2]*other.data[11]) + (self.data[3]*self.data[15]),
-
-                (self.data[4]*other.data[0]) + (self.data[5]*other.data[4]) + (self.data[6]*other.data[8]) + (self.data[7]*self.data[12]),
-                (self.data[4]*other.data[1]) + (self.data[5]*other.data[5]) + (self.data[6]*other.data[9]) + (self.data[7]*self.data[13]),
-                (self.data[4]*other.data[2]) + (self.data[5]*other.data[6]) + (self.data[6]*other.data[10]) + (self.data[7]*self.data[14]),
-                (self.data[4]*other.data[3]) + (self.data[5]*other.data[7]) + (self.data[6]*other.data[11]) + (self.data[7]*self.data[15]),
-
-                (self.data[8]*other.data[0]) + (self.data[9]*other.data[4]) + (self.data[10]*other.data[8]) + (self.data[11]*self.data[12]),
-                (self.data[8]*other.data[1]) + (self.data[9]*other.data[5]) + (self.data[10]*other.data[9]) + (self.data[11]*self.data[13]),
-                (self.data[8]*other.data[2]) + (self.data[9]*other.data[6]) + (self.data[10]*other.data[10]) + (self.data[11]*self.data[14]),
-                (self.data[8]*other.data[3]) + (self.data[9]*other.data[7]) + (self.data[10]*other.data[11]) + (self.data[11]*self.data[15]),
-
-                (self.data[12]*other.data[0]) + (self.data[13]*other.data[4]) + (self.data[14]*other.data[8]) + (self.data[15]*self.data[12]),
-                (self.data[12]*other.data[1]) + (self.data[13]*other.data[5]) + (self.data[14]*other.data[9]) + (self.data[15]*self.data[13]),
-                (self.data[12]*other.data[2]) + (self.data[13]*other.data[6]) + (self.data[14]*other.data[10]) + (self.data[15]*self.data[14]),
-                (self.data[12]*other.data[3]) + (self.data[13]*other.data[7]) + (self.data[14]*other.data[11]) + (self.data[15]*self.data[15])
+            data: [
+                (self.data[0]*other.data[0]) + (self.data[1]*other.data[4]) + (self.data[2]*other.data[8]) + (self.data[3]*other.data[12]),
+                (self.data[0]*other.data[1]) + (self.data[1]*other.data[5]) + (self.data[2]*other.data[9]) + (self.data[3]*other.data[13]),
+                (self.data[0]*other.data[2]) + (self.data[1]*other.data[6]) + (self.data[2]*other.data[10]) + (self.data[3]*other.data[14]),
+                (self.data[0]*other.data[3]) + (self.data[1]*other.data[7]) + (self.data[2]*other.data[11]) + (self.data[3]*other.data[15]),
+
+                (self.data[4]*other.data[0]) + (self.data[5]*other.data[4]) + (self.data[6]*other.data[8]) + (self.data[7]*other.data[12]),
+                (self.data[4]*other.data[1]) + (self.data[5]*other.data[5]) + (self.data[6]*other.data[9]) + (self.data[7]*other.data[13]),
+                (self.data[4]*other.data[2]) + (self.data[5]*other.data[6]) + (self.data[6]*other.data[10]) + (self.data[7]*other.data[14]),
+                (self.data[4]*other.data[3]) + (self.data[5]*other.data[7]) + (self.data[6]*other.data[11]) + (self.data[7]*other.data[15]),
+
+                (self.data[8]*other.data[0]) + (self.data[9]*other.data[4]) + (self.data[10]*other.data[8]) + (self.data[11]*other.data[12]),
+                (self.data[8]*other.data[1]) + (self.data[9]*other.data[5]) + (self.data[10]*other.data[9]) + (self.data[11]*other.data[13]),
+                (self.data[8]*other.data[2]) + (self.data[9]*other.data[6]) + (self.data[10]*other.data[10]) + (self.data[11]*other.data[14]),
+                (self.data[8]*other.data[3]) + (self.data[9]*other.data[7]) + (self.data[10]*other.data[11]) + (self.data[11]*other.data[15]),
+
+                (self.data[12]*other.data[0]) + (self.data[13]*other.data[4]) + (self.data[14]*other.data[8]) + (self.data[15]*other.data[12]),
+                (self.data[12]*other.data[1]) + (self.data[13]*other.data[5]) + (self.data[14]*other.data[9]) + (self.data[15]*other.data[13]),
+                (self.data[12]*other.data[2]) + (self.data[13]*other.data[6]) + (self.data[14]*other.data[10]) + (self.data[15]*other.data[14]),
+                (self.data[12]*other.data[3]) + (self.data[13]*other.data[7]) + (self.data[14]*other.data[11]) + (self.data[15]*other.data[15])
             ]
         }
     }
 }
 
-impl MulAssign for Matrix4f {
+impl<T: Scalar> MulAssign for Matrix4<T> {
     fn mul_assign(&mut self, other: Self) {
-        *self = Self {
-            data: vec![
-                (self.data[0]*other.data[0]) + (self.data[1]*other.data[4]) + (self.data[2]*other.data[8]) + (self.data[3]*self.data[12]),
-                (self.data[0]*other.data[1]) + (self.data[1]*other.data[5]) + (self.data[2]*other.data[9]) + (self.data[3]*self.data[13]),
-                (self.data[0]*other.data[2]) + (self.data[1]*other.data[6]) + (self.data[2]*other.data[10]) + (self.data[3]*self.data[14]),
-                (self.data[0]*other.data[3]) + (self.data[1]*other.data[7]) + (self.data[2]*other.data[11]) + (self.data[3]*self.data[15]),
-
-                (self.data[4]*other.data[0]) + (self.data[5]*other.data[4]) + (self.data[6]*other.data[8]) + (self.data[7]*self.data[12]),
-                (self.data[4]*other.data[1]) + (self.data[5]*other.data[5]) + (self.data[6]*other.data[9]) + (self.data[7]*self.data[13]),
-                (self.data[4]*other.data[2]) + (self.data[5]*other.data[6]) + (self.data[6]*other.data[10]) + (self.data[7]*self.data[14]),
-                (self.data[4]*other.data[3]) + (self.data[5]*other.data[7]) + (self.data[6]*other.data[11]) + (self.data[7]*self.data[15]),
-
-                (self.data[8]*other.data[0]) + (self.data[9]*other.data[4]) + (self.data[10]*other.data[8]) + (self.data[11]*self.data[12]),
-                (self.data[8]*other.data[1]) + (self.data[9]*other.data[5]) + (self.data[10]*other.data[9]) + (self.data[11]*self.data[13]),
-                (self.data[8]*other.data[2]) + (self.data[9]*other.data[6]) + (self.data[10]*other.data[10]) + (self.data[11]*self.data[14]),
-                (self.data[8]*other.data[3]) + (self.data[9]*other.data[7]) + (self.data[10]*other.data[11]) + (self.data[11]*self.data[15]),
-
-                (self.data[12]*other.data[0]) + (self.data[13]*other.data[4]) + (self.data[14]*other.data[8]) + (self.data[15]*self.data[12]),
-                (self.data[12]*other.data[1]) + (self.data[13]*other.data[5]) + (self.data[14]*other.data[9]) + (self.data[15]*self.data[13]),
-                (self.data[12]*other.data[2]) + (self.data[13]*other.data[6]) + (self.data[14]*other.data[10]) + (self.data[15]*self.data[14]),
-                (self.data[12]*other.data[3]) + (self.data[13]*other.data[7]) + (self.data[14]*other.data[11]) + (self.data[15]*self.data[15])
-            ]
-        };
+        *self = *self * other;
+    }
+}
+
+impl<T: SignedScalar> Neg for Matrix4<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.scale(-T::ONE)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Matrix4<T> {
+    type Output = Self;
+
+    fn mul(self, s: T) -> Self {
+        self.scale(s)
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Matrix4<T> {
+    fn mul_assign(&mut self, s: T) {
+        *self = self.scale(s);
+    }
+}
+
+impl<T: Scalar> Div<T> for Matrix4<T> {
+    type Output = Self;
+
+    fn div(self, s: T) -> Self {
+        Self {
+            data: self.data.map(|v| v / s)
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Matrix4<T> {
+    fn div_assign(&mut self, s: T) {
+        *self = *self / s;
+    }
+}
+
+impl<T: Scalar> Mul<Vector4<T>> for Matrix4<T> {
+    type Output = Vector4<T>;
+
+    fn mul(self, v: Vector4<T>) -> Vector4<T> {
+        self.transform(v)
+    }
+}
+
+forward_ref_unop!(impl [T: SignedScalar] Neg, neg for Matrix4<T>);
+forward_ref_binop!(impl [T: Scalar] Add, add for Matrix4<T>, Matrix4<T>);
+forward_ref_binop!(impl [T: Scalar] Sub, sub for Matrix4<T>, Matrix4<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Matrix4<T>, Matrix4<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Matrix4<T>, T);
+forward_ref_binop!(impl [T: Scalar] Div, div for Matrix4<T>, T);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Matrix4<T>, Vector4<T>);
+forward_ref_op_assign!(impl [T: Scalar] AddAssign, add_assign for Matrix4<T>, Matrix4<T>);
+forward_ref_op_assign!(impl [T: Scalar] SubAssign, sub_assign for Matrix4<T>, Matrix4<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Matrix4<T>, Matrix4<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Matrix4<T>, T);
+forward_ref_op_assign!(impl [T: Scalar] DivAssign, div_assign for Matrix4<T>, T);
+
+impl_scalar_lhs_mul_matrix!(Matrix4: f32, f64);
+
+impl_approx_eq!(Matrix4 [data]);
+
+impl From<Matrix4<f32>> for Matrix4<f64> {
+    fn from(m: Matrix4<f32>) -> Matrix4<f64> {
+        Matrix4{
+            data: m.data.map(f64::from)
+        }
     }
 }
 
-impl From<Matrix4f> for [[f32; 4]; 4] {
-    fn from(m: Matrix4f) -> [[f32; 4]; 4] {
+impl<T: Scalar> From<Matrix4<T>> for [[T; 4]; 4] {
+    fn from(m: Matrix4<T>) -> [[T; 4]; 4] {
         [
             [m.data[0], m.data[1], m.data[2], m.data[3]],
             [m.data[4], m.data[5], m.data[6], m.data[7]],
@@ -388,13 +1014,26 @@ impl From<Matrix4f> for [[f32; 4]; 4] {
     }
 }
 
-impl PartialEq for Matrix4f {
+impl<T: Scalar> From<[T; 16]> for Matrix4<T> {
+    fn from(data: [T; 16]) -> Matrix4<T> {
+        Matrix4::from_array(data)
+    }
+}
+
+impl<T: Scalar> From<Matrix4<T>> for [T; 16] {
+    fn from(m: Matrix4<T>) -> [T; 16] {
+        m.data
+    }
+}
+
+impl<T: Scalar> AsRef<[T]> for Matrix4<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T: Scalar> PartialEq for Matrix4<T> {
     fn eq(&self, other: &Self) -> bool {
-        for i in 0..16 {
-            if self.data[i] != other.data[i] {
-                return false;
-            }
-        }
-        return true;
+        self.data == other.data
     }
-}
\ No newline at end of file
+}
@@ -1,16 +1,67 @@
+#[macro_use]
+mod macros;
+
+pub mod scalar;
+
+pub mod simd;
+
 pub mod vector4f;
 pub mod vector3f;
 pub mod vector2f;
 
+mod swizzle;
+
 pub mod matrix4f;
 pub mod matrix3f;
 
 pub mod quaternion;
 
+pub mod transform;
+
 pub mod angles;
 
-pub use vector2f::Vector2f;
-pub use vector3f::Vector3f;
-pub use vector4f::Vector4f;
-pub use matrix3f::Matrix3f;
-pub use matrix4f::Matrix4f;
\ No newline at end of file
+pub mod euler;
+
+pub mod aabb;
+
+pub mod plane;
+
+pub mod sphere;
+
+pub mod obb;
+
+pub mod triangle;
+
+pub mod segment;
+
+pub mod capsule;
+
+pub mod ray;
+
+pub mod frustum;
+
+pub mod error;
+
+pub mod approx;
+
+pub use scalar::{Scalar, SignedScalar, Float};
+pub use vector2f::{Vector2, Vector2f, Vector2d, Vector2i, Vector2u};
+pub use vector3f::{Vector3, Vector3f, Vector3d, Vector3i, Vector3u};
+pub use vector4f::{Vector4, Vector4f, Vector4d, Vector4i, Vector4u};
+pub use matrix3f::{Matrix3, Matrix3f, Matrix3d};
+pub use matrix4f::{Matrix4, Matrix4f, Matrix4d};
+pub use quaternion::{Quat, Quaternion, Quaterniond};
+pub use transform::{Transform, GlobalTransform, Parent, Children, TransformBundle};
+pub use angles::{Rad, Deg, Radf, Radd, Degf, Degd};
+pub use euler::EulerRot;
+pub use aabb::{Aabb, Aabbf, Aabbd, Aabb2, Aabb2f, Aabb2d};
+pub use plane::{Plane, Planef, Planed};
+pub use sphere::{Sphere, Spheref, Sphered};
+pub use obb::{Obb, Obbf, Obbd};
+pub use triangle::{Triangle, Trianglef, Triangled, Triangle2, Triangle2f, Triangle2d};
+pub use segment::{Segment, Segmentf, Segmentd};
+pub use capsule::{Capsule, Capsulef, Capsuled, Contact};
+pub use ray::{Ray, Rayf, Rayd, RayHit};
+pub use frustum::{Frustum, Frustumf, Frustumd, Containment};
+pub use error::MathError;
+pub use approx::ApproxEq;
\ No newline at end of file
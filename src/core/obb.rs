@@ -0,0 +1,253 @@
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::vector3f::Vector3;
+use crate::core::matrix3f::Matrix3;
+use crate::core::matrix4f::Matrix4;
+use crate::core::aabb::Aabb;
+use crate::core::sphere::Sphere;
+use crate::core::approx::ApproxEq;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// Oriented box spanning `half_extents` on either side of `center` along
+/// each of its axes. The columns of `orientation` are the box's local x, y
+/// and z axes in world space and must be orthonormal.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Obb<T: Scalar>{
+    pub center: Vector3<T>,
+    pub half_extents: Vector3<T>,
+    pub orientation: Matrix3<T>,
+}
+
+pub type Obbf = Obb<f32>;
+pub type Obbd = Obb<f64>;
+
+impl<T: Float> Obb<T>{
+    pub fn new(center: Vector3<T>, half_extents: Vector3<T>, orientation: Matrix3<T>) -> Self {
+        Obb{
+            center,
+            half_extents,
+            orientation,
+        }
+    }
+
+    /// An empty `aabb` gives a box with negative extents, which contains
+    /// nothing.
+    pub fn from_aabb(aabb: &Aabb<T>) -> Self {
+        Obb::new(aabb.center(), aabb.extents(), Matrix3::identity())
+    }
+
+    /// A box around every point, aligned with their principal axes, or
+    /// `None` if there are none. The axes are the eigenvectors of the
+    /// points' covariance, ordered from most to least spread, so the fit is
+    /// tight for elongated clouds but not the smallest possible box.
+    pub fn from_points(points: &[Vector3<T>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let n = T::from_f64(points.len() as f64);
+        let mean = points.iter().fold(Vector3::zero(), |s, &p| s + p) / n;
+        let mut covariance = [[T::ZERO; 3]; 3];
+        for &p in points {
+            let d = (p - mean).to_array();
+            for (i, row) in covariance.iter_mut().enumerate() {
+                for (j, c) in row.iter_mut().enumerate() {
+                    *c += d[i] * d[j];
+                }
+            }
+        }
+        let axes = principal_axes(covariance);
+
+        let mut lo = [T::INFINITY; 3];
+        let mut hi = [T::NEG_INFINITY; 3];
+        for &p in points {
+            for i in 0..3 {
+                let t = p.dot(axes[i]);
+                lo[i] = lo[i].partial_min(t);
+                hi[i] = hi[i].partial_max(t);
+            }
+        }
+        let center = (0..3).fold(Vector3::zero(), |c, i| c + axes[i] * ((lo[i] + hi[i]) * T::HALF));
+        let half_extents = Vector3::new(hi[0] - lo[0], hi[1] - lo[1], hi[2] - lo[2]) * T::HALF;
+        Some(Obb::new(center, half_extents, from_axes(axes)))
+    }
+
+    /// The columns of `orientation`.
+    pub fn axes(&self) -> [Vector3<T>; 3] {
+        let d = &self.orientation.data;
+        [0, 1, 2].map(|j| Vector3::new(d[j], d[3 + j], d[6 + j]))
+    }
+
+    pub fn corners(&self) -> [Vector3<T>; 8] {
+        let [x, y, z] = self.axes();
+        let e = self.half_extents;
+        let (x, y, z) = (x * e.x, y * e.y, z * e.z);
+        let c = self.center;
+        [
+            c - x - y - z,
+            c + x - y - z,
+            c - x + y - z,
+            c + x + y - z,
+            c - x - y + z,
+            c + x - y + z,
+            c - x + y + z,
+            c + x + y + z,
+        ]
+    }
+
+    pub fn to_aabb(&self) -> Aabb<T> {
+        let abs = Matrix3::from_array(self.orientation.data.map(|v| v.abs()));
+        Aabb::from_center_extents(self.center, abs.transform(self.half_extents))
+    }
+
+    /// The point in the box closest to `p`, which is `p` itself when it is
+    /// inside.
+    pub fn closest_point(&self, p: Vector3<T>) -> Vector3<T> {
+        let d = p - self.center;
+        let e = self.half_extents.to_array();
+        self.axes().iter().zip(e).fold(self.center, |q, (&axis, e)| {
+            q + axis * d.dot(axis).clamp(-e, e)
+        })
+    }
+
+    pub fn contains_point(&self, p: Vector3<T>) -> bool {
+        let d = p - self.center;
+        self.axes().iter().zip(self.half_extents.iter()).all(|(&axis, e)| d.dot(axis).abs() <= e)
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        sphere.contains_point(self.closest_point(sphere.center))
+    }
+
+    /// Separating axis test against the 15 candidate axes: the face normals
+    /// of both boxes and the cross products of their edges.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let a = self.axes();
+        let b = other.axes();
+        let ea = self.half_extents.to_array();
+        let eb = other.half_extents.to_array();
+
+        // `other`'s axes in `self`'s frame. The absolute values are padded
+        // so that nearly parallel edges, whose cross product is close to
+        // zero, do not produce a false separating axis.
+        let mut r = [[T::ZERO; 3]; 3];
+        let mut abs_r = [[T::ZERO; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = a[i].dot(b[j]);
+                abs_r[i][j] = r[i][j].abs() + T::tolerance();
+            }
+        }
+        let d = other.center - self.center;
+        let t = a.map(|axis| d.dot(axis));
+
+        for i in 0..3 {
+            let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+            if t[i].abs() > ea[i] + rb {
+                return false;
+            }
+        }
+        for j in 0..3 {
+            let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+            let dist = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if dist.abs() > ra + eb[j] {
+                return false;
+            }
+        }
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                let dist = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if dist.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// A box containing the transformed box, for an affine `m`. It is exact
+    /// unless `m` shears the box, in which case the box keeps the direction
+    /// of its transformed x axis and grows to fit.
+    pub fn transform(&self, m: &Matrix4<T>) -> Self {
+        let linear = m.to_matrix3();
+        let e = self.half_extents;
+        let [x, y, _] = self.axes().map(|axis| linear.transform(axis));
+        let [ax, ay, az] = self.axes();
+        let edges = [ax * e.x, ay * e.y, az * e.z].map(|edge| linear.transform(edge));
+
+        let x = x.normalize();
+        let y = (y - x * x.dot(y)).normalize();
+        let axes = [x, y, x.cross(y)];
+        let half_extents = axes.map(|axis| edges.iter().fold(T::ZERO, |s, edge| s + edge.dot(axis).abs()));
+        Obb::new(m.transform_point3(self.center), Vector3::from(half_extents), from_axes(axes))
+    }
+}
+
+fn from_axes<T: Scalar>(axes: [Vector3<T>; 3]) -> Matrix3<T> {
+    let [x, y, z] = axes;
+    Matrix3::new(
+        x.x, y.x, z.x,
+        x.y, y.y, z.y,
+        x.z, y.z, z.z
+    )
+}
+
+// Eigenvectors of a symmetric matrix by cyclic Jacobi rotations, ordered by
+// decreasing eigenvalue and made right-handed.
+fn principal_axes<T: Float>(m: [[T; 3]; 3]) -> [Vector3<T>; 3] {
+    let mut a = m;
+    let mut v = [[T::ONE, T::ZERO, T::ZERO], [T::ZERO, T::ONE, T::ZERO], [T::ZERO, T::ZERO, T::ONE]];
+    for _ in 0..32 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        let diag = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off <= T::tolerance() * T::tolerance() * diag {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == T::ZERO {
+                continue;
+            }
+            // the rotation that zeroes a[p][q]
+            let theta = (a[q][q] - a[p][p]) / (T::TWO * a[p][q]);
+            let sign = if theta < T::ZERO { -T::ONE } else { T::ONE };
+            let t = sign / (theta.abs() + (theta * theta + T::ONE).sqrt());
+            let c = T::ONE / (t * t + T::ONE).sqrt();
+            let s = t * c;
+            // a = J^T a J and v = v J
+            for row in a.iter_mut() {
+                let (kp, kq) = (row[p], row[q]);
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+            let (rp, rq) = (a[p], a[q]);
+            a[p] = [0, 1, 2].map(|k| c * rp[k] - s * rq[k]);
+            a[q] = [0, 1, 2].map(|k| s * rp[k] + c * rq[k]);
+            for row in v.iter_mut() {
+                let (kp, kq) = (row[p], row[q]);
+                row[p] = c * kp - s * kq;
+                row[q] = s * kp + c * kq;
+            }
+        }
+    }
+
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| a[j][j].partial_cmp(&a[i][i]).unwrap_or(std::cmp::Ordering::Equal));
+    let x = Vector3::new(v[0][order[0]], v[1][order[0]], v[2][order[0]]);
+    let y = Vector3::new(v[0][order[1]], v[1][order[1]], v[2][order[1]]);
+    [x, y, x.cross(y)]
+}
+
+impl_approx_eq!(Obb { center, half_extents, orientation });
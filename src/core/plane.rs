@@ -0,0 +1,165 @@
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::vector4f::Vector4;
+use crate::core::vector3f::Vector3;
+use crate::core::matrix4f::Matrix4;
+use crate::core::error::MathError;
+use crate::core::approx::ApproxEq;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// The plane of points `p` with `normal.dot(p) + distance == 0`, where
+/// `normal` has unit length. `distance` is the signed distance from the
+/// plane to the origin, so the plane `z = 1` with normal +z has distance -1.
+/// The normal side is the positive half-space.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Plane<T: Scalar>{
+    pub normal: Vector3<T>,
+    pub distance: T,
+}
+
+pub type Planef = Plane<f32>;
+pub type Planed = Plane<f64>;
+
+impl<T: Float> Plane<T>{
+    /// Scales `normal` and `distance` so the normal has unit length.
+    pub fn new(normal: Vector3<T>, distance: T) -> Self {
+        let m = normal.magnitude();
+        Plane{
+            normal: normal / m,
+            distance: distance / m,
+        }
+    }
+
+    pub fn try_new(normal: Vector3<T>, distance: T) -> Result<Self, MathError> {
+        if normal.magnitude() <= T::tolerance() {
+            return Err(MathError::ZeroLengthVector);
+        }
+        Ok(Plane::new(normal, distance))
+    }
+
+    pub fn from_point_normal(point: Vector3<T>, normal: Vector3<T>) -> Self {
+        let n = normal.normalize();
+        Plane{
+            normal: n,
+            distance: -n.dot(point),
+        }
+    }
+
+    /// The plane through `a`, `b` and `c`, facing the side from which they
+    /// appear counter-clockwise.
+    pub fn from_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Plane::from_point_normal(a, (b - a).cross(c - a))
+    }
+
+    /// Fails with `MathError::DegenerateGeometry` when the points are
+    /// collinear.
+    pub fn try_from_points(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Result<Self, MathError> {
+        let n = (b - a).cross(c - a);
+        if n.magnitude() <= T::tolerance() * (b - a).magnitude() * (c - a).magnitude() {
+            return Err(MathError::DegenerateGeometry);
+        }
+        Ok(Plane::from_point_normal(a, n))
+    }
+
+    /// The plane `(a, b, c, d)` with `a*x + b*y + c*z + d == 0`, normalized.
+    pub fn from_vector4(v: Vector4<T>) -> Self {
+        Plane::new(v.truncate(), v.w)
+    }
+
+    pub fn to_vector4(&self) -> Vector4<T> {
+        self.normal.extend(self.distance)
+    }
+
+    /// The same plane with the normal reversed.
+    pub fn flip(&self) -> Self {
+        Plane{
+            normal: -self.normal,
+            distance: -self.distance,
+        }
+    }
+
+    /// Positive on the normal side.
+    pub fn signed_distance(&self, p: Vector3<T>) -> T {
+        self.normal.dot(p) + self.distance
+    }
+
+    /// The point on the plane closest to `p`.
+    pub fn project_point(&self, p: Vector3<T>) -> Vector3<T> {
+        p - self.normal * self.signed_distance(p)
+    }
+
+    /// Mirrors points across the plane.
+    pub fn reflection_matrix(&self) -> Matrix4<T> {
+        let n = self.normal;
+        let d = self.distance;
+        let two = T::TWO;
+        Matrix4::new(
+            T::ONE - two*n.x*n.x, -two*n.x*n.y, -two*n.x*n.z, -two*n.x*d,
+            -two*n.y*n.x, T::ONE - two*n.y*n.y, -two*n.y*n.z, -two*n.y*d,
+            -two*n.z*n.x, -two*n.z*n.y, T::ONE - two*n.z*n.z, -two*n.z*d,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE
+        )
+    }
+
+    /// The plane containing the images under `m` of the points of `self`,
+    /// found by transforming `(normal, distance)` by the inverse transpose
+    /// of `m`.
+    pub fn transform(&self, m: &Matrix4<T>) -> Self {
+        Plane::from_vector4(m.inverse().transpose().transform(self.to_vector4()))
+    }
+
+    pub fn try_transform(&self, m: &Matrix4<T>) -> Result<Self, MathError> {
+        let v = m.try_inverse()?.transpose().transform(self.to_vector4());
+        Plane::try_new(v.truncate(), v.w)
+    }
+
+    /// The line where the planes meet, as a point on it and a unit
+    /// direction, or `None` if they are parallel.
+    pub fn intersect_plane(&self, other: &Self) -> Option<(Vector3<T>, Vector3<T>)> {
+        let dir = self.normal.cross(other.normal);
+        let len2 = dir.magnitude_squared();
+        if len2 <= T::tolerance() * T::tolerance() {
+            return None;
+        }
+        // the point on the line closest to the origin
+        let point = (dir.cross(other.normal) * self.distance + self.normal.cross(dir) * other.distance) / len2;
+        Some((point, dir / len2.sqrt()))
+    }
+
+    /// The single point shared by three planes, or `None` if any two are
+    /// parallel or all three share a line.
+    pub fn intersect_three(a: &Self, b: &Self, c: &Self) -> Option<Vector3<T>> {
+        let bc = b.normal.cross(c.normal);
+        let det = a.normal.dot(bc);
+        if det.abs() <= T::tolerance() {
+            return None;
+        }
+        let ca = c.normal.cross(a.normal);
+        let ab = a.normal.cross(b.normal);
+        Some((bc * a.distance + ca * b.distance + ab * c.distance) / -det)
+    }
+}
+
+impl<T: Float> From<Vector4<T>> for Plane<T> {
+    fn from(v: Vector4<T>) -> Plane<T> {
+        Plane::from_vector4(v)
+    }
+}
+
+impl<T: Scalar> From<Plane<T>> for Vector4<T> {
+    fn from(p: Plane<T>) -> Vector4<T> {
+        Vector4::new(p.normal.x, p.normal.y, p.normal.z, p.distance)
+    }
+}
+
+impl_approx_eq!(Plane { normal, distance });
@@ -0,0 +1,401 @@
+use std::ops::{
+    Mul,
+    MulAssign,
+};
+
+use crate::core::vector3f::Vector3;
+use crate::core::matrix3f::Matrix3;
+use crate::core::matrix4f::Matrix4;
+use crate::core::angles::Rad;
+
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::error::MathError;
+use crate::core::approx::ApproxEq;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+use bevy_ecs::prelude::Resource;
+
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Reflect, FromReflect, Resource)]
+#[repr(C)]
+pub struct Quat<T: Scalar>{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+/// `Quat` is generic over the float type; `Quaternion` is the `f32`
+/// rotation used throughout the crate.
+pub type Quaternion = Quat<f32>;
+pub type Quaterniond = Quat<f64>;
+
+impl<T: Float> Default for Quat<T> {
+    fn default() -> Self {
+        Quat::identity()
+    }
+}
+
+impl<T: Float> Quat<T>{
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Quat{
+            x,
+            y,
+            z,
+            w,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Quat{
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: T::ONE,
+        }
+    }
+
+    pub fn from_axis_angle(axis_in: Vector3<T>, angle: impl Into<Rad<T>>) -> Self {
+        let angle = angle.into().0;
+        let axis = axis_in.normalize();
+        let half = angle * T::HALF;
+        let s = half.sin();
+        Quat::new(axis.x * s, axis.y * s, axis.z * s, half.cos())
+    }
+
+    /// Builds a rotation from Euler angles in radians. The rotations are
+    /// applied about the fixed x, then y, then z axes, matching
+    /// `from_angle_z(z) * from_angle_y(y) * from_angle_x(x)`.
+    pub fn from_euler(x: impl Into<Rad<T>>, y: impl Into<Rad<T>>, z: impl Into<Rad<T>>) -> Self {
+        let (x, y, z) = (x.into().0, y.into().0, z.into().0);
+        let (sx, cx) = (x * T::HALF).sin_cos();
+        let (sy, cy) = (y * T::HALF).sin_cos();
+        let (sz, cz) = (z * T::HALF).sin_cos();
+        Quat::new(
+            sx * cy * cz - cx * sy * sz,
+            cx * sy * cz + sx * cy * sz,
+            cx * cy * sz - sx * sy * cz,
+            cx * cy * cz + sx * sy * sz
+        )
+    }
+
+    /// Expects `m` to be a pure rotation (orthonormal, determinant 1).
+    pub fn from_rotation_matrix(m: &Matrix3<T>) -> Self {
+        let m00 = m.data[0];
+        let m01 = m.data[1];
+        let m02 = m.data[2];
+        let m10 = m.data[3];
+        let m11 = m.data[4];
+        let m12 = m.data[5];
+        let m20 = m.data[6];
+        let m21 = m.data[7];
+        let m22 = m.data[8];
+
+        let trace = m00 + m11 + m22;
+        if trace > T::ZERO {
+            let s = (trace + T::ONE).sqrt() * T::TWO;
+            Quat::new(
+                (m21 - m12) / s,
+                (m02 - m20) / s,
+                (m10 - m01) / s,
+                T::from_f64(0.25) * s
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (T::ONE + m00 - m11 - m22).sqrt() * T::TWO;
+            Quat::new(
+                T::from_f64(0.25) * s,
+                (m01 + m10) / s,
+                (m02 + m20) / s,
+                (m21 - m12) / s
+            )
+        } else if m11 > m22 {
+            let s = (T::ONE + m11 - m00 - m22).sqrt() * T::TWO;
+            Quat::new(
+                (m01 + m10) / s,
+                T::from_f64(0.25) * s,
+                (m12 + m21) / s,
+                (m02 - m20) / s
+            )
+        } else {
+            let s = (T::ONE + m22 - m00 - m11).sqrt() * T::TWO;
+            Quat::new(
+                (m02 + m20) / s,
+                (m12 + m21) / s,
+                T::from_f64(0.25) * s,
+                (m10 - m01) / s
+            )
+        }
+    }
+
+    /// Uses the upper-left 3x3 of `m`, which must be a pure rotation.
+    pub fn from_rotation_matrix4(m: &Matrix4<T>) -> Self {
+        Quat::from_rotation_matrix(&Matrix3::new(
+            m.data[0], m.data[1], m.data[2],
+            m.data[4], m.data[5], m.data[6],
+            m.data[8], m.data[9], m.data[10]
+        ))
+    }
+
+    pub fn dot(&self, other: Self) -> T {
+        (self.x * other.x) + (self.y * other.y) + (self.z * other.z) + (self.w * other.w)
+    }
+
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(*self)
+    }
+
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalize(&self) -> Self {
+        let m = self.magnitude();
+        Quat::new(self.x / m, self.y / m, self.z / m, self.w / m)
+    }
+
+    pub fn try_normalize(&self) -> Result<Self, MathError> {
+        let m = self.magnitude();
+        if m <= T::tolerance() {
+            return Err(MathError::ZeroLengthVector);
+        }
+        Ok(Quat::new(self.x / m, self.y / m, self.z / m, self.w / m))
+    }
+
+    pub fn scale(&self, s: T) -> Self {
+        Quat::new(self.x * s, self.y * s, self.z * s, self.w * s)
+    }
+
+    /// Converts each component with `as` semantics, e.g. `Quaterniond` to
+    /// `Quaternion` rounds to the nearest `f32`. The result is not
+    /// renormalized.
+    pub fn cast<U: Float>(&self) -> Quat<U> {
+        Quat::new(
+            U::from_f64(self.x.to_f64()),
+            U::from_f64(self.y.to_f64()),
+            U::from_f64(self.z.to_f64()),
+            U::from_f64(self.w.to_f64())
+        )
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Quat::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    pub fn inverse(&self) -> Self {
+        let m = self.magnitude_squared();
+        let c = self.conjugate();
+        Quat::new(c.x / m, c.y / m, c.z / m, c.w / m)
+    }
+
+    /// Natural logarithm of a unit quaternion, returned as a pure quaternion.
+    pub fn log(&self) -> Self {
+        let theta = self.w.clamp(-T::ONE, T::ONE).acos();
+        let sin_theta = theta.sin();
+        if sin_theta.abs() < T::tolerance() {
+            return Quat::new(T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+        }
+        let k = theta / sin_theta;
+        Quat::new(self.x * k, self.y * k, self.z * k, T::ZERO)
+    }
+
+    /// Exponential of a pure quaternion (the `w` component is ignored).
+    pub fn exp(&self) -> Self {
+        let theta = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        if theta < T::tolerance() {
+            return Quat::new(self.x, self.y, self.z, cos_theta);
+        }
+        let k = sin_theta / theta;
+        Quat::new(self.x * k, self.y * k, self.z * k, cos_theta)
+    }
+
+    /// Normalized linear interpolation along the shortest path. Cheaper than
+    /// `slerp` but does not move at constant angular velocity.
+    pub fn nlerp(&self, other: Self, t: T) -> Self {
+        let end = if self.dot(other) < T::ZERO { other.scale(-T::ONE) } else { other };
+        Quat::new(
+            self.x + (end.x - self.x) * t,
+            self.y + (end.y - self.y) * t,
+            self.z + (end.z - self.z) * t,
+            self.w + (end.w - self.w) * t
+        ).normalize()
+    }
+
+    /// Spherical linear interpolation along the shortest path.
+    pub fn slerp(&self, other: Self, t: T) -> Self {
+        let end = if self.dot(other) < T::ZERO { other.scale(-T::ONE) } else { other };
+        self.slerp_unchecked(end, t)
+    }
+
+    // slerp without flipping `other` onto the same hemisphere, as required
+    // by the inner interpolations of squad.
+    fn slerp_unchecked(&self, other: Self, t: T) -> Self {
+        let d = self.dot(other).clamp(-T::ONE, T::ONE);
+        if d > T::from_f64(0.9995) {
+            return Quat::new(
+                self.x + (other.x - self.x) * t,
+                self.y + (other.y - self.y) * t,
+                self.z + (other.z - self.z) * t,
+                self.w + (other.w - self.w) * t
+            ).normalize();
+        }
+        if d < T::tolerance() - T::ONE {
+            // antipodal keys leave the great circle undefined, so turn
+            // through a quaternion perpendicular to `self` instead
+            let perp = Quat::new(-self.y, self.x, -self.w, self.z);
+            let (b, a) = (t * T::PI).sin_cos();
+            return Quat::new(
+                self.x * a + perp.x * b,
+                self.y * a + perp.y * b,
+                self.z * a + perp.z * b,
+                self.w * a + perp.w * b
+            );
+        }
+        let theta = d.acos();
+        let sin_theta = theta.sin();
+        let a = ((T::ONE - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        Quat::new(
+            self.x * a + other.x * b,
+            self.y * a + other.y * b,
+            self.z * a + other.z * b,
+            self.w * a + other.w * b
+        )
+    }
+
+    /// Inner control point for the key `current` of a squad spline, given its
+    /// neighbouring keys.
+    pub fn squad_control_point(prev: Self, current: Self, next: Self) -> Self {
+        let inv = current.inverse();
+        let a = (inv * next).log();
+        let b = (inv * prev).log();
+        let c = Quat::new(
+            -(a.x + b.x) * T::from_f64(0.25),
+            -(a.y + b.y) * T::from_f64(0.25),
+            -(a.z + b.z) * T::from_f64(0.25),
+            T::ZERO
+        );
+        current * c.exp()
+    }
+
+    /// Spherical quadrangle interpolation between `q1` and `q2` using the
+    /// control points `s1` and `s2` from `squad_control_point`. The keys are
+    /// used as given and not flipped onto one hemisphere; if two of them are
+    /// antipodal, the path between them passes through a perpendicular
+    /// quaternion.
+    pub fn squad(q1: Self, q2: Self, s1: Self, s2: Self, t: T) -> Self {
+        let a = q1.slerp_unchecked(q2, t);
+        let b = s1.slerp_unchecked(s2, t);
+        a.slerp_unchecked(b, T::TWO * t * (T::ONE - t))
+    }
+
+    /// Evaluates a C1-continuous squad spline through `keys`. `t` runs from
+    /// `T::ZERO` at the first key to `keys.len() - 1` at the last, and is clamped
+    /// to that range. The end keys are used as their own neighbours.
+    pub fn squad_spline(keys: &[Quat<T>], t: T) -> Self {
+        let n = keys.len();
+        if n == 0 {
+            return Quat::identity();
+        }
+        if n == 1 {
+            return keys[0];
+        }
+
+        let t = t.clamp(T::ZERO, T::from_f64((n - 1) as f64));
+        let i = (t.floor().to_f64() as usize).min(n - 2);
+        let local = t - T::from_f64(i as f64);
+
+        // keep every key on the same hemisphere as its predecessor so each
+        // segment takes the short way round
+        let mut aligned = [keys[i.saturating_sub(1)], keys[i], keys[i + 1], keys[(i + 2).min(n - 1)]];
+        for k in 1..4 {
+            if aligned[k - 1].dot(aligned[k]) < T::ZERO {
+                aligned[k] = aligned[k].scale(-T::ONE);
+            }
+        }
+        let [q0, q1, q2, q3] = aligned;
+
+        let s1 = Quat::squad_control_point(q0, q1, q2);
+        let s2 = Quat::squad_control_point(q1, q2, q3);
+        Quat::squad(q1, q2, s1, s2, local)
+    }
+
+    /// Rotates `v` by this quaternion, which is assumed to be normalized.
+    pub fn rotate(&self, v: Vector3<T>) -> Vector3<T> {
+        let u = Vector3::new(self.x, self.y, self.z);
+        let t = u.cross(v).scale(T::TWO);
+        v + t.scale(self.w) + u.cross(t)
+    }
+
+    pub fn to_matrix3(&self) -> Matrix3<T> {
+        let xx = self.x * self.x;
+        let yy = self.y * self.y;
+        let zz = self.z * self.z;
+        let xy = self.x * self.y;
+        let xz = self.x * self.z;
+        let yz = self.y * self.z;
+        let wx = self.w * self.x;
+        let wy = self.w * self.y;
+        let wz = self.w * self.z;
+
+        Matrix3::new(
+            T::ONE - T::TWO*(yy + zz), T::TWO*(xy - wz), T::TWO*(xz + wy),
+            T::TWO*(xy + wz), T::ONE - T::TWO*(xx + zz), T::TWO*(yz - wx),
+            T::TWO*(xz - wy), T::TWO*(yz + wx), T::ONE - T::TWO*(xx + yy)
+        )
+    }
+
+    pub fn to_matrix4(&self) -> Matrix4<T> {
+        let m = self.to_matrix3();
+        Matrix4::new(
+            m.data[0], m.data[1], m.data[2], T::ZERO,
+            m.data[3], m.data[4], m.data[5], T::ZERO,
+            m.data[6], m.data[7], m.data[8], T::ZERO,
+            T::ZERO, T::ZERO, T::ZERO, T::ONE
+        )
+    }
+}
+
+impl<T: Float> Mul for Quat<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.w*other.x + self.x*other.w + self.y*other.z - self.z*other.y,
+            y: self.w*other.y - self.x*other.z + self.y*other.w + self.z*other.x,
+            z: self.w*other.z + self.x*other.y - self.y*other.x + self.z*other.w,
+            w: self.w*other.w - self.x*other.x - self.y*other.y - self.z*other.z
+        }
+    }
+}
+
+impl<T: Float> MulAssign for Quat<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl_approx_eq!(Quat { x, y, z, w });
+
+impl_lossless_from!(Quat { x, y, z, w }: f32 => f64);
+
+impl<T: Scalar> From<Quat<T>> for [T; 4] {
+    fn from(q: Quat<T>) -> [T; 4] {
+        [q.x, q.y, q.z, q.w]
+    }
+}
+
+impl<T: Scalar> PartialEq for Quat<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    }
+}
@@ -0,0 +1,233 @@
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::vector3f::Vector3;
+use crate::core::vector2f::Vector2;
+use crate::core::matrix4f::Matrix4;
+use crate::core::aabb::Aabb;
+use crate::core::plane::Plane;
+use crate::core::error::MathError;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// A half-line from `origin` along the unit vector `direction`, so hit
+/// distances are in world units.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Ray<T: Scalar>{
+    pub origin: Vector3<T>,
+    pub direction: Vector3<T>,
+}
+
+pub type Rayf = Ray<f32>;
+pub type Rayd = Ray<f64>;
+
+/// The nearest intersection in front of a ray's origin.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+pub struct RayHit<T: Scalar>{
+    pub distance: T,
+    /// Unit surface normal at the hit point.
+    pub normal: Vector3<T>,
+}
+
+impl<T: Float> Ray<T>{
+    /// Normalizes `direction`.
+    pub fn new(origin: Vector3<T>, direction: Vector3<T>) -> Self {
+        Ray{
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    pub fn try_new(origin: Vector3<T>, direction: Vector3<T>) -> Result<Self, MathError> {
+        Ok(Ray{
+            origin,
+            direction: direction.try_normalize()?,
+        })
+    }
+
+    /// The point `distance` along the ray.
+    pub fn at(&self, distance: T) -> Vector3<T> {
+        self.origin + self.direction * distance
+    }
+
+    /// Builds a picking ray through `ndc`, in normalized device coordinates,
+    /// from the inverse of a view-projection matrix with OpenGL depth, such
+    /// as `perspective_gl(..) * look_at_rh(..)`. The ray starts on the near
+    /// plane.
+    pub fn from_ndc(ndc: Vector2<T>, inverse_view_proj: &Matrix4<T>) -> Self {
+        Ray::between_depths(ndc, inverse_view_proj, -T::ONE, T::ONE)
+    }
+
+    /// `from_ndc` for a 0..1 depth range, as produced by `perspective_rh`
+    /// and the other suffixed projections. Reversed-Z matrices put the near
+    /// plane at depth 1, so a ray built here from one starts on the far plane
+    /// and points at the camera.
+    pub fn from_ndc_zero_to_one(ndc: Vector2<T>, inverse_view_proj: &Matrix4<T>) -> Self {
+        Ray::between_depths(ndc, inverse_view_proj, T::ZERO, T::ONE)
+    }
+
+    fn between_depths(ndc: Vector2<T>, inverse_view_proj: &Matrix4<T>, near: T, far: T) -> Self {
+        let near = inverse_view_proj.project_point3(ndc.extend(near));
+        let far = inverse_view_proj.project_point3(ndc.extend(far));
+        Ray::new(near, far - near)
+    }
+
+    /// `from_ndc` for a point in pixels, with the origin at the top-left of
+    /// a viewport of `viewport_size` pixels.
+    pub fn from_screen(screen: Vector2<T>, viewport_size: Vector2<T>, view_proj: &Matrix4<T>) -> Self {
+        Ray::from_ndc(screen_to_ndc(screen, viewport_size), &view_proj.inverse())
+    }
+
+    pub fn try_from_screen(screen: Vector2<T>, viewport_size: Vector2<T>, view_proj: &Matrix4<T>) -> Result<Self, MathError> {
+        Ok(Ray::from_ndc(screen_to_ndc(screen, viewport_size), &view_proj.try_inverse()?))
+    }
+
+    /// `from_screen` for a 0..1 depth range.
+    pub fn from_screen_zero_to_one(screen: Vector2<T>, viewport_size: Vector2<T>, view_proj: &Matrix4<T>) -> Self {
+        Ray::from_ndc_zero_to_one(screen_to_ndc(screen, viewport_size), &view_proj.inverse())
+    }
+
+    pub fn try_from_screen_zero_to_one(screen: Vector2<T>, viewport_size: Vector2<T>, view_proj: &Matrix4<T>) -> Result<Self, MathError> {
+        Ok(Ray::from_ndc_zero_to_one(screen_to_ndc(screen, viewport_size), &view_proj.try_inverse()?))
+    }
+
+    /// The hit normal faces the ray's origin. Rays parallel to the plane
+    /// miss.
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<RayHit<T>> {
+        let n = plane.normal;
+        let denom = n.dot(self.direction);
+        if denom.abs() <= T::tolerance() {
+            return None;
+        }
+        let distance = -plane.signed_distance(self.origin) / denom;
+        if distance < T::ZERO {
+            return None;
+        }
+        Some(RayHit{
+            distance,
+            normal: if denom > T::ZERO { -n } else { n },
+        })
+    }
+
+    /// Intersects a solid sphere. From inside, the hit is where the ray
+    /// leaves the sphere. The hit normal points outward.
+    pub fn intersect_sphere(&self, center: Vector3<T>, radius: T) -> Option<RayHit<T>> {
+        let oc = self.origin - center;
+        let b = oc.dot(self.direction);
+        let c = oc.magnitude_squared() - radius * radius;
+        // outside and pointing away
+        if c > T::ZERO && b > T::ZERO {
+            return None;
+        }
+        let disc = b * b - c;
+        if disc < T::ZERO {
+            return None;
+        }
+        let root = disc.sqrt();
+        let distance = if -b - root >= T::ZERO { -b - root } else { -b + root };
+        Some(RayHit{
+            distance,
+            normal: (self.at(distance) - center) / radius,
+        })
+    }
+
+    /// Intersects a solid box with the slab method. From inside, the hit is
+    /// where the ray leaves the box. The hit normal is the outward normal of
+    /// the face that was hit.
+    pub fn intersect_aabb(&self, aabb: &Aabb<T>) -> Option<RayHit<T>> {
+        let o = self.origin.to_array();
+        let d = self.direction.to_array();
+        let (lo, hi) = (aabb.min.to_array(), aabb.max.to_array());
+
+        // entry and exit distances, with the axis each was found on
+        let (mut enter, mut enter_axis) = (T::NEG_INFINITY, 0);
+        let (mut exit, mut exit_axis) = (T::INFINITY, 0);
+        for i in 0..3 {
+            if d[i] == T::ZERO {
+                if o[i] < lo[i] || o[i] > hi[i] {
+                    return None;
+                }
+                continue;
+            }
+            let inv = T::ONE / d[i];
+            let t1 = (lo[i] - o[i]) * inv;
+            let t2 = (hi[i] - o[i]) * inv;
+            let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+            if near > enter {
+                enter = near;
+                enter_axis = i;
+            }
+            if far < exit {
+                exit = far;
+                exit_axis = i;
+            }
+        }
+        if exit < enter || exit < T::ZERO {
+            return None;
+        }
+
+        let (distance, axis, sign) = if enter >= T::ZERO {
+            (enter, enter_axis, -d[enter_axis])
+        } else {
+            (exit, exit_axis, d[exit_axis])
+        };
+        let mut normal = [T::ZERO; 3];
+        normal[axis] = if sign > T::ZERO { T::ONE } else { -T::ONE };
+        Some(RayHit{
+            distance,
+            normal: Vector3::from(normal),
+        })
+    }
+
+    /// Intersects the triangle `a, b, c` from either side with the
+    /// Möller–Trumbore algorithm. The hit normal faces the ray's origin.
+    pub fn intersect_triangle(&self, a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Option<RayHit<T>> {
+        let e1 = b - a;
+        let e2 = c - a;
+        let p = self.direction.cross(e2);
+        let det = e1.dot(p);
+        // parallel to the triangle's plane, or a degenerate triangle
+        if det.abs() <= T::tolerance() * e1.magnitude() * e2.magnitude() {
+            return None;
+        }
+        let inv_det = T::ONE / det;
+
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if u < T::ZERO || u > T::ONE {
+            return None;
+        }
+        let q = s.cross(e1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < T::ZERO || u + v > T::ONE {
+            return None;
+        }
+        let distance = e2.dot(q) * inv_det;
+        if distance < T::ZERO {
+            return None;
+        }
+
+        // det = -direction . (e1 x e2), so a positive det means e1 x e2
+        // already faces the origin.
+        let n = e1.cross(e2).normalize();
+        Some(RayHit{
+            distance,
+            normal: if det > T::ZERO { n } else { -n },
+        })
+    }
+}
+
+fn screen_to_ndc<T: Float>(screen: Vector2<T>, viewport_size: Vector2<T>) -> Vector2<T> {
+    Vector2::new(
+        T::TWO * screen.x / viewport_size.x - T::ONE,
+        T::ONE - T::TWO * screen.y / viewport_size.y
+    )
+}
@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+use std::ops::{
+    Add,
+    AddAssign,
+    Sub,
+    SubAssign,
+    Mul,
+    MulAssign,
+    Div,
+    DivAssign,
+    Rem,
+    Neg,
+};
+
+use crate::core::error::DEFAULT_TOLERANCE;
+use crate::core::simd::SimdKernels;
+
+/// Element type of every vector and matrix in `core`. Implemented for `f32`,
+/// `f64`, `i32` and `u32`.
+pub trait Scalar:
+    Copy + Debug + Default + PartialEq + PartialOrd + Send + Sync + 'static
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+    + AddAssign + SubAssign + MulAssign + DivAssign + SimdKernels
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Converts with the semantics of an `as` cast: floats round to nearest,
+    /// integers truncate toward zero and saturate at their bounds.
+    fn from_f64(v: f64) -> Self;
+
+    /// Every supported scalar converts to `f64` exactly.
+    fn to_f64(self) -> f64;
+
+    /// The smaller of the two values. Named to avoid clashing with
+    /// `Ord::min` and the inherent float methods.
+    fn partial_min(self, other: Self) -> Self {
+        if other < self { other } else { self }
+    }
+
+    fn partial_max(self, other: Self) -> Self {
+        if other > self { other } else { self }
+    }
+}
+
+pub trait SignedScalar: Scalar + Neg<Output = Self> {
+    fn abs(self) -> Self;
+}
+
+pub trait Float: SignedScalar {
+    const TWO: Self;
+    const HALF: Self;
+    const PI: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn is_finite(self) -> bool;
+
+    /// `DEFAULT_TOLERANCE` in this precision.
+    fn tolerance() -> Self {
+        Self::from_f64(DEFAULT_TOLERANCE as f64)
+    }
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = 0 as $t;
+                const ONE: Self = 1 as $t;
+
+                fn from_f64(v: f64) -> Self {
+                    v as $t
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl SignedScalar for $t {
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {
+        $(
+            impl Float for $t {
+                const TWO: Self = 2.0;
+                const HALF: Self = 0.5;
+                const PI: Self = std::$t::consts::PI;
+                const INFINITY: Self = <$t>::INFINITY;
+                const NEG_INFINITY: Self = <$t>::NEG_INFINITY;
+
+                fn sqrt(self) -> Self { <$t>::sqrt(self) }
+                fn sin(self) -> Self { <$t>::sin(self) }
+                fn cos(self) -> Self { <$t>::cos(self) }
+                fn tan(self) -> Self { <$t>::tan(self) }
+                fn asin(self) -> Self { <$t>::asin(self) }
+                fn acos(self) -> Self { <$t>::acos(self) }
+                fn atan(self) -> Self { <$t>::atan(self) }
+                fn atan2(self, other: Self) -> Self { <$t>::atan2(self, other) }
+                fn sin_cos(self) -> (Self, Self) { <$t>::sin_cos(self) }
+                fn floor(self) -> Self { <$t>::floor(self) }
+                fn ceil(self) -> Self { <$t>::ceil(self) }
+                fn round(self) -> Self { <$t>::round(self) }
+                fn clamp(self, min: Self, max: Self) -> Self { <$t>::clamp(self, min, max) }
+                fn is_finite(self) -> bool { <$t>::is_finite(self) }
+            }
+        )*
+    };
+}
+
+impl_scalar!(f32, f64, i32, u32);
+impl_signed_scalar!(f32, f64, i32);
+impl_float!(f32, f64);
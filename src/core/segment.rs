@@ -0,0 +1,112 @@
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::vector3f::Vector3;
+use crate::core::approx::ApproxEq;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// The line segment from `start` to `end`. Points along it are addressed
+/// by a parameter from 0 at `start` to 1 at `end`.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Segment<T: Scalar>{
+    pub start: Vector3<T>,
+    pub end: Vector3<T>,
+}
+
+pub type Segmentf = Segment<f32>;
+pub type Segmentd = Segment<f64>;
+
+impl<T: Float> Segment<T>{
+    pub fn new(start: Vector3<T>, end: Vector3<T>) -> Self {
+        Segment{
+            start,
+            end,
+        }
+    }
+
+    /// `end - start`, not normalized.
+    pub fn direction(&self) -> Vector3<T> {
+        self.end - self.start
+    }
+
+    pub fn length(&self) -> T {
+        self.direction().magnitude()
+    }
+
+    pub fn at(&self, t: T) -> Vector3<T> {
+        self.start + self.direction() * t
+    }
+
+    /// The parameter of the point on the segment closest to `p`.
+    pub fn closest_parameter(&self, p: Vector3<T>) -> T {
+        let d = self.direction();
+        let len2 = d.magnitude_squared();
+        if len2 == T::ZERO {
+            return T::ZERO;
+        }
+        ((p - self.start).dot(d) / len2).clamp(T::ZERO, T::ONE)
+    }
+
+    pub fn closest_point(&self, p: Vector3<T>) -> Vector3<T> {
+        self.at(self.closest_parameter(p))
+    }
+
+    pub fn distance_squared_to_point(&self, p: Vector3<T>) -> T {
+        (p - self.closest_point(p)).magnitude_squared()
+    }
+
+    pub fn distance_to_point(&self, p: Vector3<T>) -> T {
+        self.distance_squared_to_point(p).sqrt()
+    }
+
+    /// The closest pair of points, the first on `self` and the second on
+    /// `other`. Parallel segments have many such pairs; one is returned.
+    pub fn closest_points(&self, other: &Self) -> (Vector3<T>, Vector3<T>) {
+        let d1 = self.direction();
+        let d2 = other.direction();
+        let r = self.start - other.start;
+        let (a, e, f) = (d1.magnitude_squared(), d2.magnitude_squared(), d2.dot(r));
+
+        // either segment may be a single point
+        let (s, t) = if a == T::ZERO && e == T::ZERO {
+            (T::ZERO, T::ZERO)
+        } else if a == T::ZERO {
+            (T::ZERO, (f / e).clamp(T::ZERO, T::ONE))
+        } else {
+            let c = d1.dot(r);
+            if e == T::ZERO {
+                ((-c / a).clamp(T::ZERO, T::ONE), T::ZERO)
+            } else {
+                let b = d1.dot(d2);
+                let denom = a * e - b * b;
+                // the closest point on the line through `self` to the line
+                // through `other`, or `start` if they are parallel
+                let s = if denom > T::tolerance() * a * e {
+                    ((b * f - c * e) / denom).clamp(T::ZERO, T::ONE)
+                } else {
+                    T::ZERO
+                };
+                let t = (b * s + f) / e;
+                if t < T::ZERO {
+                    ((-c / a).clamp(T::ZERO, T::ONE), T::ZERO)
+                } else if t > T::ONE {
+                    (((b - c) / a).clamp(T::ZERO, T::ONE), T::ONE)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.at(s), other.at(t))
+    }
+}
+
+impl_approx_eq!(Segment { start, end });
@@ -0,0 +1,161 @@
+//! Hooks that let a scalar type replace the portable vector and matrix
+//! arithmetic with SIMD kernels.
+//!
+//! Every hook returns `None` by default, which selects the portable code.
+//! With the `simd` feature enabled on x86_64, `f32` implements them with
+//! SSE2, backing `Vector4f`, `Vector3f` and `Matrix4f`. On other targets the
+//! feature has no effect. The kernels load from and store to temporary
+//! arrays, so the feature never changes the size or alignment of a type;
+//! `Vector3f` stays 12 bytes and is padded to four lanes only in registers.
+//!
+//! Element-wise operations, `Matrix4::transform` and the matrix product
+//! perform the same multiplications and additions in the same order as the
+//! portable code, so their results are bit-identical. Dot products (and
+//! through them `magnitude` and `normalize`) sum the lane products pairwise,
+//! `(x + z) + (y + w)` rather than left to right; the two results differ by
+//! at most `2 * f32::EPSILON * (|x| + |y| + |z| + |w|)`, where `x..w` are the
+//! lane products.
+
+/// Implemented by every `Scalar`. Only `f32` overrides the hooks.
+#[doc(hidden)]
+pub trait SimdKernels: Sized {
+    #[inline]
+    fn simd_add4(_a: [Self; 4], _b: [Self; 4]) -> Option<[Self; 4]> {
+        None
+    }
+
+    #[inline]
+    fn simd_sub4(_a: [Self; 4], _b: [Self; 4]) -> Option<[Self; 4]> {
+        None
+    }
+
+    #[inline]
+    fn simd_mul4(_a: [Self; 4], _b: [Self; 4]) -> Option<[Self; 4]> {
+        None
+    }
+
+    #[inline]
+    fn simd_div4(_a: [Self; 4], _b: [Self; 4]) -> Option<[Self; 4]> {
+        None
+    }
+
+    #[inline]
+    fn simd_scale4(_a: [Self; 4], _s: Self) -> Option<[Self; 4]> {
+        None
+    }
+
+    #[inline]
+    fn simd_dot4(_a: [Self; 4], _b: [Self; 4]) -> Option<Self> {
+        None
+    }
+
+    /// Product of two row-major 4x4 matrices.
+    #[inline]
+    fn simd_mat4_mul(_a: &[Self; 16], _b: &[Self; 16]) -> Option<[Self; 16]> {
+        None
+    }
+
+    /// Row-major 4x4 matrix times a column vector.
+    #[inline]
+    fn simd_mat4_transform(_m: &[Self; 16], _v: [Self; 4]) -> Option<[Self; 4]> {
+        None
+    }
+}
+
+impl SimdKernels for f64 {}
+impl SimdKernels for i32 {}
+impl SimdKernels for u32 {}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl SimdKernels for f32 {}
+
+// SAFETY: SSE and SSE2 are part of the x86_64 baseline, so every intrinsic
+// used below is available on all targets this module is compiled for. Loads
+// and stores are unaligned and stay within the arrays they are given.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod sse2 {
+    use std::arch::x86_64::*;
+
+    use super::SimdKernels;
+
+    #[inline(always)]
+    unsafe fn load(a: [f32; 4]) -> __m128 {
+        _mm_loadu_ps(a.as_ptr())
+    }
+
+    #[inline(always)]
+    unsafe fn store(v: __m128) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        _mm_storeu_ps(out.as_mut_ptr(), v);
+        out
+    }
+
+    impl SimdKernels for f32 {
+        #[inline]
+        fn simd_add4(a: [f32; 4], b: [f32; 4]) -> Option<[f32; 4]> {
+            unsafe { Some(store(_mm_add_ps(load(a), load(b)))) }
+        }
+
+        #[inline]
+        fn simd_sub4(a: [f32; 4], b: [f32; 4]) -> Option<[f32; 4]> {
+            unsafe { Some(store(_mm_sub_ps(load(a), load(b)))) }
+        }
+
+        #[inline]
+        fn simd_mul4(a: [f32; 4], b: [f32; 4]) -> Option<[f32; 4]> {
+            unsafe { Some(store(_mm_mul_ps(load(a), load(b)))) }
+        }
+
+        #[inline]
+        fn simd_div4(a: [f32; 4], b: [f32; 4]) -> Option<[f32; 4]> {
+            unsafe { Some(store(_mm_div_ps(load(a), load(b)))) }
+        }
+
+        #[inline]
+        fn simd_scale4(a: [f32; 4], s: f32) -> Option<[f32; 4]> {
+            unsafe { Some(store(_mm_mul_ps(load(a), _mm_set1_ps(s)))) }
+        }
+
+        #[inline]
+        fn simd_dot4(a: [f32; 4], b: [f32; 4]) -> Option<f32> {
+            unsafe {
+                let p = _mm_mul_ps(load(a), load(b));
+                // [x + z, y + w, ..], then (x + z) + (y + w).
+                let s = _mm_add_ps(p, _mm_movehl_ps(p, p));
+                let s = _mm_add_ss(s, _mm_shuffle_ps(s, s, 0b01));
+                Some(_mm_cvtss_f32(s))
+            }
+        }
+
+        // Row i of the product is the sum of row k of `b` scaled by a[i][k],
+        // accumulated in the same order as the portable code.
+        #[inline]
+        fn simd_mat4_mul(a: &[f32; 16], b: &[f32; 16]) -> Option<[f32; 16]> {
+            unsafe {
+                let rows: [__m128; 4] = std::array::from_fn(|k| load([b[4 * k], b[4 * k + 1], b[4 * k + 2], b[4 * k + 3]]));
+                let mut out = [0.0; 16];
+                for i in 0..4 {
+                    let mut r = _mm_mul_ps(_mm_set1_ps(a[4 * i]), rows[0]);
+                    for (k, rk) in rows.iter().enumerate().skip(1) {
+                        r = _mm_add_ps(r, _mm_mul_ps(_mm_set1_ps(a[4 * i + k]), *rk));
+                    }
+                    out[4 * i..4 * i + 4].copy_from_slice(&store(r));
+                }
+                Some(out)
+            }
+        }
+
+        // Sum of the columns of `m` scaled by the components of `v`.
+        #[inline]
+        fn simd_mat4_transform(m: &[f32; 16], v: [f32; 4]) -> Option<[f32; 4]> {
+            unsafe {
+                let cols: [__m128; 4] = std::array::from_fn(|j| load([m[j], m[4 + j], m[8 + j], m[12 + j]]));
+                let mut r = _mm_mul_ps(cols[0], _mm_set1_ps(v[0]));
+                for j in 1..4 {
+                    r = _mm_add_ps(r, _mm_mul_ps(cols[j], _mm_set1_ps(v[j])));
+                }
+                Some(store(r))
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::vector3f::Vector3;
+use crate::core::matrix4f::Matrix4;
+use crate::core::aabb::Aabb;
+use crate::core::approx::ApproxEq;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// Solid ball of points within `radius` of `center`.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Sphere<T: Scalar>{
+    pub center: Vector3<T>,
+    pub radius: T,
+}
+
+pub type Spheref = Sphere<f32>;
+pub type Sphered = Sphere<f64>;
+
+impl<T: Float> Sphere<T>{
+    pub fn new(center: Vector3<T>, radius: T) -> Self {
+        Sphere{
+            center,
+            radius,
+        }
+    }
+
+    /// A sphere around every point with Ritter's algorithm, or `None` if
+    /// there are none. It takes two passes over the points and is usually
+    /// within a few percent of the smallest enclosing sphere.
+    pub fn from_points_ritter(points: &[Vector3<T>]) -> Option<Self> {
+        let first = *points.first()?;
+        let farthest = |from: Vector3<T>| points.iter().fold(from, |best, &p| {
+            if (p - from).magnitude_squared() > (best - from).magnitude_squared() { p } else { best }
+        });
+        let a = farthest(first);
+        let b = farthest(a);
+        let start = Sphere::new((a + b) * T::HALF, (b - a).magnitude() * T::HALF);
+        Some(points.iter().fold(start, |s, &p| s.expand_to_point(p)))
+    }
+
+    /// The smallest sphere around every point with Welzl's algorithm, or
+    /// `None` if there are none. Copies the points and shuffles the copy
+    /// with a fixed seed, so the expected running time is linear whatever
+    /// their order and the result is the same on every call.
+    pub fn from_points_minimal(points: &[Vector3<T>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let mut points = points.to_vec();
+        shuffle(&mut points);
+        let end = points.len();
+        Some(welzl(&mut points, end, &mut [Vector3::zero(); 4], 0))
+    }
+
+    pub fn contains_point(&self, p: Vector3<T>) -> bool {
+        (p - self.center).magnitude_squared() <= self.radius * self.radius
+    }
+
+    /// Whether `other` lies entirely inside `self`.
+    pub fn contains(&self, other: &Self) -> bool {
+        (other.center - self.center).magnitude() + other.radius <= self.radius
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        let r = self.radius + other.radius;
+        (other.center - self.center).magnitude_squared() <= r * r
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        let closest = self.center.max(aabb.min).min(aabb.max);
+        !aabb.is_empty() && self.contains_point(closest)
+    }
+
+    /// The smallest sphere containing `self` and `p`.
+    pub fn expand_to_point(&self, p: Vector3<T>) -> Self {
+        let d = (p - self.center).magnitude();
+        if d <= self.radius {
+            return *self;
+        }
+        let radius = (self.radius + d) * T::HALF;
+        Sphere::new(self.center + (p - self.center) * ((radius - self.radius) / d), radius)
+    }
+
+    pub fn to_aabb(&self) -> Aabb<T> {
+        Aabb::from_center_extents(self.center, Vector3::one() * self.radius)
+    }
+
+    /// A sphere containing the transformed sphere, for an affine `m`. The
+    /// radius grows by the largest scale of `m`, so it is exact for rigid
+    /// transforms and uniform scales.
+    pub fn transform(&self, m: &Matrix4<T>) -> Self {
+        let d = &m.data;
+        let scale = (0..3)
+            .map(|j| Vector3::new(d[j], d[4 + j], d[8 + j]).magnitude())
+            .fold(T::ZERO, |a, b| a.partial_max(b));
+        Sphere::new(m.transform_point3(self.center), self.radius * scale)
+    }
+
+    // Containment with some slack for the rounding in `circumsphere`, so
+    // points on the boundary are not added to the support again.
+    fn encloses(&self, p: Vector3<T>) -> bool {
+        let slack = T::tolerance() * T::from_f64(16.0);
+        (p - self.center).magnitude() <= self.radius * (T::ONE + slack) + slack
+    }
+}
+
+// Fisher-Yates with a 64-bit LCG. The seed is fixed on purpose: Welzl only
+// needs the order to be unrelated to the input, and a fixed seed keeps
+// `from_points_minimal` deterministic without a source of randomness.
+fn shuffle<T>(items: &mut [T]) {
+    let mut state = items.len() as u64;
+    for i in (1..items.len()).rev() {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        items.swap(i, ((state >> 33) % (i as u64 + 1)) as usize);
+    }
+}
+
+// The move-to-front form of Welzl's algorithm: the smallest sphere
+// enclosing `points[..end]` with `support[..n]` on its boundary. Points
+// that end up on the boundary move to the front, so later passes see them
+// first.
+fn welzl<T: Float>(points: &mut [Vector3<T>], end: usize, support: &mut [Vector3<T>; 4], n: usize) -> Sphere<T> {
+    let mut s = circumsphere(&support[..n]);
+    if n == 4 {
+        return s;
+    }
+    for i in 0..end {
+        let p = points[i];
+        if !s.encloses(p) {
+            support[n] = p;
+            s = welzl(points, i, support, n + 1);
+            points[..=i].rotate_right(1);
+        }
+    }
+    s
+}
+
+// The smallest sphere with every point on its boundary. Collinear or
+// coplanar points fall back to the smallest sphere through a subset that
+// still encloses them all. No points gives a sphere that encloses nothing.
+fn circumsphere<T: Float>(support: &[Vector3<T>]) -> Sphere<T> {
+    match *support {
+        [] => Sphere::new(Vector3::zero(), T::NEG_INFINITY),
+        [a] => Sphere::new(a, T::ZERO),
+        [a, b] => Sphere::new((a + b) * T::HALF, (b - a).magnitude() * T::HALF),
+        [a, b, c] => {
+            let (ab, ac) = (b - a, c - a);
+            let n = ab.cross(ac);
+            let limit = T::tolerance() * ab.magnitude() * ac.magnitude();
+            if n.magnitude() <= limit {
+                return [[a, b], [a, c], [b, c]]
+                    .map(|pair| circumsphere(&pair))
+                    .into_iter()
+                    .fold(Sphere::new(a, T::ZERO), |best, s| if s.radius > best.radius { s } else { best });
+            }
+            let offset = (n.cross(ab) * ac.magnitude_squared() + ac.cross(n) * ab.magnitude_squared())
+                / (T::TWO * n.magnitude_squared());
+            Sphere::new(a + offset, offset.magnitude())
+        }
+        [a, b, c, d] => {
+            let (ab, ac, ad) = (b - a, c - a, d - a);
+            let det = ab.dot(ac.cross(ad));
+            let limit = T::tolerance() * ab.magnitude() * ac.magnitude() * ad.magnitude();
+            if det.abs() <= limit {
+                let candidates = [[a, b, c], [a, b, d], [a, c, d], [b, c, d]].map(|tri| circumsphere(&tri));
+                let enclosing = candidates.iter()
+                    .filter(|s| support.iter().all(|&p| s.encloses(p)))
+                    .fold(None, |best: Option<Sphere<T>>, &s| match best {
+                        Some(b) if b.radius <= s.radius => Some(b),
+                        _ => Some(s),
+                    });
+                return enclosing.unwrap_or_else(|| {
+                    candidates.into_iter().fold(candidates[0], |best, s| if s.radius > best.radius { s } else { best })
+                });
+            }
+            let offset = (ab.cross(ac) * ad.magnitude_squared()
+                + ad.cross(ab) * ac.magnitude_squared()
+                + ac.cross(ad) * ab.magnitude_squared())
+                / (T::TWO * det);
+            Sphere::new(a + offset, offset.magnitude())
+        }
+        _ => unreachable!("a sphere is fixed by at most four points"),
+    }
+}
+
+impl_approx_eq!(Sphere { center, radius });
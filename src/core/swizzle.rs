@@ -0,0 +1,9 @@
+// Shader-style swizzles such as `v.xy()`, `v.zyx()` and `v.xxww()`, generated
+// by `build.rs` for every combination of two, three and four components.
+
+use crate::core::scalar::Scalar;
+use crate::core::vector2f::Vector2;
+use crate::core::vector3f::Vector3;
+use crate::core::vector4f::Vector4;
+
+include!(concat!(env!("OUT_DIR"), "/swizzles.rs"));
\ No newline at end of file
@@ -0,0 +1,237 @@
+use std::ops::Mul;
+
+use crate::core::vector3f::Vector3f;
+use crate::core::matrix4f::Matrix4f;
+use crate::core::quaternion::Quaternion;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+use bevy_ecs::prelude::{
+    Bundle,
+    Component,
+    Entity,
+    Query,
+    Schedule,
+    With,
+    Without,
+    World,
+};
+
+/// Local transform of an entity relative to its `Parent`, or to the world for
+/// root entities. Applied as scale, then rotation, then translation.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect, Component)]
+pub struct Transform{
+    pub translation: Vector3f,
+    pub rotation: Quaternion,
+    pub scale: Vector3f,
+}
+
+/// World-space transform, written by `propagate_transforms`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Serialize, Deserialize, Reflect, FromReflect, Component)]
+pub struct GlobalTransform(pub Matrix4f);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Reflect, FromReflect, Component)]
+pub struct Parent(pub Entity);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Reflect, FromReflect, Component)]
+pub struct Children(pub Vec<Entity>);
+
+#[derive(Debug, Copy, Clone, Default, Bundle)]
+pub struct TransformBundle{
+    pub local: Transform,
+    pub global: GlobalTransform,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+impl Transform{
+    pub fn identity() -> Self {
+        Transform{
+            translation: Vector3f::zero(),
+            rotation: Quaternion::identity(),
+            scale: Vector3f::one(),
+        }
+    }
+
+    pub fn from_translation(translation: Vector3f) -> Self {
+        Transform{
+            translation,
+            ..Transform::identity()
+        }
+    }
+
+    pub fn from_rotation(rotation: Quaternion) -> Self {
+        Transform{
+            rotation,
+            ..Transform::identity()
+        }
+    }
+
+    pub fn from_scale(scale: Vector3f) -> Self {
+        Transform{
+            scale,
+            ..Transform::identity()
+        }
+    }
+
+    /// Recovers the transform from an affine matrix with `Matrix4::decompose`.
+    pub fn from_matrix(m: &Matrix4f) -> Self {
+        let (translation, scale, rotation) = m.decompose_quat();
+        Transform{
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn with_translation(self, translation: Vector3f) -> Self {
+        Transform{
+            translation,
+            ..self
+        }
+    }
+
+    pub fn with_rotation(self, rotation: Quaternion) -> Self {
+        Transform{
+            rotation,
+            ..self
+        }
+    }
+
+    pub fn with_scale(self, scale: Vector3f) -> Self {
+        Transform{
+            scale,
+            ..self
+        }
+    }
+
+    pub fn compute_matrix(&self) -> Matrix4f {
+        Matrix4f::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+
+    pub fn transform_point(&self, p: Vector3f) -> Vector3f {
+        self.rotation.rotate(p * self.scale) + self.translation
+    }
+}
+
+impl GlobalTransform{
+    pub fn matrix(&self) -> Matrix4f {
+        self.0
+    }
+
+    pub fn translation(&self) -> Vector3f {
+        Vector3f::new(self.0.data[3], self.0.data[7], self.0.data[11])
+    }
+
+    pub fn transform_point(&self, p: Vector3f) -> Vector3f {
+        self.0.transform_point3(p)
+    }
+
+    /// World transform of a child with local transform `local`.
+    pub fn mul_transform(&self, local: &Transform) -> GlobalTransform {
+        GlobalTransform(self.0 * local.compute_matrix())
+    }
+}
+
+impl Mul<Transform> for GlobalTransform {
+    type Output = GlobalTransform;
+
+    fn mul(self, local: Transform) -> GlobalTransform {
+        self.mul_transform(&local)
+    }
+}
+
+impl From<Transform> for GlobalTransform {
+    fn from(t: Transform) -> GlobalTransform {
+        GlobalTransform(t.compute_matrix())
+    }
+}
+
+impl From<Transform> for TransformBundle {
+    fn from(local: Transform) -> TransformBundle {
+        TransformBundle{
+            local,
+            global: GlobalTransform::from(local),
+        }
+    }
+}
+
+/// Makes `child` a child of `parent`, detaching it from any previous parent.
+pub fn set_parent(world: &mut World, child: Entity, parent: Entity) {
+    remove_parent(world, child);
+    world.entity_mut(child).insert(Parent(parent));
+
+    let mut parent = world.entity_mut(parent);
+    match parent.get_mut::<Children>() {
+        Some(mut children) => children.0.push(child),
+        None => {
+            parent.insert(Children(vec![child]));
+        }
+    }
+}
+
+/// Turns `child` back into a root entity.
+pub fn remove_parent(world: &mut World, child: Entity) {
+    let old = match world.entity_mut(child).take::<Parent>() {
+        Some(Parent(old)) => old,
+        None => return,
+    };
+    if let Some(mut old) = world.get_entity_mut(old) {
+        if let Some(mut children) = old.get_mut::<Children>() {
+            children.0.retain(|&e| e != child);
+        }
+    }
+}
+
+/// Updates the `GlobalTransform` of every entity from its `Transform` and
+/// those of its ancestors. Entities without a `GlobalTransform` are skipped
+/// but their descendants are still updated.
+pub fn propagate_transforms(
+    roots: Query<(Entity, &Transform, Option<&Children>), Without<Parent>>,
+    nodes: Query<(&Transform, Option<&Children>), With<Parent>>,
+    mut globals: Query<&mut GlobalTransform>,
+) {
+    for (entity, transform, children) in roots.iter() {
+        let global = GlobalTransform::from(*transform);
+        if let Ok(mut g) = globals.get_mut(entity) {
+            *g = global;
+        }
+        if let Some(children) = children {
+            propagate_children(&global, children, &nodes, &mut globals);
+        }
+    }
+}
+
+fn propagate_children(
+    parent: &GlobalTransform,
+    children: &Children,
+    nodes: &Query<(&Transform, Option<&Children>), With<Parent>>,
+    globals: &mut Query<&mut GlobalTransform>,
+) {
+    for &child in children.0.iter() {
+        let (transform, grandchildren) = match nodes.get(child) {
+            Ok(node) => node,
+            Err(_) => continue,
+        };
+        let global = parent.mul_transform(transform);
+        if let Ok(mut g) = globals.get_mut(child) {
+            *g = global;
+        }
+        if let Some(grandchildren) = grandchildren {
+            propagate_children(&global, grandchildren, nodes, globals);
+        }
+    }
+}
+
+pub fn add_transform_systems(schedule: &mut Schedule) -> &mut Schedule {
+    schedule.add_system(propagate_transforms)
+}
@@ -0,0 +1,236 @@
+use crate::core::scalar::{
+    Scalar,
+    Float,
+};
+use crate::core::vector3f::Vector3;
+use crate::core::vector2f::Vector2;
+use crate::core::aabb::{
+    Aabb,
+    Aabb2,
+};
+use crate::core::error::MathError;
+use crate::core::approx::ApproxEq;
+use serde::{
+    Serialize,
+    Deserialize,
+};
+use bevy_reflect::{
+    Reflect,
+    FromReflect
+};
+
+/// Triangle with corners `a`, `b` and `c`. Its front face is the side from
+/// which they appear counter-clockwise.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Triangle<T: Scalar>{
+    pub a: Vector3<T>,
+    pub b: Vector3<T>,
+    pub c: Vector3<T>,
+}
+
+pub type Trianglef = Triangle<f32>;
+pub type Triangled = Triangle<f64>;
+
+/// The 2D counterpart of `Triangle`, for work in UV space.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Reflect, FromReflect)]
+#[repr(C)]
+pub struct Triangle2<T: Scalar>{
+    pub a: Vector2<T>,
+    pub b: Vector2<T>,
+    pub c: Vector2<T>,
+}
+
+pub type Triangle2f = Triangle2<f32>;
+pub type Triangle2d = Triangle2<f64>;
+
+// Operations shared by `Triangle` and `Triangle2`, written with dot
+// products only so they work in either dimension.
+macro_rules! impl_triangle {
+    ($name:ident, $vec:ident) => {
+        impl<T: Float> $name<T> {
+            pub fn new(a: $vec<T>, b: $vec<T>, c: $vec<T>) -> Self {
+                $name{
+                    a,
+                    b,
+                    c,
+                }
+            }
+
+            pub fn centroid(&self) -> $vec<T> {
+                (self.a + self.b + self.c) / T::from_f64(3.0)
+            }
+
+            /// The weights `(u, v, w)` of `a`, `b` and `c` whose sum is the
+            /// point of the triangle's plane closest to `p`. They add up to
+            /// one, and are all non-negative when that point is inside the
+            /// triangle. Degenerate triangles give NaN.
+            pub fn barycentric(&self, p: $vec<T>) -> Vector3<T> {
+                let (ab, ac, ap) = (self.b - self.a, self.c - self.a, p - self.a);
+                let (d00, d01, d11) = (ab.dot(ab), ab.dot(ac), ac.dot(ac));
+                let (d20, d21) = (ap.dot(ab), ap.dot(ac));
+                let denom = d00 * d11 - d01 * d01;
+                let v = (d11 * d20 - d01 * d21) / denom;
+                let w = (d00 * d21 - d01 * d20) / denom;
+                Vector3::new(T::ONE - v - w, v, w)
+            }
+
+            /// Fails with `MathError::DegenerateGeometry` when the corners
+            /// are collinear.
+            pub fn try_barycentric(&self, p: $vec<T>) -> Result<Vector3<T>, MathError> {
+                let (ab, ac) = (self.b - self.a, self.c - self.a);
+                let (d00, d01, d11) = (ab.dot(ab), ab.dot(ac), ac.dot(ac));
+                if d00 * d11 - d01 * d01 <= T::tolerance() * d00 * d11 {
+                    return Err(MathError::DegenerateGeometry);
+                }
+                Ok(self.barycentric(p))
+            }
+
+            /// The point with barycentric weights `weights`.
+            pub fn point_from_barycentric(&self, weights: Vector3<T>) -> $vec<T> {
+                self.a * weights.x + self.b * weights.y + self.c * weights.z
+            }
+
+            /// The point on the triangle closest to `p`, found by testing
+            /// which corner or edge region `p` lies in.
+            pub fn closest_point(&self, p: $vec<T>) -> $vec<T> {
+                let (a, b, c) = (self.a, self.b, self.c);
+                let (ab, ac) = (b - a, c - a);
+
+                let ap = p - a;
+                let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+                if d1 <= T::ZERO && d2 <= T::ZERO {
+                    return a;
+                }
+                let bp = p - b;
+                let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+                if d3 >= T::ZERO && d4 <= d3 {
+                    return b;
+                }
+                let vc = d1 * d4 - d3 * d2;
+                if vc <= T::ZERO && d1 >= T::ZERO && d3 <= T::ZERO {
+                    return a + ab * (d1 / (d1 - d3));
+                }
+                let cp = p - c;
+                let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+                if d6 >= T::ZERO && d5 <= d6 {
+                    return c;
+                }
+                let vb = d5 * d2 - d1 * d6;
+                if vb <= T::ZERO && d2 >= T::ZERO && d6 <= T::ZERO {
+                    return a + ac * (d2 / (d2 - d6));
+                }
+                let va = d3 * d6 - d5 * d4;
+                if va <= T::ZERO && d4 - d3 >= T::ZERO && d5 - d6 >= T::ZERO {
+                    return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+                }
+                let denom = va + vb + vc;
+                a + ab * (vb / denom) + ac * (vc / denom)
+            }
+        }
+
+        impl_approx_eq!($name { a, b, c });
+    };
+}
+
+impl_triangle!(Triangle, Vector3);
+impl_triangle!(Triangle2, Vector2);
+
+impl<T: Float> Triangle<T> {
+    pub fn area(&self) -> T {
+        (self.b - self.a).cross(self.c - self.a).magnitude() * T::HALF
+    }
+
+    /// The unit normal of the front face.
+    pub fn normal(&self) -> Vector3<T> {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    /// Fails with `MathError::DegenerateGeometry` when the corners are
+    /// collinear.
+    pub fn try_normal(&self) -> Result<Vector3<T>, MathError> {
+        let (ab, ac) = (self.b - self.a, self.c - self.a);
+        let n = ab.cross(ac);
+        if n.magnitude() <= T::tolerance() * ab.magnitude() * ac.magnitude() {
+            return Err(MathError::DegenerateGeometry);
+        }
+        Ok(n.normalize())
+    }
+
+    /// Whether `p`, projected onto the triangle's plane, lands inside the
+    /// triangle. Points on an edge are inside.
+    pub fn contains_point(&self, p: Vector3<T>) -> bool {
+        self.barycentric(p).iter().all(|w| w >= T::ZERO)
+    }
+
+    /// Separating axis test of Akenine-Möller: the box's face normals, the
+    /// triangle's normal and the cross products of their edges.
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        if aabb.is_empty() {
+            return false;
+        }
+        let center = aabb.center();
+        let e = aabb.extents();
+        let v = [self.a - center, self.b - center, self.c - center];
+        let edges = [v[1] - v[0], v[2] - v[1], v[0] - v[2]];
+
+        // whether the projections of the triangle and box onto `axis` are
+        // disjoint
+        let separated = |axis: Vector3<T>| {
+            let [p0, p1, p2] = v.map(|p| p.dot(axis));
+            let r = e.dot(axis.abs());
+            p0.partial_min(p1).partial_min(p2) > r || p0.partial_max(p1).partial_max(p2) < -r
+        };
+
+        let units = [Vector3::new(T::ONE, T::ZERO, T::ZERO), Vector3::new(T::ZERO, T::ONE, T::ZERO), Vector3::new(T::ZERO, T::ZERO, T::ONE)];
+        for unit in units {
+            for edge in edges {
+                if separated(unit.cross(edge)) {
+                    return false;
+                }
+            }
+        }
+        if units.into_iter().any(separated) {
+            return false;
+        }
+        !separated(edges[0].cross(edges[1]))
+    }
+}
+
+impl<T: Float> Triangle2<T> {
+    /// Positive when the corners are counter-clockwise.
+    pub fn signed_area(&self) -> T {
+        let (ab, ac) = (self.b - self.a, self.c - self.a);
+        (ab.x * ac.y - ab.y * ac.x) * T::HALF
+    }
+
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+
+    /// Points on an edge are inside.
+    pub fn contains_point(&self, p: Vector2<T>) -> bool {
+        self.barycentric(p).iter().all(|w| w >= T::ZERO)
+    }
+
+    /// Separating axis test against the box's axes and the triangle's edge
+    /// normals.
+    pub fn intersects_aabb(&self, aabb: &Aabb2<T>) -> bool {
+        if aabb.is_empty() {
+            return false;
+        }
+        let center = aabb.center();
+        let e = aabb.extents();
+        let v = [self.a - center, self.b - center, self.c - center];
+
+        let separated = |axis: Vector2<T>| {
+            let [p0, p1, p2] = v.map(|p| p.dot(axis));
+            let r = e.dot(axis.abs());
+            p0.partial_min(p1).partial_min(p2) > r || p0.partial_max(p1).partial_max(p2) < -r
+        };
+
+        let units = [Vector2::new(T::ONE, T::ZERO), Vector2::new(T::ZERO, T::ONE)];
+        let normals = [v[1] - v[0], v[2] - v[1], v[0] - v[2]].map(|edge| Vector2::new(-edge.y, edge.x));
+        !units.into_iter().chain(normals).any(separated)
+    }
+}
@@ -3,8 +3,24 @@ use std::ops::{
     AddAssign,
     Sub,
     SubAssign,
+    Mul,
+    MulAssign,
+    Div,
+    DivAssign,
+    Neg,
+};
+use std::hash::{
+    Hash,
+    Hasher,
 };
 
+use crate::core::scalar::{
+    Scalar,
+    SignedScalar,
+    Float,
+};
+use crate::core::error::MathError;
+use crate::core::approx::ApproxEq;
 use serde::{
     Serialize,
     Deserialize,
@@ -15,71 +31,113 @@ use bevy_reflect::{
 };
 use bevy_ecs::prelude::Resource;
 
+use crate::core::vector3f::Vector3;
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Reflect, FromReflect, Resource)]
 #[repr(C)]
-pub struct Vector2f{
-    pub x: f32,
-    pub y: f32,
+pub struct Vector2<T: Scalar>{
+    pub x: T,
+    pub y: T,
 }
 
-impl Default for Vector2f {
+pub type Vector2f = Vector2<f32>;
+pub type Vector2d = Vector2<f64>;
+pub type Vector2i = Vector2<i32>;
+pub type Vector2u = Vector2<u32>;
+
+impl<T: Scalar> Default for Vector2<T> {
     fn default() -> Self {
-        Vector2f::zero()
+        Vector2::zero()
     }
 }
 
-impl Vector2f{
-    pub fn new(x: f32, y: f32) -> Self {
-        Vector2f{
-            x: x,
-            y: y,
+impl<T: Scalar> Vector2<T>{
+    pub fn new(x: T, y: T) -> Self {
+        Vector2{
+            x,
+            y,
         }
     }
 
     pub fn zero() -> Self {
-        Vector2f{
-            x: 0.0,
-            y: 0.0,
+        Vector2{
+            x: T::ZERO,
+            y: T::ZERO,
         }
     }
     
     pub fn one() -> Self {
-        Vector2f{
-            x: 1.0,
-            y: 1.0,
+        Vector2{
+            x: T::ONE,
+            y: T::ONE,
         }
     }
 
-    pub fn dot(&self, other: Self) -> f32 {
+    pub fn dot(&self, other: Self) -> T {
         (self.x * other.x) + (self.y * other.y)
     }
     
-    pub fn magnitude_squared(&self) -> f32 {
+    pub fn magnitude_squared(&self) -> T {
         let x = self.x;
         let y = self.y;
         (x * x) + (y * y)
     }
 
-    pub fn magnitude(&self) -> f32 {
+    pub fn scale(&self, s: T) -> Self {
+        Vector2::new(self.x * s, self.y * s)
+    }
+
+    pub fn extend(&self, z: T) -> Vector3<T> {
+        Vector3::new(self.x, self.y, z)
+    }
+
+    /// Component-wise minimum.
+    pub fn min(&self, other: Self) -> Self {
+        Vector2::new(self.x.partial_min(other.x), self.y.partial_min(other.y))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(&self, other: Self) -> Self {
+        Vector2::new(self.x.partial_max(other.x), self.y.partial_max(other.y))
+    }
+
+    /// Converts each component with `as` semantics, e.g. `Vector2f` to
+    /// `Vector2i` truncates toward zero.
+    pub fn cast<U: Scalar>(&self) -> Vector2<U> {
+        Vector2::new(U::from_f64(self.x.to_f64()), U::from_f64(self.y.to_f64()))
+    }
+}
+
+impl<T: SignedScalar> Vector2<T>{
+    pub fn abs(&self) -> Self {
+        Vector2::new(self.x.abs(), self.y.abs())
+    }
+}
+
+impl<T: Float> Vector2<T>{
+    pub fn magnitude(&self) -> T {
         self.magnitude_squared().sqrt()
     }
 
     pub fn normalize(&self) -> Self {
         let m = self.magnitude();
-        Vector2f::new(self.x / m, self.y / m)
+        Vector2::new(self.x / m, self.y / m)
     }
 
-    pub fn scale(&self, s: f32) -> Self {
-        Vector2f::new(self.x * s, self.y * s)
+    pub fn try_normalize(&self) -> Result<Self, MathError> {
+        let m = self.magnitude();
+        if m <= T::tolerance() {
+            return Err(MathError::ZeroLengthVector);
+        }
+        Ok(Vector2::new(self.x / m, self.y / m))
     }
 
-    pub fn angle_between_rad(&self, other: Self) -> f32 {
+    pub fn angle_between_rad(&self, other: Self) -> T {
         (self.dot(other) / (self.magnitude() * other.magnitude())).acos()
     }
 }
 
-impl Add for Vector2f {
+impl<T: Scalar> Add for Vector2<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -90,7 +148,7 @@ impl Add for Vector2f {
     }
 }
 
-impl AddAssign for Vector2f {
+impl<T: Scalar> AddAssign for Vector2<T> {
     fn add_assign(&mut self, other: Self) {
         *self = Self {
             x: self.x + other.x,
@@ -99,7 +157,7 @@ impl AddAssign for Vector2f {
     }
 }
 
-impl Sub for Vector2f {
+impl<T: Scalar> Sub for Vector2<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -110,7 +168,7 @@ impl Sub for Vector2f {
     }
 }
 
-impl SubAssign for Vector2f {
+impl<T: Scalar> SubAssign for Vector2<T> {
     fn sub_assign(&mut self, other: Self) {
         *self = Self {
             x: self.x - other.x,
@@ -119,14 +177,136 @@ impl SubAssign for Vector2f {
     }
 }
 
-impl From<Vector2f> for [f32; 2] {
-    fn from(vec: Vector2f) -> [f32; 2] {
+impl<T: SignedScalar> Neg for Vector2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y
+        }
+    }
+}
+
+impl<T: Scalar> Mul for Vector2<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign for Vector2<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = Self {
+            x: self.x * other.x,
+            y: self.y * other.y
+        };
+    }
+}
+
+impl<T: Scalar> Div for Vector2<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign for Vector2<T> {
+    fn div_assign(&mut self, other: Self) {
+        *self = Self {
+            x: self.x / other.x,
+            y: self.y / other.y
+        };
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector2<T> {
+    type Output = Self;
+
+    fn mul(self, s: T) -> Self {
+        Self {
+            x: self.x * s,
+            y: self.y * s
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Vector2<T> {
+    fn mul_assign(&mut self, s: T) {
+        *self = Self {
+            x: self.x * s,
+            y: self.y * s
+        };
+    }
+}
+
+impl<T: Scalar> Div<T> for Vector2<T> {
+    type Output = Self;
+
+    fn div(self, s: T) -> Self {
+        Self {
+            x: self.x / s,
+            y: self.y / s
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Vector2<T> {
+    fn div_assign(&mut self, s: T) {
+        *self = Self {
+            x: self.x / s,
+            y: self.y / s
+        };
+    }
+}
+
+forward_ref_unop!(impl [T: SignedScalar] Neg, neg for Vector2<T>);
+forward_ref_binop!(impl [T: Scalar] Add, add for Vector2<T>, Vector2<T>);
+forward_ref_binop!(impl [T: Scalar] Sub, sub for Vector2<T>, Vector2<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Vector2<T>, Vector2<T>);
+forward_ref_binop!(impl [T: Scalar] Div, div for Vector2<T>, Vector2<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Vector2<T>, T);
+forward_ref_binop!(impl [T: Scalar] Div, div for Vector2<T>, T);
+forward_ref_op_assign!(impl [T: Scalar] AddAssign, add_assign for Vector2<T>, Vector2<T>);
+forward_ref_op_assign!(impl [T: Scalar] SubAssign, sub_assign for Vector2<T>, Vector2<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Vector2<T>, Vector2<T>);
+forward_ref_op_assign!(impl [T: Scalar] DivAssign, div_assign for Vector2<T>, Vector2<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Vector2<T>, T);
+forward_ref_op_assign!(impl [T: Scalar] DivAssign, div_assign for Vector2<T>, T);
+
+impl_scalar_lhs_mul!(Vector2 { x, y }: f32, f64, i32, u32);
+
+impl_approx_eq!(Vector2 { x, y });
+
+impl_vector_components!(Vector2, 2, (T, T) { x: 0, y: 1 });
+
+impl_lossless_from!(Vector2 { x, y }: f32 => f64, i32 => f64, u32 => f64);
+
+impl<T: Scalar> From<Vector2<T>> for [T; 2] {
+    fn from(vec: Vector2<T>) -> [T; 2] {
         [vec.x, vec.y]
     }
 }
 
-impl PartialEq for Vector2f {
+impl<T: Scalar> PartialEq for Vector2<T> {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y
     }
 }
+
+impl<T: Scalar + Eq> Eq for Vector2<T> {}
+
+impl<T: Scalar + Hash> Hash for Vector2<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+    }
+}
@@ -3,8 +3,24 @@ use std::ops::{
     AddAssign,
     Sub,
     SubAssign,
+    Mul,
+    MulAssign,
+    Div,
+    DivAssign,
+    Neg,
+};
+use std::hash::{
+    Hash,
+    Hasher,
 };
 
+use crate::core::scalar::{
+    Scalar,
+    SignedScalar,
+    Float,
+};
+use crate::core::error::MathError;
+use crate::core::approx::ApproxEq;
 use serde::{
     Serialize,
     Deserialize,
@@ -15,91 +31,145 @@ use bevy_reflect::{
 };
 use bevy_ecs::prelude::Resource;
 
-use crate::core::vector4f::Vector4f;
+use crate::core::vector4f::Vector4;
+use crate::core::vector2f::Vector2;
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Reflect, FromReflect, Resource)]
 #[repr(C)]
-pub struct Vector3f{
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+pub struct Vector3<T: Scalar>{
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
-impl Default for Vector3f{
+pub type Vector3f = Vector3<f32>;
+pub type Vector3d = Vector3<f64>;
+pub type Vector3i = Vector3<i32>;
+pub type Vector3u = Vector3<u32>;
+
+impl<T: Scalar> Default for Vector3<T> {
     fn default() -> Self {
-        Vector3f::zero()
+        Vector3::zero()
     }
 }
 
-impl Vector3f{
-    pub fn new(x: f32, y: f32, z: f32) -> Self {
-        Vector3f{
-            x: x,
-            y: y,
-            z: z,
+impl<T: Scalar> Vector3<T>{
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vector3{
+            x,
+            y,
+            z,
         }
     }
 
     pub fn zero() -> Self {
-        Vector3f{
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
+        Vector3{
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
         }
     }
     
     pub fn one() -> Self {
-        Vector3f{
-            x: 1.0,
-            y: 1.0,
-            z: 1.0,
+        Vector3{
+            x: T::ONE,
+            y: T::ONE,
+            z: T::ONE,
         }
     }
 
-    pub fn dot(&self, other: Self) -> f32 {
+    pub fn dot(&self, other: Self) -> T {
+        if let Some(d) = T::simd_dot4(self.lanes(T::ZERO), other.lanes(T::ZERO)) {
+            return d;
+        }
         (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
     }
-    
-    pub fn cross(&self, other: Self) -> Self {
-        let x: f32 = (self.y * other.z) - (self.z * other.y);
-        let y: f32 = (self.z * other.x) - (self.x * other.z);
-        let z: f32 = (self.x * other.y) - (self.y * other.x);
-        Vector3f::new(x, y, z)
+
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(*self)
+    }
+
+    pub fn scale(&self, s: T) -> Self {
+        *self * s
+    }
+
+    pub fn extend(&self, w: T) -> Vector4<T> {
+        Vector4::new(self.x, self.y, self.z, w)
+    }
+
+    pub fn truncate(&self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
     }
 
-    pub fn magnitude_squared(&self) -> f32 {
-        let x = self.x;
-        let y = self.y;
-        let z = self.z;
-        (x * x) + (y * y) + (z * z)
+    // The components padded to four SIMD lanes with `w`.
+    fn lanes(&self, w: T) -> [T; 4] {
+        [self.x, self.y, self.z, w]
     }
 
-    pub fn magnitude(&self) -> f32 {
+    /// Component-wise minimum.
+    pub fn min(&self, other: Self) -> Self {
+        Vector3::new(self.x.partial_min(other.x), self.y.partial_min(other.y), self.z.partial_min(other.z))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(&self, other: Self) -> Self {
+        Vector3::new(self.x.partial_max(other.x), self.y.partial_max(other.y), self.z.partial_max(other.z))
+    }
+
+    /// Converts each component with `as` semantics, e.g. `Vector3f` to
+    /// `Vector3i` truncates toward zero.
+    pub fn cast<U: Scalar>(&self) -> Vector3<U> {
+        Vector3::new(
+            U::from_f64(self.x.to_f64()),
+            U::from_f64(self.y.to_f64()),
+            U::from_f64(self.z.to_f64())
+        )
+    }
+}
+
+impl<T: SignedScalar> Vector3<T>{
+    pub fn abs(&self) -> Self {
+        Vector3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn cross(&self, other: Self) -> Self {
+        let x: T = (self.y * other.z) - (self.z * other.y);
+        let y: T = (self.z * other.x) - (self.x * other.z);
+        let z: T = (self.x * other.y) - (self.y * other.x);
+        Vector3::new(x, y, z)
+    }
+}
+
+impl<T: Float> Vector3<T>{
+    pub fn magnitude(&self) -> T {
         self.magnitude_squared().sqrt()
     }
 
     pub fn normalize(&self) -> Self {
         let m = self.magnitude();
-        Vector3f::new(self.x / m, self.y / m, self.z / m)
-    }
-
-    pub fn scale(&self, s: f32) -> Self {
-        Vector3f::new(self.x * s, self.y * s, self.z * s)
+        Vector3::new(self.x / m, self.y / m, self.z / m)
     }
 
-    pub fn extend(&self, w: f32) -> Vector4f {
-        Vector4f::new(self.x, self.y, self.z, w)
+    pub fn try_normalize(&self) -> Result<Self, MathError> {
+        let m = self.magnitude();
+        if m <= T::tolerance() {
+            return Err(MathError::ZeroLengthVector);
+        }
+        Ok(Vector3::new(self.x / m, self.y / m, self.z / m))
     }
 
-    pub fn angle_between_rad(&self, other: Self) -> f32 {
+    pub fn angle_between_rad(&self, other: Self) -> T {
         (self.dot(other) / (self.magnitude() * other.magnitude())).acos()
     }
 }
 
-impl Add for Vector3f {
+impl<T: Scalar> Add for Vector3<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
+        if let Some([x, y, z, _]) = T::simd_add4(self.lanes(T::ZERO), other.lanes(T::ZERO)) {
+            return Vector3::new(x, y, z);
+        }
         Self {
             x: self.x + other.x,
             y: self.y + other.y,
@@ -108,20 +178,19 @@ impl Add for Vector3f {
     }
 }
 
-impl AddAssign for Vector3f {
+impl<T: Scalar> AddAssign for Vector3<T> {
     fn add_assign(&mut self, other: Self) {
-        *self = Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z
-        };
+        *self = *self + other;
     }
 }
 
-impl Sub for Vector3f {
+impl<T: Scalar> Sub for Vector3<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
+        if let Some([x, y, z, _]) = T::simd_sub4(self.lanes(T::ZERO), other.lanes(T::ZERO)) {
+            return Vector3::new(x, y, z);
+        }
         Self {
             x: self.x - other.x,
             y: self.y - other.y,
@@ -130,25 +199,146 @@ impl Sub for Vector3f {
     }
 }
 
-impl SubAssign for Vector3f {
+impl<T: Scalar> SubAssign for Vector3<T> {
     fn sub_assign(&mut self, other: Self) {
-        *self = Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z
-        };
+        *self = *self - other;
+    }
+}
+
+impl<T: SignedScalar> Neg for Vector3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z
+        }
+    }
+}
+
+impl<T: Scalar> Mul for Vector3<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        if let Some([x, y, z, _]) = T::simd_mul4(self.lanes(T::ZERO), other.lanes(T::ZERO)) {
+            return Vector3::new(x, y, z);
+        }
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign for Vector3<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
     }
 }
 
-impl From<Vector3f> for [f32; 3] {
-    fn from(vec: Vector3f) -> [f32; 3] {
+impl<T: Scalar> Div for Vector3<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        if let Some([x, y, z, _]) = T::simd_div4(self.lanes(T::ZERO), other.lanes(T::ONE)) {
+            return Vector3::new(x, y, z);
+        }
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign for Vector3<T> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector3<T> {
+    type Output = Self;
+
+    fn mul(self, s: T) -> Self {
+        if let Some([x, y, z, _]) = T::simd_scale4(self.lanes(T::ZERO), s) {
+            return Vector3::new(x, y, z);
+        }
+        Self {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Vector3<T> {
+    fn mul_assign(&mut self, s: T) {
+        *self = *self * s;
+    }
+}
+
+impl<T: Scalar> Div<T> for Vector3<T> {
+    type Output = Self;
+
+    fn div(self, s: T) -> Self {
+        Self {
+            x: self.x / s,
+            y: self.y / s,
+            z: self.z / s
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Vector3<T> {
+    fn div_assign(&mut self, s: T) {
+        *self = *self / s;
+    }
+}
+
+forward_ref_unop!(impl [T: SignedScalar] Neg, neg for Vector3<T>);
+forward_ref_binop!(impl [T: Scalar] Add, add for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl [T: Scalar] Sub, sub for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl [T: Scalar] Div, div for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Vector3<T>, T);
+forward_ref_binop!(impl [T: Scalar] Div, div for Vector3<T>, T);
+forward_ref_op_assign!(impl [T: Scalar] AddAssign, add_assign for Vector3<T>, Vector3<T>);
+forward_ref_op_assign!(impl [T: Scalar] SubAssign, sub_assign for Vector3<T>, Vector3<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Vector3<T>, Vector3<T>);
+forward_ref_op_assign!(impl [T: Scalar] DivAssign, div_assign for Vector3<T>, Vector3<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Vector3<T>, T);
+forward_ref_op_assign!(impl [T: Scalar] DivAssign, div_assign for Vector3<T>, T);
+
+impl_scalar_lhs_mul!(Vector3 { x, y, z }: f32, f64, i32, u32);
+
+impl_approx_eq!(Vector3 { x, y, z });
+
+impl_vector_components!(Vector3, 3, (T, T, T) { x: 0, y: 1, z: 2 });
+
+impl_lossless_from!(Vector3 { x, y, z }: f32 => f64, i32 => f64, u32 => f64);
+
+impl<T: Scalar> From<Vector3<T>> for [T; 3] {
+    fn from(vec: Vector3<T>) -> [T; 3] {
         [vec.x, vec.y, vec.z]
     }
 }
 
 
-impl PartialEq for Vector3f {
+impl<T: Scalar> PartialEq for Vector3<T> {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y && self.z == other.z
     }
-}
\ No newline at end of file
+}
+
+impl<T: Scalar + Eq> Eq for Vector3<T> {}
+
+impl<T: Scalar + Hash> Hash for Vector3<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.z.hash(state);
+    }
+}
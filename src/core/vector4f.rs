@@ -3,9 +3,25 @@ use std::ops::{
     AddAssign,
     Sub,
     SubAssign,
+    Mul,
+    MulAssign,
+    Div,
+    DivAssign,
+    Neg,
 };
-use crate::core::vector3f::Vector3f;
+use std::hash::{
+    Hash,
+    Hasher,
+};
+use crate::core::vector3f::Vector3;
 
+use crate::core::scalar::{
+    Scalar,
+    SignedScalar,
+    Float,
+};
+use crate::core::error::MathError;
+use crate::core::approx::ApproxEq;
 use serde::{
     Serialize,
     Deserialize,
@@ -16,85 +32,127 @@ use bevy_reflect::{
 };
 use bevy_ecs::prelude::Resource;
 
-
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Reflect, FromReflect, Resource)]
 #[repr(C)]
-pub struct Vector4f{
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
+pub struct Vector4<T: Scalar>{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
 }
 
-impl Default for Vector4f {
+pub type Vector4f = Vector4<f32>;
+pub type Vector4d = Vector4<f64>;
+pub type Vector4i = Vector4<i32>;
+pub type Vector4u = Vector4<u32>;
+
+impl<T: Scalar> Default for Vector4<T> {
     fn default() -> Self {
-        Vector4f::zero()
+        Vector4::zero()
     }
 }
 
-impl Vector4f{
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
-        Vector4f{
-            x: x,
-            y: y,
-            z: z,
-            w: w,
+impl<T: Scalar> Vector4<T>{
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Vector4{
+            x,
+            y,
+            z,
+            w,
         }
     }
 
     pub fn zero() -> Self {
-        Vector4f{
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-            w: 0.0,
+        Vector4{
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: T::ZERO,
         }
     }
     
     pub fn one() -> Self {
-        Vector4f{
-            x: 1.0,
-            y: 1.0,
-            z: 1.0,
-            w: 1.0,
+        Vector4{
+            x: T::ONE,
+            y: T::ONE,
+            z: T::ONE,
+            w: T::ONE,
         }
     }
 
-    pub fn dot(&self, other: Self) -> f32 {
+    pub fn dot(&self, other: Self) -> T {
+        if let Some(d) = T::simd_dot4(self.to_array(), other.to_array()) {
+            return d;
+        }
         (self.x * other.x) + (self.y * other.y) + (self.z * other.z) + (self.w * other.w)
     }
 
-    pub fn magnitude_squared(&self) -> f32 {
-        let x = self.x;
-        let y = self.y;
-        let z = self.z;
-        let w = self.w;
-        (x * x) + (y * y) + (z * z) + (w * w)
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(*self)
     }
 
-    pub fn magnitude(&self) -> f32 {
-        self.magnitude_squared().sqrt()
+    pub fn scale(&self, s: T) -> Self {
+        *self * s
     }
 
-    pub fn normalize(&self) -> Self {
-        let m = self.magnitude();
-        Vector4f::new(self.x / m, self.y / m, self.z / m, self.w / m)
+    pub fn truncate(&self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    /// Component-wise minimum.
+    pub fn min(&self, other: Self) -> Self {
+        Vector4::new(self.x.partial_min(other.x), self.y.partial_min(other.y), self.z.partial_min(other.z), self.w.partial_min(other.w))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(&self, other: Self) -> Self {
+        Vector4::new(self.x.partial_max(other.x), self.y.partial_max(other.y), self.z.partial_max(other.z), self.w.partial_max(other.w))
     }
 
-    pub fn scale(&self, s: f32) -> Self {
-        Vector4f::new(self.x * s, self.y * s, self.z * s, self.w * s)
+    /// Converts each component with `as` semantics, e.g. `Vector4f` to
+    /// `Vector4i` truncates toward zero.
+    pub fn cast<U: Scalar>(&self) -> Vector4<U> {
+        Vector4::new(
+            U::from_f64(self.x.to_f64()),
+            U::from_f64(self.y.to_f64()),
+            U::from_f64(self.z.to_f64()),
+            U::from_f64(self.w.to_f64())
+        )
+    }
+}
+
+impl<T: SignedScalar> Vector4<T>{
+    pub fn abs(&self) -> Self {
+        Vector4::new(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
+    }
+}
+
+impl<T: Float> Vector4<T>{
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
     }
 
-    pub fn truncate(&self) -> Vector3f {
-        Vector3f::new(self.x, self.y, self.z)
+    pub fn normalize(&self) -> Self {
+        let m = self.magnitude();
+        Vector4::new(self.x / m, self.y / m, self.z / m, self.w / m)
     }
 
+    pub fn try_normalize(&self) -> Result<Self, MathError> {
+        let m = self.magnitude();
+        if m <= T::tolerance() {
+            return Err(MathError::ZeroLengthVector);
+        }
+        Ok(Vector4::new(self.x / m, self.y / m, self.z / m, self.w / m))
+    }
 }
 
-impl Add for Vector4f {
+impl<T: Scalar> Add for Vector4<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
+        if let Some([x, y, z, w]) = T::simd_add4(self.to_array(), other.to_array()) {
+            return Vector4::new(x, y, z, w);
+        }
         Self {
             x: self.x + other.x,
             y: self.y + other.y,
@@ -104,21 +162,19 @@ impl Add for Vector4f {
     }
 }
 
-impl AddAssign for Vector4f {
+impl<T: Scalar> AddAssign for Vector4<T> {
     fn add_assign(&mut self, other: Self) {
-        *self = Self {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
-            w: self.w + other.w
-        };
+        *self = *self + other;
     }
 }
 
-impl Sub for Vector4f {
+impl<T: Scalar> Sub for Vector4<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
+        if let Some([x, y, z, w]) = T::simd_sub4(self.to_array(), other.to_array()) {
+            return Vector4::new(x, y, z, w);
+        }
         Self {
             x: self.x - other.x,
             y: self.y - other.y,
@@ -128,26 +184,152 @@ impl Sub for Vector4f {
     }
 }
 
-impl SubAssign for Vector4f {
+impl<T: Scalar> SubAssign for Vector4<T> {
     fn sub_assign(&mut self, other: Self) {
-        *self = Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-            w: self.w - other.w
-        };
+        *self = *self - other;
+    }
+}
+
+impl<T: SignedScalar> Neg for Vector4<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w
+        }
+    }
+}
+
+impl<T: Scalar> Mul for Vector4<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        if let Some([x, y, z, w]) = T::simd_mul4(self.to_array(), other.to_array()) {
+            return Vector4::new(x, y, z, w);
+        }
+        Self {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+            w: self.w * other.w
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign for Vector4<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Scalar> Div for Vector4<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        if let Some([x, y, z, w]) = T::simd_div4(self.to_array(), other.to_array()) {
+            return Vector4::new(x, y, z, w);
+        }
+        Self {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+            w: self.w / other.w
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign for Vector4<T> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector4<T> {
+    type Output = Self;
+
+    fn mul(self, s: T) -> Self {
+        if let Some([x, y, z, w]) = T::simd_scale4(self.to_array(), s) {
+            return Vector4::new(x, y, z, w);
+        }
+        Self {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s,
+            w: self.w * s
+        }
     }
 }
 
-impl From<Vector4f> for [f32; 4] {
-    fn from(vec: Vector4f) -> [f32; 4] {
+impl<T: Scalar> MulAssign<T> for Vector4<T> {
+    fn mul_assign(&mut self, s: T) {
+        *self = *self * s;
+    }
+}
+
+impl<T: Scalar> Div<T> for Vector4<T> {
+    type Output = Self;
+
+    fn div(self, s: T) -> Self {
+        Self {
+            x: self.x / s,
+            y: self.y / s,
+            z: self.z / s,
+            w: self.w / s
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Vector4<T> {
+    fn div_assign(&mut self, s: T) {
+        *self = *self / s;
+    }
+}
+
+forward_ref_unop!(impl [T: SignedScalar] Neg, neg for Vector4<T>);
+forward_ref_binop!(impl [T: Scalar] Add, add for Vector4<T>, Vector4<T>);
+forward_ref_binop!(impl [T: Scalar] Sub, sub for Vector4<T>, Vector4<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Vector4<T>, Vector4<T>);
+forward_ref_binop!(impl [T: Scalar] Div, div for Vector4<T>, Vector4<T>);
+forward_ref_binop!(impl [T: Scalar] Mul, mul for Vector4<T>, T);
+forward_ref_binop!(impl [T: Scalar] Div, div for Vector4<T>, T);
+forward_ref_op_assign!(impl [T: Scalar] AddAssign, add_assign for Vector4<T>, Vector4<T>);
+forward_ref_op_assign!(impl [T: Scalar] SubAssign, sub_assign for Vector4<T>, Vector4<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Vector4<T>, Vector4<T>);
+forward_ref_op_assign!(impl [T: Scalar] DivAssign, div_assign for Vector4<T>, Vector4<T>);
+forward_ref_op_assign!(impl [T: Scalar] MulAssign, mul_assign for Vector4<T>, T);
+forward_ref_op_assign!(impl [T: Scalar] DivAssign, div_assign for Vector4<T>, T);
+
+impl_scalar_lhs_mul!(Vector4 { x, y, z, w }: f32, f64, i32, u32);
+
+impl_approx_eq!(Vector4 { x, y, z, w });
+
+impl_vector_components!(Vector4, 4, (T, T, T, T) { x: 0, y: 1, z: 2, w: 3 });
+
+impl_lossless_from!(Vector4 { x, y, z, w }: f32 => f64, i32 => f64, u32 => f64);
+
+impl<T: Scalar> From<Vector4<T>> for [T; 4] {
+    fn from(vec: Vector4<T>) -> [T; 4] {
         [vec.x, vec.y, vec.z, vec.w]
     }
 }
 
 
-impl PartialEq for Vector4f {
+impl<T: Scalar> PartialEq for Vector4<T> {
     fn eq(&self, other: &Self) -> bool {
         self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
     }
-}
\ No newline at end of file
+}
+
+impl<T: Scalar + Eq> Eq for Vector4<T> {}
+
+impl<T: Scalar + Hash> Hash for Vector4<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+        self.z.hash(state);
+        self.w.hash(state);
+    }
+}
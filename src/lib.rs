@@ -1,13 +1,48 @@
 pub mod core;
 
 pub use crate::core::{
-    vector2f::Vector2f,
-    vector3f::Vector3f,
-    vector4f::Vector4f,
-    matrix3f::Matrix3f,
-    matrix4f::Matrix4f,
+    scalar::{
+        Scalar,
+        SignedScalar,
+        Float,
+    },
+    vector2f::{Vector2, Vector2f, Vector2d, Vector2i, Vector2u},
+    vector3f::{Vector3, Vector3f, Vector3d, Vector3i, Vector3u},
+    vector4f::{Vector4, Vector4f, Vector4d, Vector4i, Vector4u},
+    matrix3f::{Matrix3, Matrix3f, Matrix3d},
+    matrix4f::{Matrix4, Matrix4f, Matrix4d},
+    quaternion::{Quat, Quaternion, Quaterniond},
+    transform::{
+        Transform,
+        GlobalTransform,
+        Parent,
+        Children,
+        TransformBundle,
+        propagate_transforms,
+        add_transform_systems,
+        set_parent,
+        remove_parent,
+    },
+    euler::EulerRot,
+    aabb::{Aabb, Aabbf, Aabbd, Aabb2, Aabb2f, Aabb2d},
+    plane::{Plane, Planef, Planed},
+    sphere::{Sphere, Spheref, Sphered},
+    obb::{Obb, Obbf, Obbd},
+    triangle::{Triangle, Trianglef, Triangled, Triangle2, Triangle2f, Triangle2d},
+    segment::{Segment, Segmentf, Segmentd},
+    capsule::{Capsule, Capsulef, Capsuled, Contact},
+    ray::{Ray, Rayf, Rayd, RayHit},
+    frustum::{Frustum, Frustumf, Frustumd, Containment},
+    error::MathError,
+    approx::ApproxEq,
     angles::{
         deg,
         rad,
+        Rad,
+        Deg,
+        Radf,
+        Radd,
+        Degf,
+        Degd,
     },
 };
\ No newline at end of file
@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Aabbf, Aabb2f};
+    use ember_math::core::{Vector2f, Vector3f, Vector4f, Matrix3f, Matrix4f, Quaternion, Rad};
+    use ember_math::assert_approx_eq;
+
+    static EPS:f32 = 1e-5;
+
+    pub fn unit() -> Aabbf {
+        Aabbf::new(Vector3f::new(-1.0, -1.0, -1.0), Vector3f::new(1.0, 1.0, 1.0))
+    }
+
+    #[test]
+    pub fn test_construction(){
+        let points = [
+            Vector3f::new(1.0, 5.0, -2.0),
+            Vector3f::new(-3.0, 0.0, 4.0),
+            Vector3f::new(2.0, 1.0, 0.0),
+        ];
+        let b = Aabbf::from_points(&points);
+        assert_eq!(b.min, Vector3f::new(-3.0, 0.0, -2.0));
+        assert_eq!(b.max, Vector3f::new(2.0, 5.0, 4.0));
+        assert_eq!(b.center(), Vector3f::new(-0.5, 2.5, 1.0));
+        assert_eq!(b.extents(), Vector3f::new(2.5, 2.5, 3.0));
+        assert_eq!(b.size(), Vector3f::new(5.0, 5.0, 6.0));
+        assert_eq!(Aabbf::from_center_extents(b.center(), b.extents()), b);
+
+        assert!(Aabbf::from_points(&[]).is_empty());
+        assert!(Aabbf::default().is_empty());
+        assert!(!b.is_empty());
+    }
+
+    #[test]
+    pub fn test_union_intersection(){
+        let a = unit();
+        let b = Aabbf::new(Vector3f::new(0.0, 0.5, -3.0), Vector3f::new(2.0, 3.0, 0.0));
+        let u = a.union(&b);
+        assert_eq!(u, Aabbf::new(Vector3f::new(-1.0, -1.0, -3.0), Vector3f::new(2.0, 3.0, 1.0)));
+        assert_eq!(a.union(&Aabbf::empty()), a);
+
+        let i = a.intersection(&b).unwrap();
+        assert_eq!(i, Aabbf::new(Vector3f::new(0.0, 0.5, -1.0), Vector3f::new(1.0, 1.0, 0.0)));
+        assert!(a.intersects(&b));
+
+        // touching faces still intersect
+        let touching = Aabbf::new(Vector3f::new(1.0, -1.0, -1.0), Vector3f::new(2.0, 1.0, 1.0));
+        assert!(a.intersects(&touching));
+
+        let apart = Aabbf::new(Vector3f::new(1.5, -1.0, -1.0), Vector3f::new(2.0, 1.0, 1.0));
+        assert_eq!(a.intersection(&apart), None);
+        assert!(!a.intersects(&apart));
+    }
+
+    #[test]
+    pub fn test_containment_and_expansion(){
+        let a = unit();
+        assert!(a.contains_point(Vector3f::new(1.0, 0.0, -1.0)));
+        assert!(!a.contains_point(Vector3f::new(1.1, 0.0, 0.0)));
+        assert!(a.contains(&a.expand(-0.5)));
+        assert!(!a.contains(&a.expand(0.5)));
+        assert!(a.contains(&Aabbf::empty()));
+
+        assert_eq!(a.expand(1.0), Aabbf::new(Vector3f::new(-2.0, -2.0, -2.0), Vector3f::new(2.0, 2.0, 2.0)));
+        let e = a.expand_to_point(Vector3f::new(3.0, 0.0, 0.0));
+        assert_eq!(e.max, Vector3f::new(3.0, 1.0, 1.0));
+        assert_eq!(e.min, a.min);
+    }
+
+    #[test]
+    pub fn test_transform(){
+        let q = Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(std::f32::consts::FRAC_PI_4));
+        let m = Matrix4f::from_scale_rotation_translation(Vector3f::new(2.0, 1.0, 1.0), q, Vector3f::new(10.0, 0.0, 0.0));
+        let b = Aabbf::new(Vector3f::new(0.0, 0.0, 0.0), Vector3f::new(1.0, 1.0, 1.0));
+
+        // same as bounding the transformed corners
+        let corners = b.corners().map(|c| m.transform_point3(c));
+        let expected = Aabbf::from_points(&corners);
+        assert_approx_eq!(b.transform(&m), expected, epsilon = EPS);
+
+        let p = Matrix4f::perspective_gl(1.0, 1.0, 0.1, 100.0);
+        let far = Aabbf::new(Vector3f::new(-1.0, -1.0, -10.0), Vector3f::new(1.0, 1.0, -5.0));
+        let expected = Aabbf::from_points(&far.corners().map(|c| p.project_point3(c)));
+        assert_approx_eq!(far.transform(&p), expected, epsilon = EPS);
+        for c in far.corners() {
+            let clip = p.transform(Vector4f::new(c.x, c.y, c.z, 1.0));
+            assert!(far.transform(&p).expand(EPS).contains_point(clip.truncate() / clip.w));
+        }
+
+        // a box straddling the camera plane has no finite image
+        let straddling = Aabbf::new(Vector3f::new(-1.0, -1.0, -5.0), Vector3f::new(1.0, 1.0, 5.0));
+        let t = straddling.transform(&p);
+        assert!(t.contains(&far.transform(&p)));
+        assert_eq!(t, Aabbf::unbounded());
+        assert!(t.is_unbounded());
+
+        // and stays unbounded, rather than turning to NaN, through an
+        // affine transform
+        assert_eq!(t.transform(&m), Aabbf::unbounded());
+        let half = Aabbf::new(Vector3f::zero(), Vector3f::new(1.0, f32::INFINITY, 1.0));
+        assert!(half.is_unbounded());
+        assert_eq!(half.transform(&Matrix4f::from_scale(0.0)), Aabbf::unbounded());
+        assert!(!far.is_unbounded());
+        assert!(!Aabbf::empty().is_unbounded());
+
+        // entirely behind the camera is still a bounded projection
+        let behind = Aabbf::new(Vector3f::new(-1.0, -1.0, 5.0), Vector3f::new(1.0, 1.0, 10.0));
+        let expected = Aabbf::from_points(&behind.corners().map(|c| p.project_point3(c)));
+        assert_approx_eq!(behind.transform(&p), expected, epsilon = EPS);
+
+        assert!(Aabbf::empty().transform(&m).is_empty());
+    }
+
+    #[test]
+    pub fn test_aabb2(){
+        let b = Aabb2f::from_points(&[Vector2f::new(1.0, 2.0), Vector2f::new(-1.0, 0.0)]);
+        assert_eq!(b.center(), Vector2f::new(0.0, 1.0));
+        assert_eq!(b.extents(), Vector2f::new(1.0, 1.0));
+        assert!(b.contains_point(Vector2f::new(0.5, 1.5)));
+        assert!(b.intersects(&Aabb2f::new(Vector2f::new(0.5, 1.5), Vector2f::new(3.0, 3.0))));
+        assert_eq!(b.corners().len(), 4);
+
+        // rotate by 90 degrees and translate by (5, 0)
+        let m = Matrix3f::new(
+            0.0, -1.0, 5.0,
+            1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0
+        );
+        let t = b.transform(&m);
+        assert_approx_eq!(t, Aabb2f::new(Vector2f::new(3.0, -1.0), Vector2f::new(5.0, 1.0)), epsilon = EPS);
+
+        // the line w = 0 runs through x = 0
+        let p = Matrix3f::new(
+            1.0, 0.0, 0.0,
+            0.0, 1.0, 0.0,
+            1.0, 0.0, 0.0
+        );
+        assert_eq!(b.transform(&p), Aabb2f::unbounded());
+        assert_eq!(Aabb2f::unbounded().transform(&m), Aabb2f::unbounded());
+        let right = Aabb2f::new(Vector2f::new(1.0, 0.0), Vector2f::new(2.0, 1.0));
+        assert_approx_eq!(right.transform(&p), Aabb2f::new(Vector2f::new(1.0, 0.0), Vector2f::new(1.0, 1.0)), epsilon = EPS);
+    }
+}
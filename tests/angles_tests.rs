@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Rad, Deg, Radf, Degf, Degd, Matrix3f, Matrix4f, Quaternion, Vector3f, EulerRot};
+    use ember_math::core::angles::{deg, rad};
+    use ember_math::assert_approx_eq;
+    use bevy_reflect::{FromReflect, Reflect, ReflectRef};
+    use std::f32::consts::{PI, FRAC_PI_2};
+
+    static EPS:f32 = 1e-5;
+
+    #[test]
+    pub fn test_free_functions(){
+        assert_approx_eq!(deg(PI), 180.0);
+        assert_approx_eq!(rad(90.0f32), FRAC_PI_2);
+        assert_approx_eq!(deg(std::f64::consts::PI), 180.0);
+    }
+
+    #[test]
+    pub fn test_conversions(){
+        assert_approx_eq!(Deg(180.0f32).to_rad().0, PI);
+        assert_approx_eq!(Rad(FRAC_PI_2).to_deg().0, 90.0);
+        let r: Radf = Deg(45.0).into();
+        assert_approx_eq!(r.0, PI / 4.0);
+        let d: Degf = Rad(PI).into();
+        assert_approx_eq!(d.0, 180.0);
+        assert_eq!(Degd::from(Deg(30.0f32)), Deg(30.0f64));
+    }
+
+    #[test]
+    pub fn test_arithmetic(){
+        let mut a = Deg(30.0f32);
+        assert_eq!(a + Deg(15.0), Deg(45.0));
+        assert_eq!(a - Deg(45.0), Deg(-15.0));
+        assert_eq!(-a, Deg(-30.0));
+        assert_eq!(a * 2.0, Deg(60.0));
+        assert_eq!(3.0 * a, Deg(90.0));
+        assert_eq!(a / 2.0, Deg(15.0));
+        assert_eq!(Deg(90.0f32) / a, 3.0);
+        assert_eq!(Deg(400.0f32) % Deg(360.0), Deg(40.0));
+        a += Deg(10.0);
+        a -= Deg(5.0);
+        a *= 2.0;
+        a /= 7.0;
+        assert_eq!(a, Deg(10.0));
+        assert!(Rad(1.0f32) < Rad(2.0));
+    }
+
+    #[test]
+    pub fn test_normalize(){
+        assert_eq!(Deg(370.0f32).normalize(), Deg(10.0));
+        assert_eq!(Deg(-10.0f32).normalize(), Deg(350.0));
+        assert_eq!(Deg(-720.0f32).normalize(), Deg(0.0));
+        assert_eq!(Deg(360.0f32).normalize(), Deg(0.0));
+        assert_eq!(Deg(-1e-10f32).normalize(), Deg(0.0));
+
+        assert_eq!(Deg(190.0f32).normalize_signed(), Deg(-170.0));
+        assert_eq!(Deg(180.0f32).normalize_signed(), Deg(180.0));
+        assert_eq!(Deg(-180.0f32).normalize_signed(), Deg(180.0));
+        assert_eq!(Deg(-90.0f32).normalize_signed(), Deg(-90.0));
+
+        assert_approx_eq!(Rad(-FRAC_PI_2).normalize().0, 3.0 * FRAC_PI_2);
+        assert_approx_eq!(Rad(3.0 * PI).normalize_signed().0, PI);
+        let r = Rad(5.0f32).normalize();
+        assert!(r.0 >= 0.0 && r < Rad::turn());
+    }
+
+    #[test]
+    pub fn test_shortest_difference(){
+        assert_eq!(Deg(350.0f32).shortest_difference(Deg(10.0)), Deg(20.0));
+        assert_eq!(Deg(10.0f32).shortest_difference(Deg(350.0)), Deg(-20.0));
+        assert_eq!(Deg(-170.0f32).shortest_difference(Deg(170.0)), Deg(-20.0));
+        assert_eq!(Deg(0.0f32).shortest_difference(Deg(720.0)), Deg(0.0));
+        assert_approx_eq!(Rad(0.1f32).shortest_difference(Rad(2.0 * PI - 0.1)).0, -0.2, epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_trig(){
+        assert_approx_eq!(Deg(30.0f32).sin(), 0.5, epsilon = EPS);
+        assert_approx_eq!(Deg(60.0f32).cos(), 0.5, epsilon = EPS);
+        assert_approx_eq!(Deg(45.0f32).tan(), 1.0, epsilon = EPS);
+        let (s, c) = Rad(FRAC_PI_2).sin_cos();
+        assert_approx_eq!(s, 1.0);
+        assert_approx_eq!(c, 0.0, epsilon = EPS);
+
+        assert_approx_eq!(Deg::asin(0.5f32).0, 30.0, epsilon = 1e-4);
+        assert_approx_eq!(Deg::acos(0.0f32).0, 90.0);
+        assert_approx_eq!(Rad::atan(1.0f32).0, PI / 4.0);
+        assert_approx_eq!(Deg::atan2(-1.0f32, -1.0).0, -135.0);
+    }
+
+    #[test]
+    pub fn test_rotation_constructors(){
+        let v = Vector3f::new(1.0, 0.0, 0.0);
+        let axis = Vector3f::new(0.0, 0.0, 1.0);
+        let from_rad = Matrix3f::from_axis_angle(axis, Rad(FRAC_PI_2));
+        let from_deg = Matrix3f::from_axis_angle(axis, Deg(90.0));
+        assert_approx_eq!(from_deg, from_rad, epsilon = EPS);
+        assert_approx_eq!(from_deg.transform(v), Vector3f::new(0.0, 1.0, 0.0), epsilon = EPS);
+
+        assert_approx_eq!(Matrix4f::from_angle_y(Deg(30.0)), Matrix4f::from_angle_y_deg(30.0));
+        assert_approx_eq!(Matrix4f::from_angle_z(Deg(-45.0)), Matrix4f::from_angle_z(Rad(-PI / 4.0)), epsilon = EPS);
+        assert_approx_eq!(Matrix3f::from_angle_x(Deg(90.0)), Matrix3f::from_angle_x(Rad(FRAC_PI_2)), epsilon = EPS);
+        assert_approx_eq!(
+            Quaternion::from_axis_angle(axis, Deg(180.0)),
+            Quaternion::from_axis_angle(axis, Rad(PI)),
+            epsilon = EPS
+        );
+        assert_approx_eq!(
+            Matrix3f::from_euler(EulerRot::YXZ, Deg(90.0), Rad(0.5), Rad(0.25)),
+            Matrix3f::from_euler(EulerRot::YXZ, Rad(FRAC_PI_2), Rad(0.5), Rad(0.25)),
+            epsilon = EPS
+        );
+    }
+
+    #[test]
+    pub fn test_reflect(){
+        let d = Deg(12.5f32);
+        let reflected: &dyn Reflect = &d;
+        assert_eq!(Deg::<f32>::from_reflect(reflected), Some(d));
+        match reflected.reflect_ref() {
+            ReflectRef::TupleStruct(t) => assert_eq!(t.field(0).unwrap().downcast_ref::<f32>(), Some(&12.5)),
+            _ => panic!("expected a tuple struct"),
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{ApproxEq, Vector2f, Vector3f, Vector3d, Vector4f, Matrix3f, Matrix4d, Quaternion, Rad};
+    use ember_math::{assert_approx_eq, assert_abs_diff_eq, assert_ulps_eq};
+
+    #[test]
+    pub fn test_abs_diff_eq(){
+        assert!(1.0f32.abs_diff_eq(&1.0005, 1e-3));
+        assert!(!1.0f32.abs_diff_eq(&1.002, 1e-3));
+        assert!(!f32::NAN.abs_diff_eq(&f32::NAN, 1.0));
+
+        let a = Vector3f::new(1.0, 2.0, 3.0);
+        let b = Vector3f::new(1.0, 2.0005, 3.0);
+        assert!(a.abs_diff_eq(&b, 1e-3));
+        assert!(!a.abs_diff_eq(&b, 1e-4));
+    }
+
+    #[test]
+    pub fn test_relative_eq(){
+        // an absolute tolerance of 1e-3 is meaningless at this magnitude
+        assert!(1.0e7f32.relative_eq(&1.000001e7, 0.0, 1e-6));
+        assert!(!1.0e7f32.relative_eq(&1.0001e7, 0.0, 1e-6));
+        // epsilon is the floor near zero
+        assert!(1e-9f32.relative_eq(&-1e-9, 1e-8, 1e-6));
+        assert!(!1e-9f32.relative_eq(&-1e-9, 0.0, 1e-6));
+
+        assert!(f32::INFINITY.relative_eq(&f32::INFINITY, 0.0, 0.0));
+        assert!(!f32::INFINITY.relative_eq(&f32::MAX, 1.0, 1.0));
+        assert!(!f64::NAN.relative_eq(&f64::NAN, 1.0, 1.0));
+
+        let v = Vector3f::new(0.1, 0.2, 0.3) * 3.0;
+        assert!(v != Vector3f::new(0.3, 0.6, 0.9));
+        assert!(v.approx_eq(&Vector3f::new(0.3, 0.6, 0.9)));
+    }
+
+    #[test]
+    pub fn test_ulps_eq(){
+        let a = 1.0f32;
+        let b = f32::from_bits(a.to_bits() + 3);
+        assert!(a.ulps_eq(&b, 0.0, 3));
+        assert!(!a.ulps_eq(&b, 0.0, 2));
+        assert!(b.ulps_eq(&a, 0.0, 3));
+        // opposite signs are only equal within epsilon
+        assert!(!1e-40f32.ulps_eq(&-1e-40, 0.0, 4));
+        assert!(1e-40f32.ulps_eq(&-1e-40, f32::EPSILON, 4));
+        assert!(0.0f32.ulps_eq(&-0.0, 0.0, 0));
+
+        let c = 1.0f64;
+        let d = f64::from_bits(c.to_bits() + 4);
+        assert!(c.ulps_eq(&d, 0.0, 4));
+        assert!(!c.ulps_eq(&d, 0.0, 3));
+    }
+
+    #[test]
+    pub fn test_matrices(){
+        let a = Matrix3f::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(std::f32::consts::FRAC_PI_2));
+        let b = Matrix3f::new(
+            0.0, -1.0, 0.0,
+            1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0
+        );
+        assert!(a != b);
+        assert!(a.abs_diff_eq(&b, 1e-6));
+        assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+
+        let m = Matrix4d::from_scale(3.0);
+        assert_approx_eq!(m.inverse(), Matrix4d::from_scale(1.0 / 3.0));
+    }
+
+    #[test]
+    pub fn test_quaternions(){
+        let q = Quaternion::from_axis_angle(Vector3f::new(0.0, 1.0, 0.0), Rad(std::f32::consts::PI));
+        assert_abs_diff_eq!(q, Quaternion::new(0.0, 1.0, 0.0, 0.0), epsilon = 1e-6);
+        // component-wise, so q and -q are not approximately equal
+        assert!(!q.abs_diff_eq(&q.scale(-1.0), 1e-6));
+    }
+
+    #[test]
+    pub fn test_macros(){
+        let v = Vector2f::new(0.1, 0.7) * 10.0;
+        assert_approx_eq!(v, Vector2f::new(1.0, 7.0));
+        assert_approx_eq!(v.y, 7.0, epsilon = 1e-5);
+        assert_approx_eq!(1.0e7f32, 1.000001e7, max_relative = 1e-6);
+        assert_approx_eq!(Vector3d::new(1.0, 2.0, 3.0), Vector3d::new(1.0, 2.0, 3.0 + 1e-13), epsilon = 0.0, max_relative = 1e-12);
+        assert_ulps_eq!(0.1f32 + 0.2, 0.3);
+        assert_ulps_eq!(Vector4f::one() * 0.1, Vector4f::new(0.1, 0.1, 0.1, 0.1), max_ulps = 0);
+        assert_abs_diff_eq!(1.0f64, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "left: `Vector3 { x: 1.0, y: 2.0, z: 3.0 }`")]
+    pub fn test_macro_failure(){
+        assert_approx_eq!(Vector3f::new(1.0, 2.0, 3.0), Vector3f::new(1.0, 2.0, 3.1));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Capsulef, Spheref, Aabbf};
+    use ember_math::core::Vector3f;
+    use ember_math::assert_approx_eq;
+    use crate::common::Lcg;
+
+    static EPS:f32 = 1e-5;
+
+    // a standing character, feet at the origin
+    pub fn character() -> Capsulef {
+        Capsulef::new(Vector3f::new(0.0, 0.5, 0.0), Vector3f::new(0.0, 1.5, 0.0), 0.5)
+    }
+
+    #[test]
+    pub fn test_shape(){
+        let c = character();
+        assert!(c.contains_point(Vector3f::new(0.4, 1.0, 0.0)));
+        assert!(c.contains_point(Vector3f::new(0.0, 0.0, 0.0)));
+        assert!(!c.contains_point(Vector3f::new(0.4, 0.1, 0.0)));
+        assert_eq!(c.to_aabb(), Aabbf::new(Vector3f::new(-0.5, 0.0, -0.5), Vector3f::new(0.5, 2.0, 0.5)));
+    }
+
+    #[test]
+    pub fn test_capsules(){
+        let c = character();
+        let other = Capsulef::new(Vector3f::new(0.7, 0.0, -1.0), Vector3f::new(0.7, 0.0, 1.0), 0.4);
+        let contact = c.contact(&other).unwrap();
+        assert_approx_eq!(contact.normal, Vector3f::new(0.7, -0.5, 0.0).normalize(), epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.9 - (0.49f32 + 0.25).sqrt(), epsilon = EPS);
+        assert!(c.intersects(&other));
+
+        // side by side
+        let beside = Capsulef::new(Vector3f::new(0.8, 0.0, 0.0), Vector3f::new(0.8, 3.0, 0.0), 0.4);
+        let contact = c.contact(&beside).unwrap();
+        assert_approx_eq!(contact.normal, Vector3f::new(1.0, 0.0, 0.0), epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.1, epsilon = EPS);
+        let back = beside.contact(&c).unwrap();
+        assert_approx_eq!(back.normal, -contact.normal, epsilon = EPS);
+
+        let apart = Capsulef::new(Vector3f::new(1.1, 0.0, 0.0), Vector3f::new(1.1, 3.0, 0.0), 0.4);
+        assert!(c.contact(&apart).is_none());
+        assert!(!c.intersects(&apart));
+
+        // crossing axes push apart along their common normal
+        let crossing = Capsulef::new(Vector3f::new(-1.0, 1.0, 0.0), Vector3f::new(1.0, 1.0, 0.0), 0.2);
+        let contact = c.contact(&crossing).unwrap();
+        assert_approx_eq!(contact.normal.dot(Vector3f::new(0.0, 0.0, 1.0)).abs(), 1.0, epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.7, epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_spheres(){
+        let c = character();
+        let contact = c.contact_sphere(&Spheref::new(Vector3f::new(0.0, 2.5, 0.0), 0.75)).unwrap();
+        assert_approx_eq!(contact.normal, Vector3f::new(0.0, 1.0, 0.0), epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.25, epsilon = EPS);
+
+        let contact = c.contact_sphere(&Spheref::new(Vector3f::new(0.0, 1.0, -0.6), 0.2)).unwrap();
+        assert_approx_eq!(contact.normal, Vector3f::new(0.0, 0.0, -1.0), epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.1, epsilon = EPS);
+
+        // centered on the axis
+        let contact = c.contact_sphere(&Spheref::new(Vector3f::new(0.0, 1.0, 0.0), 0.2)).unwrap();
+        assert_approx_eq!(contact.normal.magnitude(), 1.0, epsilon = EPS);
+        assert_approx_eq!(contact.normal.y, 0.0, epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.7, epsilon = EPS);
+
+        assert!(c.contact_sphere(&Spheref::new(Vector3f::new(1.0, 1.0, 0.0), 0.4)).is_none());
+        assert!(c.intersects_sphere(&Spheref::new(Vector3f::new(1.0, 1.0, 0.0), 0.5)));
+    }
+
+    #[test]
+    pub fn test_aabbs(){
+        let c = character();
+        let floor = Aabbf::new(Vector3f::new(-5.0, -1.0, -5.0), Vector3f::new(5.0, 0.1, 5.0));
+        let contact = c.contact_aabb(&floor).unwrap();
+        assert_approx_eq!(contact.normal, Vector3f::new(0.0, -1.0, 0.0), epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.1, epsilon = EPS);
+
+        // against a box edge, the normal points at the edge
+        let ledge = Aabbf::new(Vector3f::new(0.3, -1.0, -1.0), Vector3f::new(2.0, 0.3, 1.0));
+        let contact = c.contact_aabb(&ledge).unwrap();
+        assert_approx_eq!(contact.normal, Vector3f::new(0.3, -0.2, 0.0).normalize(), epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.5 - (0.09f32 + 0.04).sqrt(), epsilon = EPS);
+
+        // a tilted capsule whose closest point is inside its segment, over
+        // the box edge at x = 1, y = 1
+        let tilted = Capsulef::new(Vector3f::new(-2.0, 3.0, 0.0), Vector3f::new(2.0, 1.0, 0.0), 0.5);
+        let block = Aabbf::new(Vector3f::new(-1.0, -1.0, -1.0), Vector3f::new(1.0, 1.0, 1.0));
+        let contact = tilted.contact_aabb(&block).unwrap();
+        assert_approx_eq!(contact.normal, Vector3f::new(-1.0, -2.0, 0.0).normalize(), epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.5 - 1.0 / 5.0f32.sqrt(), epsilon = EPS);
+
+        // a segment through the box leaves by the nearest face
+        let wall = Aabbf::new(Vector3f::new(-0.2, -1.0, -2.0), Vector3f::new(0.4, 3.0, 2.0));
+        let contact = c.contact_aabb(&wall).unwrap();
+        assert_approx_eq!(contact.normal, Vector3f::new(1.0, 0.0, 0.0), epsilon = EPS);
+        assert_approx_eq!(contact.depth, 0.7, epsilon = EPS);
+
+        assert!(c.contact_aabb(&Aabbf::new(Vector3f::new(0.6, 0.0, 0.0), Vector3f::new(1.0, 1.0, 1.0))).is_none());
+        assert!(!c.intersects_aabb(&Aabbf::empty()));
+        assert!(c.intersects_aabb(&floor));
+    }
+
+    #[test]
+    pub fn test_aabbs_random(){
+        // the distance from the segment to the box matches a dense sampling
+        let mut rng = Lcg(9);
+        for _ in 0..300 {
+            let c = Capsulef::new(rng.vector(-4.0, 4.0), rng.vector(-4.0, 4.0), rng.range(0.1, 1.5));
+            let b = Aabbf::from_center_extents(rng.vector(-2.0, 2.0), rng.vector(0.1, 1.5));
+            let sampled = (0..=1000)
+                .map(|i| {
+                    let p = c.segment.at(i as f32 / 1000.0);
+                    (p.max(b.min).min(b.max) - p).magnitude()
+                })
+                .fold(f32::INFINITY, f32::min);
+            match c.contact_aabb(&b) {
+                Some(contact) => {
+                    assert!(sampled <= c.radius + 1e-2);
+                    assert_approx_eq!(contact.normal.magnitude(), 1.0, epsilon = 1e-4);
+                    if sampled > 0.0 {
+                        assert_approx_eq!(c.radius - contact.depth, sampled, epsilon = 1e-2);
+                    }
+                }
+                None => assert!(sampled > c.radius - 1e-4),
+            }
+        }
+    }
+}
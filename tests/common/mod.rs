@@ -0,0 +1,50 @@
+// Helpers shared by the integration tests. Each test crate uses only some
+// of them.
+#![allow(dead_code)]
+
+use ember_math::core::{Matrix3f, Matrix4f, Vector3f, Vector4f, Quaternion, Rad};
+
+// Small deterministic generator so the property tests are reproducible.
+pub struct Lcg(pub u64);
+
+impl Lcg {
+    pub fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.0 >> 40) as f32) / ((1u64 << 24) as f32)
+    }
+
+    pub fn range(&mut self, lo: f32, hi: f32) -> f32 {
+        lo + (hi - lo) * self.next()
+    }
+
+    pub fn vector(&mut self, lo: f32, hi: f32) -> Vector3f {
+        Vector3f::new(self.range(lo, hi), self.range(lo, hi), self.range(lo, hi))
+    }
+
+    pub fn vector4(&mut self, lo: f32, hi: f32) -> Vector4f {
+        self.vector(lo, hi).extend(self.range(lo, hi))
+    }
+
+    pub fn unit(&mut self) -> Vector3f {
+        loop {
+            let v = self.vector(-1.0, 1.0);
+            let m = v.magnitude();
+            if m > 0.1 && m <= 1.0 {
+                return v / m;
+            }
+        }
+    }
+
+    pub fn rotation(&mut self) -> Quaternion {
+        let axis = self.vector(-1.0, 1.0).normalize();
+        Quaternion::from_axis_angle(axis, Rad(self.range(-3.0, 3.0)))
+    }
+
+    pub fn matrix3(&mut self, lo: f32, hi: f32) -> Matrix3f {
+        Matrix3f::from(std::array::from_fn(|_| self.range(lo, hi)))
+    }
+
+    pub fn matrix4(&mut self, lo: f32, hi: f32) -> Matrix4f {
+        Matrix4f::from(std::array::from_fn(|_| self.range(lo, hi)))
+    }
+}
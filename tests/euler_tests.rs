@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{EulerRot, Matrix3f, Matrix4f, Quaternion, Rad, Vector3f};
+    use ember_math::assert_approx_eq;
+
+    static EPS:f32 = 1e-5;
+    static HALF_PI:f32 = std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    pub fn test_from_euler_order(){
+        let (a, b, c) = (0.3, -0.7, 1.1);
+        let (rx, ry, rz) = (Matrix3f::from_angle_x(Rad(a)), Matrix3f::from_angle_y(Rad(b)), Matrix3f::from_angle_z(Rad(c)));
+        assert_approx_eq!(Matrix3f::from_euler(EulerRot::XYZ, Rad(a), Rad(b), Rad(c)), rx * ry * rz, epsilon = EPS);
+        assert_approx_eq!(Matrix3f::from_euler(EulerRot::XYZEx, Rad(a), Rad(b), Rad(c)), rz * ry * rx, epsilon = EPS);
+
+        let (ry, rz, rx) = (Matrix3f::from_angle_y(Rad(a)), Matrix3f::from_angle_z(Rad(b)), Matrix3f::from_angle_x(Rad(c)));
+        assert_approx_eq!(Matrix3f::from_euler(EulerRot::YZX, Rad(a), Rad(b), Rad(c)), ry * rz * rx, epsilon = EPS);
+        assert_approx_eq!(Matrix3f::from_euler(EulerRot::YZXEx, Rad(a), Rad(b), Rad(c)), rx * rz * ry, epsilon = EPS);
+
+        // Quaternion::from_euler rotates about the world x, then y, then z
+        let q = Quaternion::from_euler(Rad(a), Rad(b), Rad(c));
+        assert_approx_eq!(Matrix3f::from_euler(EulerRot::XYZEx, Rad(a), Rad(b), Rad(c)), q.to_matrix3(), epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_intrinsic_rotates_with_frame(){
+        // yaw a quarter turn about y, then pitch about the new x (old -z)
+        let m = Matrix3f::from_euler(EulerRot::YXZ, Rad(HALF_PI), Rad(HALF_PI), Rad(0.0));
+        assert_approx_eq!(m.transform(Vector3f::new(0.0, 1.0, 0.0)), Vector3f::new(1.0, 0.0, 0.0), epsilon = EPS);
+        assert_approx_eq!(m.transform(Vector3f::new(0.0, 0.0, 1.0)), Vector3f::new(0.0, -1.0, 0.0), epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_reversed(){
+        for order in EulerRot::ALL {
+            assert_eq!(order.reversed().reversed(), order);
+            assert_ne!(order.reversed().is_extrinsic(), order.is_extrinsic());
+            assert_approx_eq!(
+                Matrix3f::from_euler(order, Rad(0.2), Rad(0.5), Rad(-1.3)),
+                Matrix3f::from_euler(order.reversed(), Rad(-1.3), Rad(0.5), Rad(0.2)),
+                epsilon = EPS
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_round_trip(){
+        let outer = [-3.0, -2.0, -0.5, 0.0, 0.25, 1.5, 3.0];
+        let middle = [-1.5, -1.0, -0.1, 0.0, 0.4, 1.2, 1.5];
+        for order in EulerRot::ALL {
+            for &a in outer.iter() {
+                for &b in middle.iter() {
+                    for &c in outer.iter() {
+                        let m = Matrix3f::from_euler(order, Rad(a), Rad(b), Rad(c));
+                        let (ra, rb, rc) = m.to_euler(order);
+                        assert_approx_eq!(Vector3f::new(ra, rb, rc), Vector3f::new(a, b, c), epsilon = 1e-3);
+                        assert_approx_eq!(Matrix3f::from_euler(order, Rad(ra), Rad(rb), Rad(rc)), m, epsilon = EPS);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_gimbal_lock(){
+        for order in EulerRot::ALL {
+            for &b in [HALF_PI, -HALF_PI].iter() {
+                let m = Matrix3f::from_euler(order, Rad(0.4), Rad(b), Rad(-0.9));
+                let (ra, rb, rc) = m.to_euler(order);
+                assert_approx_eq!(rb, b, epsilon = 1e-3);
+                if order.is_extrinsic() {
+                    assert_eq!(ra, 0.0);
+                } else {
+                    assert_eq!(rc, 0.0);
+                }
+                assert!(ra.is_finite() && rc.is_finite());
+                assert_approx_eq!(Matrix3f::from_euler(order, Rad(ra), Rad(rb), Rad(rc)), m, epsilon = 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_matrix4(){
+        let m = Matrix4f::from_euler(EulerRot::ZXY, Rad(0.1), Rad(0.2), Rad(0.3));
+        let r = Matrix3f::from_euler(EulerRot::ZXY, Rad(0.1), Rad(0.2), Rad(0.3));
+        assert_eq!(m.data[0..3], r.data[0..3]);
+        assert_eq!(m.data[4..7], r.data[3..6]);
+        assert_eq!(m.data[8..11], r.data[6..9]);
+        assert_eq!(m.data[12..16], [0.0, 0.0, 0.0, 1.0]);
+
+        let (a, b, c) = m.to_euler(EulerRot::ZXY);
+        assert_approx_eq!(Vector3f::new(a, b, c), Vector3f::new(0.1, 0.2, 0.3), epsilon = EPS);
+
+        let (a, b, c) = Matrix4f::from_angle_z(Rad(0.7)).to_euler(EulerRot::XYZEx);
+        assert_approx_eq!(Vector3f::new(a, b, c), Vector3f::new(0.0, 0.0, 0.7), epsilon = EPS);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Frustumf, Containment, Aabbf};
+    use ember_math::core::{Vector3f, Matrix4f};
+    use ember_math::assert_approx_eq;
+
+    static EPS:f32 = 1e-5;
+
+    // 90 degree square view looking down -z, from 1 to 10
+    pub fn gl() -> Frustumf {
+        Frustumf::from_matrix(&Matrix4f::perspective_gl(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0))
+    }
+
+    #[test]
+    pub fn test_extraction(){
+        for f in [gl(), Frustumf::from_matrix_zero_to_one(&Matrix4f::perspective_rh(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0))] {
+            let h = std::f32::consts::FRAC_1_SQRT_2;
+            assert_approx_eq!(f.planes[0].normal, Vector3f::new(h, 0.0, -h), epsilon = EPS);
+            assert_approx_eq!(f.planes[1].normal, Vector3f::new(-h, 0.0, -h), epsilon = EPS);
+            assert_approx_eq!(f.planes[2].normal, Vector3f::new(0.0, h, -h), epsilon = EPS);
+            assert_approx_eq!(f.planes[3].normal, Vector3f::new(0.0, -h, -h), epsilon = EPS);
+
+            // near at z = -1 and far at z = -10
+            assert_approx_eq!(f.planes[4].signed_distance(Vector3f::new(0.0, 0.0, -3.0)), 2.0, epsilon = 1e-4);
+            assert_approx_eq!(f.planes[5].signed_distance(Vector3f::new(0.0, 0.0, -3.0)), 7.0, epsilon = 1e-4);
+            for p in f.planes {
+                assert_approx_eq!(p.normal.magnitude(), 1.0, epsilon = EPS);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_points(){
+        let f = gl();
+        assert!(f.contains_point(Vector3f::new(0.0, 0.0, -5.0)));
+        assert!(f.contains_point(Vector3f::new(4.9, -4.9, -5.0)));
+        assert!(!f.contains_point(Vector3f::new(5.1, 0.0, -5.0)));
+        assert!(!f.contains_point(Vector3f::new(0.0, 0.0, -0.5)));
+        assert!(!f.contains_point(Vector3f::new(0.0, 0.0, -11.0)));
+        assert!(!f.contains_point(Vector3f::new(0.0, 0.0, 5.0)));
+    }
+
+    #[test]
+    pub fn test_spheres(){
+        let f = gl();
+        assert_eq!(f.classify_sphere(Vector3f::new(0.0, 0.0, -5.0), 1.0), Containment::Inside);
+        assert_eq!(f.classify_sphere(Vector3f::new(0.0, 0.0, -9.5), 1.0), Containment::Intersecting);
+        assert_eq!(f.classify_sphere(Vector3f::new(0.0, 0.0, 2.0), 1.0), Containment::Outside);
+        assert_eq!(f.classify_sphere(Vector3f::new(8.0, 0.0, -5.0), 1.0), Containment::Outside);
+        assert!(f.intersects_sphere(Vector3f::new(5.5, 0.0, -5.0), 1.0));
+        assert!(!f.intersects_sphere(Vector3f::new(0.0, 0.0, -12.0), 1.0));
+    }
+
+    #[test]
+    pub fn test_aabbs(){
+        let f = gl();
+        let inside = Aabbf::from_center_extents(Vector3f::new(0.0, 0.0, -5.0), Vector3f::new(1.0, 1.0, 1.0));
+        let crossing = Aabbf::from_center_extents(Vector3f::new(0.0, 0.0, -1.0), Vector3f::new(0.5, 0.5, 0.5));
+        let outside = Aabbf::from_center_extents(Vector3f::new(0.0, 10.0, -5.0), Vector3f::new(1.0, 1.0, 1.0));
+        assert_eq!(f.classify_aabb(&inside), Containment::Inside);
+        assert_eq!(f.classify_aabb(&crossing), Containment::Intersecting);
+        assert_eq!(f.classify_aabb(&outside), Containment::Outside);
+        assert!(f.intersects_aabb(&crossing));
+        assert!(!f.intersects_aabb(&Aabbf::empty()));
+    }
+
+    #[test]
+    pub fn test_camera(){
+        // planes from the projection are in view space, so move world points
+        // into it with the view matrix
+        let f = gl();
+        let view = Matrix4f::look_at_rh(Vector3f::new(10.0, 0.0, 0.0), Vector3f::zero(), Vector3f::new(0.0, 1.0, 0.0));
+        assert!(f.contains_point(view.transform_point3(Vector3f::new(2.0, 0.0, 0.0))));
+        assert!(!f.contains_point(view.transform_point3(Vector3f::new(12.0, 0.0, 0.0))));
+        assert!(!f.contains_point(view.transform_point3(Vector3f::new(5.0, 0.0, 8.0))));
+    }
+
+    #[test]
+    pub fn test_view_projection(){
+        let proj = Matrix4f::perspective_gl(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+        let view = Matrix4f::look_at_rh(Vector3f::new(10.0, 0.0, 0.0), Vector3f::zero(), Vector3f::new(0.0, 1.0, 0.0));
+        let f = Frustumf::from_matrix(&(proj * view));
+        assert!(f.contains_point(Vector3f::new(2.0, 0.0, 0.0)));
+        assert!(f.contains_point(Vector3f::new(5.0, 0.0, 4.9)));
+        assert!(!f.contains_point(Vector3f::new(-0.5, 0.0, 0.0)));
+        assert!(!f.contains_point(Vector3f::new(9.5, 0.0, 0.0)));
+        assert!(!f.contains_point(Vector3f::new(5.0, 0.0, 5.1)));
+        assert_eq!(f.classify_sphere(Vector3f::new(5.0, 0.0, 0.0), 1.0), Containment::Inside);
+    }
+
+    #[test]
+    pub fn test_infinite_far_plane(){
+        let f = Frustumf::from_matrix(&Matrix4f::perspective_infinite_rh(std::f32::consts::FRAC_PI_2, 1.0, 1.0));
+        let f0 = Frustumf::from_matrix_zero_to_one(&Matrix4f::perspective_infinite_rh(std::f32::consts::FRAC_PI_2, 1.0, 1.0));
+        assert!(f0.contains_point(Vector3f::new(0.0, 0.0, -1.0e6)));
+        assert!(!f0.contains_point(Vector3f::new(0.0, 0.0, -0.5)));
+        // the GL extraction still clips the sides
+        assert!(!f.contains_point(Vector3f::new(1.0e6, 0.0, -10.0)));
+    }
+
+    #[test]
+    pub fn test_reversed_z(){
+        let f = Frustumf::from_matrix_reversed_z(&Matrix4f::perspective_reversed_rh(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0));
+        // near at z = -1 and far at z = -10, as with the forward projections
+        assert_approx_eq!(f.planes[4].signed_distance(Vector3f::new(0.0, 0.0, -3.0)), 2.0, epsilon = 1e-4);
+        assert_approx_eq!(f.planes[5].signed_distance(Vector3f::new(0.0, 0.0, -3.0)), 7.0, epsilon = 1e-4);
+        assert!(f.contains_point(Vector3f::new(0.0, 0.0, -5.0)));
+        assert!(!f.contains_point(Vector3f::new(0.0, 0.0, -0.5)));
+        assert!(!f.contains_point(Vector3f::new(0.0, 0.0, -11.0)));
+
+        let inf = Frustumf::from_matrix_reversed_z(&Matrix4f::perspective_infinite_reversed_rh(std::f32::consts::FRAC_PI_2, 1.0, 1.0));
+        assert!(inf.contains_point(Vector3f::new(0.0, 0.0, -1.0e6)));
+        assert!(!inf.contains_point(Vector3f::new(0.0, 0.0, -0.5)));
+    }
+}
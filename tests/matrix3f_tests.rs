@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests {
     use ember_math::core::Matrix3f;
+    use ember_math::core::Matrix4f;
+    use ember_math::core::{Matrix3d, Vector3d};
     use ember_math::core::Vector3f;
+    use ember_math::core::Vector2f;
+    use ember_math::core::MathError;
+    use ember_math::core::Rad;
 
     static EPS:f32 = 1e-5;
 
@@ -9,6 +14,7 @@ mod tests {
         (a - b).abs() < eps
     }
 
+    #[allow(clippy::let_and_return)]
     pub fn matrix_seq() -> Matrix3f {
         let a = Matrix3f::new(
             1.0, 2.0, 3.0,
@@ -102,6 +108,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     pub fn test_transform(){
         let m1 = Matrix3f::identity();
         let v1 = Vector3f::new(1.0, 2.0, 3.0);
@@ -214,6 +221,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::assertions_on_constants)]
     pub fn test_sub(){
         assert!(true);
     }
@@ -231,7 +239,7 @@ mod tests {
 
     #[test]
     pub fn test_axis_angle() {
-        let m = Matrix3f::from_axis_angle(Vector3f::one().normalize(), 1.0);
+        let m = Matrix3f::from_axis_angle(Vector3f::one().normalize(), Rad(1.0));
 
         for i in 0..8 {
             println!("{}", m.data[i]);
@@ -249,4 +257,205 @@ mod tests {
         assert!(almost_eq(m.data[7], 0.639056, 1e-4));
         assert!(almost_eq(m.data[8], 0.693535, 1e-4));
     }
-}
\ No newline at end of file
+
+    #[test]
+    pub fn test_inline_storage(){
+        assert_eq!(std::mem::size_of::<Matrix3f>(), 9 * std::mem::size_of::<f32>());
+
+        let a = matrix_seq();
+        let b = a;
+        assert_eq!(a, b);
+        assert_eq!(a.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    }
+
+    #[test]
+    pub fn test_array_conversions(){
+        let arr = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        assert_eq!(Matrix3f::from_array(arr), matrix_seq());
+        assert_eq!(Matrix3f::from(arr), matrix_seq());
+        assert_eq!(matrix_seq().to_array(), arr);
+
+        let data: [f32; 9] = matrix_seq().into();
+        assert_eq!(data, arr);
+
+        let v = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        assert_eq!(Matrix3f::from_slice(&v), matrix_seq());
+
+        let mut m = Matrix3f::zero();
+        m.as_mut_slice()[4] = 2.0;
+        assert_eq!(m.data[4], 2.0);
+    }
+
+    #[test]
+    pub fn test_invertible(){
+        assert!(Matrix3f::identity().invertible());
+        assert!(!matrix_seq().invertible());
+
+        let mirror = Matrix3f::new(
+            -1.0, 0.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0
+        );
+        assert_eq!(mirror.determinant(), -1.0);
+        assert!(mirror.invertible());
+
+        let tiny = Matrix3f::identity().scale(0.02);
+        assert!(tiny.invertible());
+        assert!(!tiny.invertible_with_tolerance(1e-5));
+    }
+
+    #[test]
+    pub fn test_try_inverse(){
+        let m1 = Matrix3f::new(
+            0.0, -3.0, -2.0,
+            1.0, -4.0, -2.0,
+            -3.0, 4.0, 1.0
+        );
+        assert_eq!(m1.try_inverse(), Ok(m1.inverse()));
+        assert_eq!(matrix_seq().try_inverse(), Err(MathError::SingularMatrix));
+
+        let mirror = Matrix3f::new(
+            1.0, 0.0, 0.0,
+            0.0, -2.0, 0.0,
+            0.0, 0.0, 1.0
+        );
+        let inv = mirror.try_inverse().unwrap();
+        assert_eq!(inv.data[4], -0.5);
+
+        let tiny = Matrix3f::identity().scale(0.02);
+        assert!(tiny.try_inverse().is_ok());
+        assert_eq!(tiny.try_inverse_with_tolerance(1e-3), Err(MathError::SingularMatrix));
+
+        // the default test is relative to the size of the rows
+        let small = Matrix3f::identity().scale(0.001);
+        let inv = small.try_inverse().unwrap();
+        assert!(almost_eq(inv.data[0], 1000.0, 1e-3));
+        assert!(almost_eq(inv.data[8], 1000.0, 1e-3));
+        let flat = matrix_seq().scale(1000.0);
+        assert_eq!(flat.try_inverse(), Err(MathError::SingularMatrix));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_scalar_ops(){
+        let a = matrix_seq();
+        assert_eq!(a * 2.0, a.scale(2.0));
+        assert_eq!(2.0 * a, a.scale(2.0));
+        assert_eq!(-a, a.scale(-1.0));
+        assert_eq!((a * 4.0) / 4.0, a);
+        assert_eq!(&a * 2.0, a.scale(2.0));
+        assert_eq!(2.0 * &a, a.scale(2.0));
+        assert_eq!(-&a, -a);
+
+        let mut b = a;
+        b *= 2.0;
+        assert_eq!(b, a.scale(2.0));
+        b /= 2.0;
+        assert_eq!(b, a);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_mul_vector(){
+        let m = Matrix3f::new(
+            -1.0, 2.0, 4.5,
+            1.43, 1.0, 93.2,
+            0.0, 1.1, 0.5
+        );
+        let v = Vector3f::new(-0.2, 1.04, 12.2);
+        assert_eq!(m * v, m.transform(v));
+        assert_eq!(&m * &v, m.transform(v));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_ref_ops(){
+        let a = matrix_seq();
+        let b = Matrix3f::from_axis_angle(Vector3f::one(), Rad(1.0));
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - b, a - b);
+        assert_eq!(a * &b, a * b);
+        assert_eq!(&a * &b, a * b);
+
+        let mut c = a;
+        c += &b;
+        c -= &b;
+        c *= &Matrix3f::identity();
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    pub fn test_matrix3d(){
+        let a = Matrix3d::new(
+            2.0, 0.0, 1.0,
+            1.0, 3.0, 0.0,
+            0.0, 1.0, 4.0
+        );
+        assert_eq!(a.determinant(), 25.0);
+        assert_eq!(a * Vector3d::new(1.0, 1.0, 1.0), Vector3d::new(3.0, 4.0, 5.0));
+        assert_eq!(Matrix3d::from(Matrix3f::identity()), Matrix3d::identity());
+        assert_eq!(2.0 * Matrix3d::identity(), Matrix3d::identity().scale(2.0));
+    }
+
+    #[test]
+    pub fn test_cast(){
+        let m = matrix_seq() * 0.1;
+        assert_eq!(Matrix3d::from(m).cast::<f32>(), m);
+
+        let d = Matrix3d::from_array([0.1, 0.2, 0.3, 1.0 / 3.0, 1.0e10, -2.5, 0.0, 1.0, 7.0]);
+        let f = d.cast::<f32>();
+        for (a, b) in f.data.iter().zip(d.data.iter()) {
+            assert_eq!(*a, *b as f32);
+        }
+    }
+
+    #[test]
+    pub fn test_transform_points(){
+        // rotate by 90 degrees, then translate by (1, 2)
+        let m = Matrix3f::new(
+            0.0, -1.0, 1.0,
+            1.0, 0.0, 2.0,
+            0.0, 0.0, 1.0
+        );
+        let points = [Vector2f::new(1.0, 0.0), Vector2f::new(0.0, 3.0)];
+        let mut out = [Vector2f::zero(); 2];
+
+        m.transform_points(&points, &mut out);
+        assert_eq!(out, [Vector2f::new(1.0, 3.0), Vector2f::new(-2.0, 2.0)]);
+
+        m.transform_vectors(&points, &mut out);
+        assert_eq!(out, [Vector2f::new(0.0, 1.0), Vector2f::new(-3.0, 0.0)]);
+    }
+
+    #[test]
+    pub fn test_transform_homogeneous(){
+        let m = matrix_seq();
+        let points = [Vector3f::new(1.0, 2.0, 3.0), Vector3f::new(-1.0, 0.5, 2.0)];
+        let mut out = [Vector2f::zero(); 2];
+        m.transform_homogeneous(&points, &mut out);
+        for (p, o) in points.iter().zip(out.iter()) {
+            let h = m.transform(*p);
+            assert!(almost_eq(o.x, h.x / h.z, EPS));
+            assert!(almost_eq(o.y, h.y / h.z, EPS));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "input has 1 elements but output has 2")]
+    pub fn test_transform_vectors_length_mismatch(){
+        let mut out = [Vector2f::zero(); 2];
+        Matrix3f::identity().transform_vectors(&[Vector2f::zero(); 1], &mut out);
+    }
+
+    #[test]
+    pub fn test_to_matrix4(){
+        let m = matrix_seq().to_matrix4();
+        assert_eq!(m, Matrix4f::new(
+            1.0, 2.0, 3.0, 0.0,
+            4.0, 5.0, 6.0, 0.0,
+            7.0, 8.0, 9.0, 0.0,
+            0.0, 0.0, 0.0, 1.0
+        ));
+        assert_eq!(m.to_matrix3(), matrix_seq());
+    }
+}
@@ -1,10 +1,46 @@
+mod common;
+
 #[cfg(test)]
 mod tests {
     use ember_math::core::Matrix4f;
-    
+    use ember_math::core::{Matrix4d, Vector3d, Vector4d};
+    use ember_math::core::Vector3f;
+    use ember_math::core::Vector4f;
+    use ember_math::core::MathError;
+    use ember_math::core::{Matrix3f, Quaternion, Rad};
+    use ember_math::assert_approx_eq;
+    use crate::common::Lcg;
 
     static EPS:f32 = 1e-5;
 
+    pub fn almost_eq(a: f32, b: f32, eps: f32) -> bool {
+        (a - b).abs() < eps
+    }
+
+    pub fn matrix_general() -> Matrix4f {
+        Matrix4f::new(
+            2.0, -1.0, 0.0, 3.0,
+            1.0, 4.0, -2.0, 1.0,
+            0.0, 3.0, 5.0, -1.0,
+            2.0, 1.0, 1.0, 2.0
+        )
+    }
+
+    pub fn matrix_trs() -> Matrix4f {
+        let t = Matrix4f::from_translation(Vector3f::new(3.0, -2.0, 0.5));
+        let r = Matrix4f::from_angle_z(Rad(0.7));
+        let s = Matrix4f::from_scale_vec(Vector3f::new(2.0, 0.5, -1.5));
+        // composed by hand to avoid depending on Mul
+        let mut m = r;
+        for row in 0..3 {
+            for col in 0..3 {
+                m.data[row * 4 + col] = r.data[row * 4 + col] * s.data[col * 5];
+            }
+            m.data[row * 4 + 3] = t.data[row * 4 + 3];
+        }
+        m
+    }
+
     #[test]
     pub fn test_identity(){
         let m = Matrix4f::identity();
@@ -29,4 +65,640 @@ mod tests {
         assert_eq!(m.data[14], 0.0);
         assert_eq!(m.data[15], 1.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    pub fn test_inline_storage(){
+        assert_eq!(std::mem::size_of::<Matrix4f>(), 16 * std::mem::size_of::<f32>());
+
+        let a = Matrix4f::from_scale(2.0);
+        let b = a;
+        assert_eq!(a, b);
+        assert_eq!(a.as_slice()[0], 2.0);
+        assert_eq!(a.as_slice()[15], 1.0);
+    }
+
+    #[test]
+    pub fn test_array_conversions(){
+        let mut arr = [0.0; 16];
+        for (i, v) in arr.iter_mut().enumerate() {
+            *v = i as f32;
+        }
+        let m = Matrix4f::from_array(arr);
+        for i in 0..16 {
+            assert_eq!(m.data[i], i as f32);
+        }
+        assert_eq!(Matrix4f::from(arr), m);
+        assert_eq!(Matrix4f::from_slice(&arr), m);
+        assert_eq!(m.to_array(), arr);
+
+        let rows: [[f32; 4]; 4] = m.into();
+        assert_eq!(rows[1], [4.0, 5.0, 6.0, 7.0]);
+
+        assert_eq!(Matrix4f::zero().to_array(), [0.0; 16]);
+    }
+
+    #[test]
+    pub fn test_determinant(){
+        assert_eq!(Matrix4f::identity().determinant(), 1.0);
+        assert_eq!(Matrix4f::zero().determinant(), 0.0);
+        assert_eq!(matrix_general().determinant(), -33.0);
+
+        let seq = Matrix4f::new(
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0, 16.0
+        );
+        assert_eq!(seq.determinant(), 0.0);
+        assert_eq!(Matrix4f::from_scale_vec(Vector3f::new(2.0, 3.0, -4.0)).determinant(), -24.0);
+    }
+
+    #[test]
+    pub fn test_cofactor(){
+        let c = matrix_general().cofactor();
+        let r = Matrix4f::new(
+            56.0, -5.0, -7.0, -50.0,
+            17.0, -8.0, 2.0, -14.0,
+            23.0, -5.0, -7.0, -17.0,
+            -81.0, 9.0, 6.0, 57.0
+        );
+        assert_eq!(c, r);
+    }
+
+    #[test]
+    pub fn test_adjugate(){
+        let a = matrix_general().adjugate();
+        assert_eq!(a, matrix_general().cofactor().transpose());
+        assert_eq!(a.data[1], 17.0);
+        assert_eq!(a.data[4], -5.0);
+    }
+
+    #[test]
+    pub fn test_inverse(){
+        let inv = matrix_general().inverse();
+        let r = Matrix4f::new(
+            56.0, 17.0, 23.0, -81.0,
+            -5.0, -8.0, -5.0, 9.0,
+            -7.0, 2.0, -7.0, 6.0,
+            -50.0, -14.0, -17.0, 57.0
+        ).scale(-1.0 / 33.0);
+        assert_approx_eq!(inv, r, epsilon = EPS);
+
+        let signs = Matrix4f::new(
+            1.0, 1.0, 1.0, -1.0,
+            1.0, 1.0, -1.0, 1.0,
+            1.0, -1.0, 1.0, 1.0,
+            -1.0, 1.0, 1.0, 1.0
+        );
+        assert_eq!(signs.determinant(), -16.0);
+        assert_eq!(signs.inverse(), signs.scale(0.25));
+    }
+
+    #[test]
+    pub fn test_inverse_round_trip(){
+        let m = matrix_general();
+        let inv = m.inverse();
+        let v = Vector4f::new(0.3, -1.2, 4.0, 1.0);
+        let r = inv.transform(m.transform(v));
+        assert!(almost_eq(r.x, v.x, EPS));
+        assert!(almost_eq(r.y, v.y, EPS));
+        assert!(almost_eq(r.z, v.z, EPS));
+        assert!(almost_eq(r.w, v.w, EPS));
+    }
+
+    #[test]
+    pub fn test_invertible(){
+        assert!(Matrix4f::identity().invertible());
+        assert!(matrix_general().invertible());
+        // mirrored transforms have a negative determinant but are invertible
+        assert!(Matrix4f::from_scale_vec(Vector3f::new(-1.0, 1.0, 1.0)).invertible());
+        assert!(!Matrix4f::one().invertible());
+        assert!(!Matrix4f::from_scale(0.0).invertible());
+    }
+
+    #[test]
+    pub fn test_try_inverse(){
+        assert_eq!(Matrix4f::one().try_inverse(), Err(MathError::SingularMatrix));
+        let inv = matrix_general().try_inverse().unwrap();
+        assert_eq!(inv, matrix_general().inverse());
+
+        let tiny = Matrix4f::from_scale(0.02);
+        assert!(tiny.try_inverse().is_ok());
+        assert!(tiny.invertible());
+        assert_eq!(tiny.try_inverse_with_tolerance(1e-5), Err(MathError::SingularMatrix));
+        assert!(!tiny.invertible_with_tolerance(1e-5));
+    }
+
+    #[test]
+    pub fn test_try_inverse_small_scale(){
+        // well conditioned, but the determinant is only 1e-8
+        let small = Matrix4f::identity().scale(0.01);
+        assert!(small.invertible());
+        assert_approx_eq!(small.try_inverse().unwrap(), Matrix4f::identity().scale(100.0), epsilon = 1e-3);
+        assert!(!small.invertible_with_tolerance(1e-6));
+
+        let small_affine = Matrix4f::from_scale(0.001);
+        assert!(small_affine.try_inverse_affine().is_ok());
+
+        // nearly parallel rows are singular however large the determinant
+        let flat = Matrix4f::new(
+            1000.0, 0.0, 0.0, 0.0,
+            1000.0, 0.0001, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0
+        );
+        assert!(flat.determinant() > 1e-2);
+        assert!(!flat.invertible());
+        assert_eq!(flat.try_inverse(), Err(MathError::SingularMatrix));
+    }
+
+    #[test]
+    pub fn test_try_perspective_gl(){
+        let p = Matrix4f::try_perspective_gl(1.0, 1.5, 0.1, 100.0).unwrap();
+        assert_eq!(p, Matrix4f::perspective_gl(1.0, 1.5, 0.1, 100.0));
+
+        assert_eq!(Matrix4f::try_perspective_gl(0.0, 1.5, 0.1, 100.0), Err(MathError::DegenerateProjection));
+        assert_eq!(Matrix4f::try_perspective_gl(std::f32::consts::PI, 1.5, 0.1, 100.0), Err(MathError::DegenerateProjection));
+        assert_eq!(Matrix4f::try_perspective_gl(1.0, 0.0, 0.1, 100.0), Err(MathError::DegenerateProjection));
+        assert_eq!(Matrix4f::try_perspective_gl(1.0, 1.5, 1.0, 1.0), Err(MathError::DegenerateProjection));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    pub fn test_deprecated_gl_names(){
+        assert_eq!(Matrix4f::try_perspective(1.0, 1.5, 0.1, 100.0), Matrix4f::try_perspective_gl(1.0, 1.5, 0.1, 100.0));
+        assert_eq!(Matrix4f::perspective(1.0, 1.5, 0.1, 100.0), Matrix4f::perspective_gl(1.0, 1.5, 0.1, 100.0));
+        assert_eq!(Matrix4f::frustum(-1.0, 2.0, -1.0, 1.0, 0.5, 9.0), Matrix4f::frustum_gl(-1.0, 2.0, -1.0, 1.0, 0.5, 9.0));
+        assert_eq!(Matrix4f::orthographic(-1.0, 2.0, -1.0, 1.0, 0.5, 9.0), Matrix4f::orthographic_gl(-1.0, 2.0, -1.0, 1.0, 0.5, 9.0));
+    }
+
+    #[test]
+    pub fn test_try_look_at_rh(){
+        let eye = Vector3f::new(0.0, 0.0, 5.0);
+        let up = Vector3f::new(0.0, 1.0, 0.0);
+        let v = Matrix4f::try_look_at_rh(eye, Vector3f::zero(), up).unwrap();
+        assert_eq!(v, Matrix4f::look_at_rh(eye, Vector3f::zero(), up));
+
+        assert_eq!(Matrix4f::try_look_at_rh(eye, eye, up), Err(MathError::DegenerateProjection));
+        assert_eq!(Matrix4f::try_look_at_rh(Vector3f::zero(), up, up), Err(MathError::DegenerateProjection));
+    }
+
+    #[test]
+    pub fn test_look_at_rh_perspective_map_points(){
+        // the camera at +z looks at the origin, which ends up 5 units down -z
+        let view = Matrix4f::look_at_rh(Vector3f::new(0.0, 0.0, 5.0), Vector3f::zero(), Vector3f::new(0.0, 1.0, 0.0));
+        assert_approx_eq!(view * Vector4f::new(0.0, 0.0, 0.0, 1.0), Vector4f::new(0.0, 0.0, -5.0, 1.0), epsilon = EPS);
+        assert_approx_eq!(view * Vector4f::new(1.0, 2.0, 0.0, 1.0), Vector4f::new(1.0, 2.0, -5.0, 1.0), epsilon = EPS);
+
+        // near and far map to -1 and 1 after the divide by w
+        let proj = Matrix4f::perspective_gl(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+        let near = proj * Vector4f::new(1.0, 0.0, -1.0, 1.0);
+        let far = proj * Vector4f::new(0.0, -10.0, -10.0, 1.0);
+        assert_approx_eq!(near / near.w, Vector4f::new(1.0, 0.0, -1.0, 1.0), epsilon = EPS);
+        assert_approx_eq!(far / far.w, Vector4f::new(0.0, -1.0, 1.0, 1.0), epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_inverse_affine(){
+        let m = matrix_trs();
+        let fast = m.inverse_affine();
+        assert_approx_eq!(fast, m.inverse(), epsilon = EPS);
+        assert_eq!(fast.data[12], 0.0);
+        assert_eq!(fast.data[13], 0.0);
+        assert_eq!(fast.data[14], 0.0);
+        assert_eq!(fast.data[15], 1.0);
+
+        let p = Vector4f::new(1.0, 2.0, 3.0, 1.0);
+        let r = fast.transform(m.transform(p));
+        assert!(almost_eq(r.x, p.x, EPS));
+        assert!(almost_eq(r.y, p.y, EPS));
+        assert!(almost_eq(r.z, p.z, EPS));
+
+        let t = Matrix4f::from_translation(Vector3f::new(1.0, 2.0, 3.0));
+        assert_eq!(t.inverse_affine(), Matrix4f::from_translation(Vector3f::new(-1.0, -2.0, -3.0)));
+    }
+
+    #[test]
+    pub fn test_try_inverse_affine(){
+        let m = matrix_trs();
+        assert_eq!(m.try_inverse_affine().unwrap(), m.inverse_affine());
+
+        let flat = Matrix4f::from_scale_vec(Vector3f::new(1.0, 0.0, 1.0));
+        assert_eq!(flat.try_inverse_affine(), Err(MathError::SingularMatrix));
+
+        let tiny = Matrix4f::from_scale(0.02);
+        assert!(tiny.try_inverse_affine().is_ok());
+        assert_eq!(tiny.try_inverse_affine_with_tolerance(1e-3), Err(MathError::SingularMatrix));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_scalar_ops(){
+        let a = matrix_general();
+        assert_eq!(a * 2.0, a.scale(2.0));
+        assert_eq!(2.0 * a, a.scale(2.0));
+        assert_eq!(-a, a.scale(-1.0));
+        assert_eq!((a * 4.0) / 4.0, a);
+        assert_eq!(&a * 2.0, a.scale(2.0));
+        assert_eq!(2.0 * &a, a.scale(2.0));
+        assert_eq!(-&a, -a);
+
+        let mut b = a;
+        b *= 2.0;
+        assert_eq!(b, a.scale(2.0));
+        b /= 2.0;
+        assert_eq!(b, a);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_mul_vector(){
+        let m = matrix_general();
+        let v = Vector4f::new(0.3, -1.2, 4.0, 1.0);
+        assert_eq!(m * v, m.transform(v));
+        assert_eq!(&m * &v, m.transform(v));
+    }
+
+    #[test]
+    pub fn test_mul(){
+        // neither operand is affine, so the bottom rows feed every element
+        let a = Matrix4f::new(
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0, 16.0
+        );
+        let b = Matrix4f::new(
+            2.0, 0.0, 1.0, -1.0,
+            0.0, 3.0, 0.0, 2.0,
+            1.0, -2.0, 1.0, 0.0,
+            4.0, 1.0, -3.0, 2.0
+        );
+        let r = Matrix4f::new(
+            21.0, 4.0, -8.0, 11.0,
+            49.0, 12.0, -12.0, 23.0,
+            77.0, 20.0, -16.0, 35.0,
+            105.0, 28.0, -20.0, 47.0
+        );
+        assert_eq!(a * b, r);
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, r);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_ref_ops(){
+        let a = matrix_general();
+        let b = matrix_general().transpose();
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - b, a - b);
+        assert_eq!(a * &b, a * b);
+        assert_eq!(&a * &b, a * b);
+
+        let mut c = a;
+        c += &b;
+        c -= &b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    pub fn test_matrix4d(){
+        let a: Matrix4d = matrix_general().into();
+        let inv = a.inverse();
+        assert!((a.determinant() + 33.0).abs() < 1e-12);
+        let mut product = Matrix4d::zero();
+        for r in 0..4 {
+            for c in 0..4 {
+                for k in 0..4 {
+                    product.data[r * 4 + c] += a.data[r * 4 + k] * inv.data[k * 4 + c];
+                }
+            }
+        }
+        for (i, v) in product.data.iter().enumerate() {
+            let expected = if i % 5 == 0 { 1.0 } else { 0.0 };
+            assert!((v - expected).abs() < 1e-12);
+        }
+
+        let t = Matrix4d::from_translation(Vector3d::new(1.0e9, 0.0, -2.0));
+        assert_eq!(t * Vector4d::new(0.25, 1.0, 0.0, 1.0), Vector4d::new(1_000_000_000.25, 1.0, -2.0, 1.0));
+    }
+
+    #[test]
+    pub fn test_cast(){
+        let m = matrix_trs();
+        assert_eq!(Matrix4d::from(m).cast::<f32>(), m);
+
+        // the usual path for uploading a double-precision transform
+        let t = Matrix4d::from_translation(Vector3d::new(1.0e9 + 0.25, 0.1, -2.0));
+        let f = t.cast::<f32>();
+        for (a, b) in f.data.iter().zip(t.data.iter()) {
+            assert_eq!(*a, *b as f32);
+        }
+    }
+
+    pub fn project(m: Matrix4f, p: Vector3f) -> Vector3f {
+        let c = m * Vector4f::new(p.x, p.y, p.z, 1.0);
+        Vector3f::new(c.x / c.w, c.y / c.w, c.z / c.w)
+    }
+
+    pub fn ndc_eq(a: Vector3f, b: Vector3f) {
+        assert_approx_eq!(a, b, epsilon = 1e-4);
+    }
+
+    #[test]
+    pub fn test_look_at(){
+        let eye = Vector3f::new(1.0, 2.0, 5.0);
+        let up = Vector3f::new(0.0, 1.0, 0.0);
+        let target = Vector3f::new(1.0, 2.0, 0.0);
+
+        let rh = Matrix4f::look_at_rh(eye, target, up);
+        ndc_eq(rh.transform(Vector4f::new(1.0, 2.0, 0.0, 1.0)).truncate(), Vector3f::new(0.0, 0.0, -5.0));
+        ndc_eq(rh.transform(Vector4f::new(2.0, 3.0, 5.0, 1.0)).truncate(), Vector3f::new(1.0, 1.0, 0.0));
+
+        let lh = Matrix4f::look_at_lh(eye, target, up);
+        ndc_eq(lh.transform(Vector4f::new(1.0, 2.0, 0.0, 1.0)).truncate(), Vector3f::new(0.0, 0.0, 5.0));
+        // looking down -z in world space, world +x is to the left
+        ndc_eq(lh.transform(Vector4f::new(2.0, 3.0, 5.0, 1.0)).truncate(), Vector3f::new(-1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    pub fn test_perspective_ndc(){
+        let fovy = std::f32::consts::FRAC_PI_2;
+        let (n, f) = (1.0, 10.0);
+
+        let gl = Matrix4f::perspective_gl(fovy, 2.0, n, f);
+        ndc_eq(project(gl, Vector3f::new(0.0, 0.0, -n)), Vector3f::new(0.0, 0.0, -1.0));
+        ndc_eq(project(gl, Vector3f::new(0.0, 0.0, -f)), Vector3f::new(0.0, 0.0, 1.0));
+        ndc_eq(project(gl, Vector3f::new(2.0, 1.0, -n)), Vector3f::new(1.0, 1.0, -1.0));
+        ndc_eq(project(gl, Vector3f::new(-10.0, 5.0, -5.0)), Vector3f::new(-1.0, 1.0, 7.0 / 9.0));
+
+        let rh = Matrix4f::perspective_rh(fovy, 2.0, n, f);
+        ndc_eq(project(rh, Vector3f::new(0.0, 0.0, -n)), Vector3f::new(0.0, 0.0, 0.0));
+        ndc_eq(project(rh, Vector3f::new(0.0, 0.0, -f)), Vector3f::new(0.0, 0.0, 1.0));
+        ndc_eq(project(rh, Vector3f::new(-20.0, -10.0, -f)), Vector3f::new(-1.0, -1.0, 1.0));
+
+        let lh = Matrix4f::perspective_lh(fovy, 2.0, n, f);
+        ndc_eq(project(lh, Vector3f::new(0.0, 0.0, n)), Vector3f::new(0.0, 0.0, 0.0));
+        ndc_eq(project(lh, Vector3f::new(0.0, 0.0, f)), Vector3f::new(0.0, 0.0, 1.0));
+        ndc_eq(project(lh, Vector3f::new(20.0, 10.0, f)), Vector3f::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    pub fn test_perspective_reversed_and_infinite(){
+        let fovy = std::f32::consts::FRAC_PI_2;
+        let (n, f) = (0.5, 50.0);
+
+        let r = Matrix4f::perspective_reversed_rh(fovy, 1.0, n, f);
+        ndc_eq(project(r, Vector3f::new(0.0, 0.0, -n)), Vector3f::new(0.0, 0.0, 1.0));
+        ndc_eq(project(r, Vector3f::new(0.0, 0.0, -f)), Vector3f::new(0.0, 0.0, 0.0));
+        let r = Matrix4f::perspective_reversed_lh(fovy, 1.0, n, f);
+        ndc_eq(project(r, Vector3f::new(0.0, 0.0, n)), Vector3f::new(0.0, 0.0, 1.0));
+        ndc_eq(project(r, Vector3f::new(f, 0.0, f)), Vector3f::new(1.0, 0.0, 0.0));
+
+        let i = Matrix4f::perspective_infinite_rh(fovy, 1.0, n);
+        ndc_eq(project(i, Vector3f::new(0.0, 0.0, -n)), Vector3f::new(0.0, 0.0, 0.0));
+        ndc_eq(project(i, Vector3f::new(0.0, 0.0, -2.0 * n)), Vector3f::new(0.0, 0.0, 0.5));
+        assert!(project(i, Vector3f::new(0.0, 0.0, -1.0e6)).z < 1.0);
+        let i = Matrix4f::perspective_infinite_lh(fovy, 1.0, n);
+        ndc_eq(project(i, Vector3f::new(1.0, -1.0, 1.0)), Vector3f::new(1.0, -1.0, 0.5));
+
+        let ir = Matrix4f::perspective_infinite_reversed_rh(fovy, 1.0, n);
+        ndc_eq(project(ir, Vector3f::new(0.0, 0.0, -n)), Vector3f::new(0.0, 0.0, 1.0));
+        ndc_eq(project(ir, Vector3f::new(0.0, 0.0, -4.0 * n)), Vector3f::new(0.0, 0.0, 0.25));
+        assert!(project(ir, Vector3f::new(0.0, 0.0, -1.0e6)).z > 0.0);
+        let ir = Matrix4f::perspective_infinite_reversed_lh(fovy, 1.0, n);
+        ndc_eq(project(ir, Vector3f::new(-2.0, 0.0, 2.0)), Vector3f::new(-1.0, 0.0, 0.25));
+    }
+
+    #[test]
+    pub fn test_frustum_ndc(){
+        // off-center: the near rectangle spans x in 0..2 and y in -1..3
+        let (l, r, b, t, n, f) = (0.0, 2.0, -1.0, 3.0, 1.0, 3.0);
+
+        let gl = Matrix4f::frustum_gl(l, r, b, t, n, f);
+        ndc_eq(project(gl, Vector3f::new(0.0, -1.0, -n)), Vector3f::new(-1.0, -1.0, -1.0));
+        ndc_eq(project(gl, Vector3f::new(6.0, 9.0, -f)), Vector3f::new(1.0, 1.0, 1.0));
+        ndc_eq(project(gl, Vector3f::new(1.0, 1.0, -n)), Vector3f::new(0.0, 0.0, -1.0));
+        assert_approx_eq!(Matrix4f::frustum_gl(-1.0, 1.0, -1.0, 1.0, n, f),
+            Matrix4f::perspective_gl(std::f32::consts::FRAC_PI_2, 1.0, n, f), epsilon = 1e-6);
+
+        let rh = Matrix4f::frustum_rh(l, r, b, t, n, f);
+        ndc_eq(project(rh, Vector3f::new(0.0, -1.0, -n)), Vector3f::new(-1.0, -1.0, 0.0));
+        ndc_eq(project(rh, Vector3f::new(6.0, 9.0, -f)), Vector3f::new(1.0, 1.0, 1.0));
+
+        let lh = Matrix4f::frustum_lh(l, r, b, t, n, f);
+        ndc_eq(project(lh, Vector3f::new(0.0, -1.0, n)), Vector3f::new(-1.0, -1.0, 0.0));
+        ndc_eq(project(lh, Vector3f::new(3.0, 3.0, f)), Vector3f::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    pub fn test_orthographic_ndc(){
+        let (l, r, b, t, n, f) = (-4.0, 2.0, 0.0, 3.0, 1.0, 5.0);
+
+        let gl = Matrix4f::orthographic_gl(l, r, b, t, n, f);
+        ndc_eq(project(gl, Vector3f::new(-4.0, 0.0, -n)), Vector3f::new(-1.0, -1.0, -1.0));
+        ndc_eq(project(gl, Vector3f::new(2.0, 3.0, -f)), Vector3f::new(1.0, 1.0, 1.0));
+        ndc_eq(project(gl, Vector3f::new(-1.0, 1.5, -3.0)), Vector3f::new(0.0, 0.0, 0.0));
+
+        let rh = Matrix4f::orthographic_rh(l, r, b, t, n, f);
+        ndc_eq(project(rh, Vector3f::new(-4.0, 0.0, -n)), Vector3f::new(-1.0, -1.0, 0.0));
+        ndc_eq(project(rh, Vector3f::new(-1.0, 1.5, -3.0)), Vector3f::new(0.0, 0.0, 0.5));
+
+        let lh = Matrix4f::orthographic_lh(l, r, b, t, n, f);
+        ndc_eq(project(lh, Vector3f::new(2.0, 3.0, f)), Vector3f::new(1.0, 1.0, 1.0));
+        ndc_eq(project(lh, Vector3f::new(-1.0, 1.5, 3.0)), Vector3f::new(0.0, 0.0, 0.5));
+    }
+
+    #[test]
+    pub fn test_view_projection_ndc(){
+        let eye = Vector3f::new(0.0, 0.0, 5.0);
+        let up = Vector3f::new(0.0, 1.0, 0.0);
+        let fovy = std::f32::consts::FRAC_PI_2;
+
+        let view = Matrix4f::look_at_rh(eye, Vector3f::zero(), up);
+        let proj = Matrix4f::perspective_rh(fovy, 1.0, 1.0, 9.0);
+        let to_ndc = |p: Vector3f| {
+            let c = proj * (view * Vector4f::new(p.x, p.y, p.z, 1.0));
+            Vector3f::new(c.x / c.w, c.y / c.w, c.z / c.w)
+        };
+        // the origin is 5 units in front of the camera
+        ndc_eq(to_ndc(Vector3f::zero()), Vector3f::new(0.0, 0.0, 0.9));
+        ndc_eq(to_ndc(Vector3f::new(5.0, -5.0, 0.0)), Vector3f::new(1.0, -1.0, 0.9));
+        ndc_eq(to_ndc(Vector3f::new(0.0, 0.0, 4.0)), Vector3f::new(0.0, 0.0, 0.0));
+
+        let view = Matrix4f::look_at_lh(Vector3f::new(0.0, 0.0, -5.0), Vector3f::zero(), up);
+        let proj = Matrix4f::perspective_lh(fovy, 1.0, 1.0, 9.0);
+        let c = proj * (view * Vector4f::new(5.0, 5.0, 0.0, 1.0));
+        ndc_eq(Vector3f::new(c.x / c.w, c.y / c.w, c.z / c.w), Vector3f::new(1.0, 1.0, 0.9));
+    }
+
+    #[test]
+    pub fn test_decompose(){
+        let q = Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(std::f32::consts::FRAC_PI_2));
+        let m = Matrix4f::from_scale_rotation_translation(Vector3f::new(2.0, 3.0, 4.0), q, Vector3f::new(1.0, -2.0, 5.0));
+        // x scaled by 2 then rotated onto y
+        ndc_eq(project(m, Vector3f::new(1.0, 0.0, 0.0)), Vector3f::new(1.0, 0.0, 5.0));
+
+        let (t, s, r) = m.decompose();
+        assert_approx_eq!(t, Vector3f::new(1.0, -2.0, 5.0));
+        assert_approx_eq!(s, Vector3f::new(2.0, 3.0, 4.0), epsilon = 1e-6);
+        assert_approx_eq!(r, q.to_matrix3(), epsilon = 1e-6);
+
+        let (_, _, rq) = m.decompose_quat();
+        assert_approx_eq!(rq, q, epsilon = 1e-6);
+
+        let (t, s, r) = Matrix4f::identity().decompose();
+        assert_eq!(t, Vector3f::zero());
+        assert_eq!(s, Vector3f::one());
+        assert_eq!(r, Matrix3f::identity());
+    }
+
+    #[test]
+    pub fn test_decompose_negative_scale(){
+        let m = Matrix4f::from_scale_vec(Vector3f::new(1.0, -2.0, 3.0));
+        let (_, s, r) = m.decompose();
+        // a single reflection always lands on x
+        assert_approx_eq!(s, Vector3f::new(-1.0, 2.0, 3.0));
+        assert_approx_eq!(r.determinant(), 1.0, epsilon = 1e-6);
+        assert_approx_eq!(Matrix4f::from_scale_rotation_translation(s, Quaternion::from_rotation_matrix(&r), Vector3f::zero()), m, epsilon = 1e-6);
+
+        // two reflections are a rotation by pi
+        let (_, s, r) = Matrix4f::from_scale_vec(Vector3f::new(-1.0, -1.0, 1.0)).decompose();
+        assert_eq!(s, Vector3f::one());
+        assert_approx_eq!(r, Matrix3f::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(std::f32::consts::PI)), epsilon = 1e-6);
+    }
+
+    #[test]
+    pub fn test_try_decompose(){
+        let m = Matrix4f::from_scale_vec(Vector3f::new(1.0, 0.0, 1.0));
+        assert_eq!(m.try_decompose(), Err(MathError::SingularMatrix));
+        assert!(Matrix4f::from_scale(0.5).try_decompose().is_ok());
+    }
+
+    #[test]
+    pub fn test_decompose_round_trip(){
+        let mut rng = Lcg(7);
+        for _ in 0..500 {
+            let scale = rng.vector(0.1, 10.0);
+            let rotation = rng.rotation();
+            let translation = rng.vector(-100.0, 100.0);
+            let m = Matrix4f::from_scale_rotation_translation(scale, rotation, translation);
+
+            let (t, s, r) = m.decompose();
+            assert_approx_eq!(t, translation);
+            assert_approx_eq!(s, scale, epsilon = 1e-5, max_relative = 1e-5);
+            assert_approx_eq!(r, rotation.to_matrix3(), epsilon = 1e-5);
+
+            // q and -q are the same rotation
+            let (_, _, q) = m.decompose_quat();
+            let q = if q.dot(rotation) < 0.0 { q.scale(-1.0) } else { q };
+            assert_approx_eq!(q, rotation, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    pub fn test_decompose_round_trip_negative_scale(){
+        let mut rng = Lcg(11);
+        for _ in 0..500 {
+            let mut scale = rng.vector(0.1, 10.0);
+            if rng.next() < 0.5 { scale.x = -scale.x; }
+            if rng.next() < 0.5 { scale.y = -scale.y; }
+            if rng.next() < 0.5 { scale.z = -scale.z; }
+            let m = Matrix4f::from_scale_rotation_translation(scale, rng.rotation(), rng.vector(-100.0, 100.0));
+
+            // the split is not unique with reflections, so check recomposition
+            let (t, s, q) = m.decompose_quat();
+            let back = Matrix4f::from_scale_rotation_translation(s, q, t);
+            assert_approx_eq!(back, m, epsilon = 1e-4, max_relative = 1e-4);
+            assert_eq!(s.x * s.y * s.z < 0.0, scale.x * scale.y * scale.z < 0.0);
+        }
+    }
+
+    #[test]
+    pub fn test_transform_points(){
+        let m = Matrix4f::from_scale_rotation_translation(
+            Vector3f::new(2.0, 1.0, 0.5),
+            Quaternion::from_axis_angle(Vector3f::new(0.0, 1.0, 0.0), Rad(0.3)),
+            Vector3f::new(1.0, -2.0, 3.0)
+        );
+        let points: Vec<Vector3f> = (0..37).map(|i| Vector3f::new(i as f32, 1.0 - i as f32, 0.5 * i as f32)).collect();
+
+        let mut out = vec![Vector3f::zero(); points.len()];
+        m.transform_points(&points, &mut out);
+        for (p, o) in points.iter().zip(out.iter()) {
+            assert_approx_eq!(*o, m.transform(p.extend(1.0)).truncate());
+        }
+
+        m.transform_vectors(&points, &mut out);
+        for (p, o) in points.iter().zip(out.iter()) {
+            assert_approx_eq!(*o, m.transform(p.extend(0.0)).truncate());
+        }
+    }
+
+    #[test]
+    pub fn test_transform_homogeneous(){
+        let m = Matrix4f::perspective_gl(1.0, 1.5, 0.1, 100.0);
+        let points = [Vector4f::new(1.0, 2.0, -5.0, 1.0), Vector4f::new(-3.0, 0.5, -50.0, 1.0)];
+        let mut out = [Vector3f::zero(); 2];
+        m.transform_homogeneous(&points, &mut out);
+        for (p, o) in points.iter().zip(out.iter()) {
+            let clip = m.transform(*p);
+            assert_approx_eq!(*o, clip.truncate() / clip.w);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "input has 2 elements but output has 1")]
+    pub fn test_transform_points_length_mismatch(){
+        let mut out = [Vector3f::zero(); 1];
+        Matrix4f::identity().transform_points(&[Vector3f::zero(); 2], &mut out);
+    }
+
+    #[test]
+    pub fn test_transform_point3_and_vector3(){
+        let m = Matrix4f::from_translation(Vector3f::new(1.0, 2.0, 3.0)) * Matrix4f::from_scale(2.0);
+        let p = Vector3f::new(1.0, -1.0, 0.5);
+        assert_eq!(m.transform_point3(p), Vector3f::new(3.0, 0.0, 4.0));
+        assert_eq!(m.transform_vector3(p), Vector3f::new(2.0, -2.0, 1.0));
+        assert_eq!(m.transform_point3(p), m.transform(p.extend(1.0)).truncate());
+    }
+
+    #[test]
+    pub fn test_project_point3(){
+        let m = Matrix4f::perspective_gl(1.0, 1.5, 0.1, 100.0);
+        let p = Vector3f::new(1.0, 2.0, -5.0);
+        let clip = m.transform(p.extend(1.0));
+        assert_approx_eq!(m.project_point3(p), clip.truncate() / clip.w);
+
+        // affine matrices have w = 1
+        let a = Matrix4f::from_translation(Vector3f::new(1.0, 2.0, 3.0));
+        assert_eq!(a.project_point3(p), a.transform_point3(p));
+    }
+
+    #[test]
+    pub fn test_transform_normal(){
+        let m = Matrix4f::from_scale_vec(Vector3f::new(4.0, 1.0, 1.0)) * Matrix4f::from_angle_z(Rad(0.4));
+        // the plane x + y = 0 contains these two directions
+        let n = Vector3f::new(1.0, 1.0, 0.0);
+        let t1 = Vector3f::new(1.0, -1.0, 0.0);
+        let t2 = Vector3f::new(0.0, 0.0, 1.0);
+
+        let n2 = m.transform_normal(n);
+        assert!(n2.dot(m.transform_vector3(t1)).abs() < EPS);
+        assert!(n2.dot(m.transform_vector3(t2)).abs() < EPS);
+        // a plain transform does not stay perpendicular under non-uniform scale
+        assert!(m.transform_vector3(n).dot(m.transform_vector3(t1)).abs() > 1.0);
+
+        assert_eq!(m.try_transform_normal(n).unwrap(), n2);
+        assert_eq!(Matrix4f::from_scale(0.0).try_transform_normal(n), Err(MathError::SingularMatrix));
+    }
+
+    #[test]
+    pub fn test_to_matrix3(){
+        let m = Matrix4f::from_translation(Vector3f::new(1.0, 2.0, 3.0)) * Matrix4f::from_angle_y(Rad(0.7));
+        let r = m.to_matrix3();
+        assert_eq!(r, Matrix3f::from_angle_y(Rad(0.7)));
+        assert_eq!(r.to_matrix4(), Matrix4f::from_angle_y(Rad(0.7)));
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Obbf, Aabbf, Spheref};
+    use ember_math::core::{Vector3f, Matrix3f, Matrix4f, Quaternion, Rad};
+    use ember_math::assert_approx_eq;
+
+    static EPS:f32 = 1e-5;
+
+    pub fn rotated() -> Obbf {
+        let q = Quaternion::from_axis_angle(Vector3f::new(1.0, 2.0, -1.0).normalize(), Rad(0.8f32));
+        Obbf::new(Vector3f::new(2.0, -1.0, 0.5), Vector3f::new(3.0, 2.0, 1.0), q.to_matrix3())
+    }
+
+    #[test]
+    pub fn test_from_aabb(){
+        let b = Aabbf::new(Vector3f::new(-1.0, 0.0, 2.0), Vector3f::new(3.0, 1.0, 4.0));
+        let o = Obbf::from_aabb(&b);
+        assert_eq!(o.center, Vector3f::new(1.0, 0.5, 3.0));
+        assert_eq!(o.half_extents, Vector3f::new(2.0, 0.5, 1.0));
+        assert_eq!(o.to_aabb(), b);
+        assert_eq!(Aabbf::from_points(&o.corners()), b);
+    }
+
+    #[test]
+    pub fn test_points(){
+        let o = rotated();
+        let [x, y, z] = o.axes();
+        assert_approx_eq!(x.cross(y), z, epsilon = EPS);
+
+        let inside = o.center + x * 2.9 - y * 1.9 + z * 0.9;
+        let outside = o.center + x * 2.9 - y * 2.1;
+        assert!(o.contains_point(inside));
+        assert!(!o.contains_point(outside));
+        assert_approx_eq!(o.closest_point(inside), inside, epsilon = EPS);
+        assert_approx_eq!(o.closest_point(outside), o.center + x * 2.9 - y * 2.0, epsilon = EPS);
+        assert_approx_eq!(o.closest_point(o.center + x * 10.0 + y * 10.0 - z * 10.0), o.center + x * 3.0 + y * 2.0 - z, epsilon = EPS);
+
+        let bounds = o.to_aabb();
+        for c in o.corners() {
+            assert!(o.contains_point(o.center + (c - o.center) * 0.999));
+            assert!(bounds.expand(EPS).contains_point(c));
+        }
+
+        assert!(o.intersects_sphere(&Spheref::new(o.center + y * 2.5, 0.6)));
+        assert!(!o.intersects_sphere(&Spheref::new(o.center + y * 2.5, 0.4)));
+    }
+
+    #[test]
+    pub fn test_from_points(){
+        assert!(Obbf::from_points(&[]).is_none());
+
+        let o = rotated();
+        let fitted = Obbf::from_points(&o.corners()).unwrap();
+        assert_approx_eq!(fitted.center, o.center, epsilon = 1e-4);
+        assert_approx_eq!(fitted.half_extents, o.half_extents, epsilon = 1e-4);
+        // the axes may point either way
+        for (a, b) in fitted.axes().iter().zip(o.axes()) {
+            assert_approx_eq!(a.dot(b).abs(), 1.0, epsilon = 1e-4);
+        }
+        assert_approx_eq!(fitted.orientation.determinant(), 1.0, epsilon = 1e-4);
+
+        // a flat, square-ish cloud picks any axes in its plane
+        let flat = [(0.0, 0.0), (2.0, 0.0), (0.0, 2.0), (2.0, 2.0), (1.0, 1.0)].map(|(x, y)| Vector3f::new(x, y, 5.0));
+        let fitted = Obbf::from_points(&flat).unwrap();
+        assert_approx_eq!(fitted.half_extents.z, 0.0, epsilon = 1e-4);
+        for p in flat {
+            assert!(fitted.contains_point(p + (Vector3f::new(1.0, 1.0, 5.0) - p) * 1e-3));
+        }
+
+        let single = Obbf::from_points(&[Vector3f::new(1.0, 2.0, 3.0)]).unwrap();
+        assert_eq!(single.center, Vector3f::new(1.0, 2.0, 3.0));
+        assert_eq!(single.half_extents, Vector3f::zero());
+    }
+
+    #[test]
+    pub fn test_intersects(){
+        let unit = Obbf::new(Vector3f::zero(), Vector3f::one(), Matrix3f::identity());
+        let o = rotated();
+        assert!(o.intersects(&o));
+        assert!(unit.intersects(&Obbf::new(Vector3f::new(2.0, 0.0, 0.0), Vector3f::one(), Matrix3f::identity())));
+        assert!(!unit.intersects(&Obbf::new(Vector3f::new(2.1, 0.0, 0.0), Vector3f::one(), Matrix3f::identity())));
+
+        // separated along a face normal of the rotated box only
+        let turned = Obbf::new(Vector3f::new(2.2, 2.2, 0.0), Vector3f::one(), Matrix3f::from_angle_z(Rad(std::f32::consts::FRAC_PI_4)));
+        assert!(!unit.intersects(&turned));
+        assert!(!turned.intersects(&unit));
+        assert!(unit.intersects(&Obbf::new(Vector3f::new(1.6, 1.6, 0.0), Vector3f::one(), turned.orientation)));
+
+        // ridges crossing at right angles, separated only along the cross
+        // product of their edges
+        let a = Obbf::new(Vector3f::zero(), Vector3f::one(), Matrix3f::from_angle_z(Rad(std::f32::consts::FRAC_PI_4)));
+        let gap = 2.0 * 2.0f32.sqrt();
+        let ridge = |x: f32| Obbf::new(Vector3f::new(x, 0.0, 0.0), Vector3f::one(), Matrix3f::from_angle_y(Rad(std::f32::consts::FRAC_PI_4)));
+        assert!(!a.intersects(&ridge(gap + 0.05)));
+        assert!(!ridge(gap + 0.05).intersects(&a));
+        assert!(a.intersects(&ridge(gap - 0.05)));
+    }
+
+    #[test]
+    pub fn test_transform(){
+        let o = rotated();
+        let q = Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(1.1f32));
+        let m = Matrix4f::from_translation(Vector3f::new(-3.0, 4.0, 1.0)) * q.to_matrix4() * Matrix4f::from_scale(2.0);
+        let moved = o.transform(&m);
+        assert_approx_eq!(moved.center, m.transform_point3(o.center), epsilon = 1e-4);
+        assert_approx_eq!(moved.half_extents, o.half_extents * 2.0, epsilon = 1e-4);
+        for (a, b) in o.corners().iter().zip(moved.corners()) {
+            assert_approx_eq!(m.transform_point3(*a), b, epsilon = 1e-4);
+        }
+
+        // a shear keeps every corner inside
+        let shear = Matrix4f::new(
+            1.0, 0.5, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0
+        );
+        let sheared = o.transform(&shear);
+        for c in o.corners() {
+            let p = shear.transform_point3(c);
+            assert!(sheared.contains_point(sheared.center + (p - sheared.center) * (1.0 - 1e-4)));
+        }
+    }
+}
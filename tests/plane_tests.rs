@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Planef, MathError};
+    use ember_math::core::{Vector3f, Vector4f, Matrix4f, Quaternion, Rad};
+    use ember_math::assert_approx_eq;
+
+    static EPS:f32 = 1e-5;
+
+    #[test]
+    pub fn test_construction(){
+        // z = 2, facing +z
+        let expected = Planef::new(Vector3f::new(0.0, 0.0, 1.0), -2.0);
+        let from_points = Planef::from_points(
+            Vector3f::new(0.0, 0.0, 2.0),
+            Vector3f::new(1.0, 0.0, 2.0),
+            Vector3f::new(0.0, 1.0, 2.0)
+        );
+        assert_approx_eq!(from_points, expected, epsilon = EPS);
+        let from_point_normal = Planef::from_point_normal(Vector3f::new(3.0, -1.0, 2.0), Vector3f::new(0.0, 0.0, 5.0));
+        assert_approx_eq!(from_point_normal, expected, epsilon = EPS);
+        let from_vector = Planef::from_vector4(Vector4f::new(0.0, 0.0, 4.0, -8.0));
+        assert_approx_eq!(from_vector, expected, epsilon = EPS);
+        assert_approx_eq!(Planef::from(Vector4f::new(0.0, 0.0, 4.0, -8.0)), expected, epsilon = EPS);
+        assert_eq!(Vector4f::from(expected), Vector4f::new(0.0, 0.0, 1.0, -2.0));
+        assert_eq!(expected.to_vector4(), Vector4f::new(0.0, 0.0, 1.0, -2.0));
+
+        // clockwise from +z faces -z
+        let reversed = Planef::from_points(
+            Vector3f::new(0.0, 0.0, 2.0),
+            Vector3f::new(0.0, 1.0, 2.0),
+            Vector3f::new(1.0, 0.0, 2.0)
+        );
+        assert_approx_eq!(reversed, expected.flip(), epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_construction_errors(){
+        let p = Vector3f::new(1.0, 2.0, 3.0);
+        let d = Vector3f::new(1.0, 1.0, 0.0);
+        assert_eq!(Planef::try_from_points(p, p + d, p + d * 3.0), Err(MathError::DegenerateGeometry));
+        assert_eq!(Planef::try_from_points(p, p, p + d), Err(MathError::DegenerateGeometry));
+        assert_eq!(Planef::try_new(Vector3f::zero(), 1.0), Err(MathError::ZeroLengthVector));
+        assert!(Planef::try_from_points(p, p + d, p + Vector3f::new(0.0, 0.0, 1.0)).is_ok());
+    }
+
+    #[test]
+    pub fn test_signed_distance_and_projection(){
+        let plane = Planef::from_point_normal(Vector3f::new(1.0, 1.0, 0.0), Vector3f::new(1.0, 1.0, 0.0));
+        let d = 2.0f32.sqrt();
+        assert_approx_eq!(plane.signed_distance(Vector3f::zero()), -d, epsilon = EPS);
+        assert_approx_eq!(plane.signed_distance(Vector3f::new(2.0, 2.0, 7.0)), d, epsilon = EPS);
+        assert_approx_eq!(plane.flip().signed_distance(Vector3f::new(2.0, 2.0, 7.0)), -d, epsilon = EPS);
+
+        let p = Vector3f::new(3.0, 0.0, -4.0);
+        let q = plane.project_point(p);
+        assert_approx_eq!(q, Vector3f::new(2.5, -0.5, -4.0), epsilon = EPS);
+        assert_approx_eq!(plane.signed_distance(q), 0.0, epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_reflection(){
+        let plane = Planef::new(Vector3f::new(0.0, 1.0, 0.0), -1.0);
+        let m = plane.reflection_matrix();
+        assert_approx_eq!(m.transform_point3(Vector3f::new(2.0, 3.0, -1.0)), Vector3f::new(2.0, -1.0, -1.0), epsilon = EPS);
+        assert_approx_eq!(m.transform_vector3(Vector3f::new(0.0, 1.0, 1.0)), Vector3f::new(0.0, -1.0, 1.0), epsilon = EPS);
+        assert_approx_eq!(m * m, Matrix4f::identity(), epsilon = EPS);
+
+        let tilted = Planef::from_point_normal(Vector3f::new(1.0, 2.0, 3.0), Vector3f::new(1.0, -2.0, 2.0));
+        let m = tilted.reflection_matrix();
+        let p = Vector3f::new(-4.0, 0.5, 2.0);
+        let r = m.transform_point3(p);
+        assert_approx_eq!(tilted.signed_distance(r), -tilted.signed_distance(p), epsilon = EPS);
+        assert_approx_eq!(tilted.project_point(r), tilted.project_point(p), epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_transform(){
+        let plane = Planef::from_points(
+            Vector3f::new(1.0, 0.0, 0.0),
+            Vector3f::new(0.0, 2.0, 0.0),
+            Vector3f::new(0.0, 0.0, 3.0)
+        );
+        let m = Matrix4f::from_translation(Vector3f::new(1.0, -2.0, 0.5))
+            * Quaternion::from_axis_angle(Vector3f::new(1.0, 1.0, 0.0).normalize(), Rad(0.7f32)).to_matrix4()
+            * Matrix4f::from_scale_vec(Vector3f::new(2.0, 0.5, 3.0));
+        let moved = plane.transform(&m);
+        assert_approx_eq!(moved.normal.magnitude(), 1.0, epsilon = EPS);
+        for p in [Vector3f::new(1.0, 0.0, 0.0), Vector3f::new(0.0, 2.0, 0.0), Vector3f::new(0.0, 0.0, 3.0)] {
+            assert_approx_eq!(moved.signed_distance(m.transform_point3(p)), 0.0, epsilon = EPS);
+        }
+        // the positive side stays positive
+        assert!(moved.signed_distance(m.transform_point3(Vector3f::new(2.0, 2.0, 2.0))) > 0.0);
+        assert_approx_eq!(plane.try_transform(&m).unwrap(), moved, epsilon = EPS);
+
+        let singular = Matrix4f::from_scale_vec(Vector3f::new(1.0, 0.0, 1.0));
+        assert_eq!(plane.try_transform(&singular), Err(MathError::SingularMatrix));
+    }
+
+    #[test]
+    pub fn test_intersect_plane(){
+        let a = Planef::from_point_normal(Vector3f::new(0.0, 0.0, 1.0), Vector3f::new(0.0, 0.0, 1.0));
+        let b = Planef::from_point_normal(Vector3f::new(2.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0));
+        let (point, dir) = a.intersect_plane(&b).unwrap();
+        assert_approx_eq!(point, Vector3f::new(2.0, 0.0, 1.0), epsilon = EPS);
+        assert_approx_eq!(dir, Vector3f::new(0.0, 1.0, 0.0), epsilon = EPS);
+
+        let c = Planef::from_point_normal(Vector3f::new(1.0, 2.0, -1.0), Vector3f::new(1.0, 3.0, -2.0));
+        let (point, dir) = a.intersect_plane(&c).unwrap();
+        assert_approx_eq!(a.signed_distance(point), 0.0, epsilon = EPS);
+        assert_approx_eq!(c.signed_distance(point), 0.0, epsilon = EPS);
+        assert_approx_eq!(a.signed_distance(point + dir * 5.0), 0.0, epsilon = EPS);
+        assert_approx_eq!(c.signed_distance(point + dir * 5.0), 0.0, epsilon = EPS);
+
+        assert!(a.intersect_plane(&a.flip()).is_none());
+        assert!(a.intersect_plane(&Planef::new(Vector3f::new(0.0, 0.0, 1.0), 4.0)).is_none());
+    }
+
+    #[test]
+    pub fn test_intersect_three(){
+        let x = Planef::from_point_normal(Vector3f::new(1.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0));
+        let y = Planef::from_point_normal(Vector3f::new(0.0, -2.0, 0.0), Vector3f::new(0.0, 1.0, 0.0));
+        let z = Planef::from_point_normal(Vector3f::new(0.0, 0.0, 3.0), Vector3f::new(0.0, 0.0, -1.0));
+        assert_approx_eq!(Planef::intersect_three(&x, &y, &z).unwrap(), Vector3f::new(1.0, -2.0, 3.0), epsilon = EPS);
+
+        let tilted = Planef::from_point_normal(Vector3f::new(1.0, 1.0, 1.0), Vector3f::new(1.0, 2.0, 3.0));
+        let p = Planef::intersect_three(&x, &tilted, &z).unwrap();
+        for plane in [x, tilted, z] {
+            assert_approx_eq!(plane.signed_distance(p), 0.0, epsilon = EPS);
+        }
+
+        // two parallel planes, and three planes through one line
+        let x2 = Planef::new(Vector3f::new(1.0, 0.0, 0.0), 5.0);
+        assert!(Planef::intersect_three(&x, &x2, &y).is_none());
+        let diagonal = Planef::from_point_normal(Vector3f::new(1.0, -2.0, 0.0), Vector3f::new(1.0, 1.0, 0.0));
+        assert!(Planef::intersect_three(&x, &y, &diagonal).is_none());
+    }
+}
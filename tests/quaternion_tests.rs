@@ -0,0 +1,314 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::Quaternion;
+    use ember_math::core::{Quaterniond, Vector3d};
+    use ember_math::core::Vector3f;
+    use ember_math::core::Matrix3f;
+    use ember_math::core::Matrix4f;
+    use ember_math::core::MathError;
+    use ember_math::core::Rad;
+
+    static EPS:f32 = 1e-5;
+    static HALF_PI:f32 = std::f32::consts::FRAC_PI_2;
+
+    pub fn almost_eq(a: f32, b: f32, eps: f32) -> bool {
+        (a - b).abs() < eps
+    }
+
+    pub fn vec_almost_eq(a: Vector3f, b: Vector3f, eps: f32) -> bool {
+        almost_eq(a.x, b.x, eps) && almost_eq(a.y, b.y, eps) && almost_eq(a.z, b.z, eps)
+    }
+
+    pub fn quat_almost_eq(a: Quaternion, b: Quaternion, eps: f32) -> bool {
+        almost_eq(a.x, b.x, eps) && almost_eq(a.y, b.y, eps) && almost_eq(a.z, b.z, eps) && almost_eq(a.w, b.w, eps)
+    }
+
+    #[test]
+    pub fn test_create_quaternion(){
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(q.x, 1.0);
+        assert_eq!(q.y, 2.0);
+        assert_eq!(q.z, 3.0);
+        assert_eq!(q.w, 4.0);
+    }
+
+    #[test]
+    pub fn test_identity(){
+        let q = Quaternion::identity();
+        assert_eq!(q, Quaternion::new(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(q, Quaternion::default());
+
+        let v = Vector3f::new(1.0, -2.0, 3.0);
+        assert_eq!(q.rotate(v), v);
+    }
+
+    #[test]
+    pub fn test_from_axis_angle(){
+        let q = Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 2.0), Rad(HALF_PI));
+        assert!(almost_eq(q.x, 0.0, EPS));
+        assert!(almost_eq(q.y, 0.0, EPS));
+        assert!(almost_eq(q.z, std::f32::consts::FRAC_1_SQRT_2, EPS));
+        assert!(almost_eq(q.w, std::f32::consts::FRAC_1_SQRT_2, EPS));
+    }
+
+    #[test]
+    pub fn test_rotate(){
+        let qz = Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(HALF_PI));
+        let r = qz.rotate(Vector3f::new(1.0, 0.0, 0.0));
+        assert!(vec_almost_eq(r, Vector3f::new(0.0, 1.0, 0.0), EPS));
+
+        let qx = Quaternion::from_axis_angle(Vector3f::new(1.0, 0.0, 0.0), Rad(HALF_PI));
+        let r = qx.rotate(Vector3f::new(0.0, 1.0, 0.0));
+        assert!(vec_almost_eq(r, Vector3f::new(0.0, 0.0, 1.0), EPS));
+    }
+
+    #[test]
+    pub fn test_multiplication(){
+        let a = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        let b = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+        assert_eq!(a * b, Quaternion::new(24.0, 48.0, 48.0, -6.0));
+
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+    }
+
+    #[test]
+    pub fn test_multiplication_composes_rotations(){
+        let qx = Quaternion::from_axis_angle(Vector3f::new(1.0, 0.0, 0.0), Rad(HALF_PI));
+        let qz = Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(HALF_PI));
+        let v = Vector3f::new(0.0, 1.0, 0.0);
+        let composed = (qz * qx).rotate(v);
+        assert!(vec_almost_eq(composed, qz.rotate(qx.rotate(v)), EPS));
+        assert!(vec_almost_eq(composed, Vector3f::new(0.0, 0.0, 1.0), EPS));
+    }
+
+    #[test]
+    pub fn test_conjugate_inverse(){
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(q.conjugate(), Quaternion::new(-1.0, -2.0, -3.0, 4.0));
+        assert!(quat_almost_eq(q * q.inverse(), Quaternion::identity(), EPS));
+
+        let r = Quaternion::from_axis_angle(Vector3f::new(1.0, 1.0, 0.0), Rad(0.7));
+        assert!(quat_almost_eq(r.inverse(), r.conjugate(), EPS));
+    }
+
+    #[test]
+    pub fn test_normalize(){
+        let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+        assert!(almost_eq(q.magnitude_squared(), 30.0, EPS));
+        assert!(almost_eq(q.normalize().magnitude(), 1.0, EPS));
+    }
+
+    #[test]
+    pub fn test_try_normalize(){
+        let q = Quaternion::new(0.0, 0.0, 3.0, 4.0);
+        assert_eq!(q.try_normalize(), Ok(Quaternion::new(0.0, 0.0, 0.6, 0.8)));
+        assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).try_normalize(), Err(MathError::ZeroLengthVector));
+    }
+
+    #[test]
+    pub fn test_to_matrix3(){
+        let q = Quaternion::from_axis_angle(Vector3f::one(), Rad(1.0));
+        let m = q.to_matrix3();
+        let r = Matrix3f::from_axis_angle(Vector3f::one(), Rad(1.0));
+        for i in 0..9 {
+            assert!(almost_eq(m.data[i], r.data[i], EPS));
+        }
+
+        let v = Vector3f::new(0.3, -1.2, 2.0);
+        assert!(vec_almost_eq(m.transform(v), q.rotate(v), EPS));
+    }
+
+    #[test]
+    pub fn test_to_matrix4(){
+        let q = Quaternion::from_axis_angle(Vector3f::new(0.0, 1.0, 0.0), Rad(0.5));
+        let m = q.to_matrix4();
+        let r = Matrix4f::from_angle_y(Rad(0.5));
+        for i in 0..16 {
+            assert!(almost_eq(m.data[i], r.data[i], EPS));
+        }
+    }
+
+    #[test]
+    pub fn test_from_rotation_matrix(){
+        let axes = [
+            Vector3f::new(1.0, 0.0, 0.0),
+            Vector3f::new(0.0, 1.0, 0.0),
+            Vector3f::new(0.0, 0.0, 1.0),
+            Vector3f::new(1.0, -2.0, 0.5),
+        ];
+        let angles = [0.3, 1.5, 3.0, -2.5];
+        for axis in axes.iter() {
+            for angle in angles.iter() {
+                let q = Quaternion::from_axis_angle(*axis, Rad(*angle));
+                let r = Quaternion::from_rotation_matrix(&q.to_matrix3());
+                // q and -q describe the same rotation
+                let r = if r.dot(q) < 0.0 { Quaternion::new(-r.x, -r.y, -r.z, -r.w) } else { r };
+                assert!(quat_almost_eq(q, r, EPS));
+
+                let r4 = Quaternion::from_rotation_matrix4(&q.to_matrix4());
+                assert!(almost_eq(r4.dot(q).abs(), 1.0, EPS));
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_from_euler(){
+        let q = Quaternion::from_euler(Rad(0.4), Rad(-0.9), Rad(1.3));
+        let r = Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(1.3))
+            * Quaternion::from_axis_angle(Vector3f::new(0.0, 1.0, 0.0), Rad(-0.9))
+            * Quaternion::from_axis_angle(Vector3f::new(1.0, 0.0, 0.0), Rad(0.4));
+        assert!(quat_almost_eq(q, r, EPS));
+
+        let m = Matrix3f::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(1.3))
+            * Matrix3f::from_axis_angle(Vector3f::new(0.0, 1.0, 0.0), Rad(-0.9))
+            * Matrix3f::from_axis_angle(Vector3f::new(1.0, 0.0, 0.0), Rad(0.4));
+        let qm = q.to_matrix3();
+        for i in 0..9 {
+            assert!(almost_eq(qm.data[i], m.data[i], EPS));
+        }
+    }
+
+    pub fn about_z(angle: f32) -> Quaternion {
+        Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(angle))
+    }
+
+    #[test]
+    pub fn test_slerp(){
+        let a = Quaternion::identity();
+        let b = about_z(HALF_PI);
+
+        let half = a.slerp(b, 0.5);
+        assert!(quat_almost_eq(half, Quaternion::new(0.0, 0.0, 0.382683, 0.923880), EPS));
+
+        let quarter = a.slerp(b, 0.25);
+        assert!(quat_almost_eq(quarter, Quaternion::new(0.0, 0.0, 0.195090, 0.980785), EPS));
+
+        assert!(quat_almost_eq(a.slerp(b, 0.0), a, EPS));
+        assert!(quat_almost_eq(a.slerp(b, 1.0), b, EPS));
+    }
+
+    #[test]
+    pub fn test_slerp_shortest_path(){
+        let a = Quaternion::identity();
+        let b = about_z(HALF_PI);
+        let neg_b = b.scale(-1.0);
+
+        let r = a.slerp(neg_b, 0.5);
+        assert!(quat_almost_eq(r, Quaternion::new(0.0, 0.0, 0.382683, 0.923880), EPS));
+    }
+
+    #[test]
+    pub fn test_slerp_nearly_equal(){
+        let a = about_z(0.3);
+        let b = about_z(0.3001);
+        let r = a.slerp(b, 0.5);
+        assert!(almost_eq(r.magnitude(), 1.0, EPS));
+        assert!(quat_almost_eq(r, about_z(0.30005), EPS));
+    }
+
+    #[test]
+    pub fn test_nlerp(){
+        let a = Quaternion::identity();
+        let b = about_z(HALF_PI);
+
+        let half = a.nlerp(b, 0.5);
+        assert!(quat_almost_eq(half, Quaternion::new(0.0, 0.0, 0.382683, 0.923880), EPS));
+
+        let quarter = a.nlerp(b, 0.25);
+        assert!(quat_almost_eq(quarter, Quaternion::new(0.0, 0.0, 0.187366, 0.982290), EPS));
+
+        let r = a.nlerp(b.scale(-1.0), 0.5);
+        assert!(quat_almost_eq(r, half, EPS));
+    }
+
+    #[test]
+    pub fn test_log_exp(){
+        let q = Quaternion::from_axis_angle(Vector3f::new(1.0, 2.0, -1.0), Rad(1.2));
+        let l = q.log();
+        assert!(almost_eq(l.w, 0.0, EPS));
+        assert!(almost_eq((l.x * l.x + l.y * l.y + l.z * l.z).sqrt(), 0.6, EPS));
+        assert!(quat_almost_eq(l.exp(), q, EPS));
+
+        assert!(quat_almost_eq(Quaternion::identity().log().exp(), Quaternion::identity(), EPS));
+    }
+
+    #[test]
+    pub fn test_squad_endpoints(){
+        let q1 = about_z(0.2);
+        let q2 = Quaternion::from_axis_angle(Vector3f::new(1.0, 1.0, 0.0), Rad(0.9));
+        let s1 = Quaternion::from_axis_angle(Vector3f::new(0.0, 1.0, 0.0), Rad(0.4));
+        let s2 = Quaternion::from_axis_angle(Vector3f::new(1.0, 0.0, 1.0), Rad(0.6));
+        assert!(quat_almost_eq(Quaternion::squad(q1, q2, s1, s2, 0.0), q1, EPS));
+        assert!(quat_almost_eq(Quaternion::squad(q1, q2, s1, s2, 1.0), q2, EPS));
+    }
+
+    #[test]
+    pub fn test_squad_antipodal_keys(){
+        let q = Quaternion::from_axis_angle(Vector3f::new(1.0, 2.0, 3.0).normalize(), Rad(0.8));
+        let n = q.scale(-1.0);
+        assert!(quat_almost_eq(Quaternion::squad(q, n, q, n, 0.0), q, EPS));
+        assert!(quat_almost_eq(Quaternion::squad(q, n, q, n, 1.0), n, EPS));
+        for i in 0..11 {
+            let r = Quaternion::squad(q, n, q, n, i as f32 * 0.1);
+            assert!(almost_eq(r.magnitude(), 1.0, EPS));
+        }
+        assert!(almost_eq(Quaternion::squad(q, n, q, n, 0.5).dot(q), 0.0, EPS));
+    }
+
+    #[test]
+    pub fn test_squad_spline_uniform(){
+        // evenly spaced keys about one axis: the control points coincide
+        // with the keys and squad reduces to slerp
+        let keys = [about_z(0.0), about_z(0.5), about_z(1.0), about_z(1.5)];
+        let c = Quaternion::squad_control_point(keys[0], keys[1], keys[2]);
+        assert!(quat_almost_eq(c, keys[1], EPS));
+
+        assert!(quat_almost_eq(Quaternion::squad_spline(&keys, 1.5), about_z(0.75), EPS));
+        assert!(quat_almost_eq(Quaternion::squad_spline(&keys, 1.25), about_z(0.625), EPS));
+        assert!(quat_almost_eq(Quaternion::squad_spline(&keys, 1.9), about_z(0.95), EPS));
+    }
+
+    #[test]
+    pub fn test_squad_spline_passes_through_keys(){
+        let keys = [
+            Quaternion::identity(),
+            about_z(HALF_PI),
+            Quaternion::from_axis_angle(Vector3f::new(1.0, 0.0, 0.0), Rad(1.0)),
+            Quaternion::from_axis_angle(Vector3f::new(0.0, 1.0, 1.0), Rad(-0.7)).scale(-1.0),
+        ];
+        for (i, key) in keys.iter().enumerate() {
+            let r = Quaternion::squad_spline(&keys, i as f32);
+            assert!(almost_eq(r.dot(*key).abs(), 1.0, EPS));
+        }
+
+        // clamped outside the key range
+        assert!(quat_almost_eq(Quaternion::squad_spline(&keys, -1.0), keys[0], EPS));
+        assert!(almost_eq(Quaternion::squad_spline(&keys, 10.0).dot(keys[3]).abs(), 1.0, EPS));
+
+        // stays unit length between keys
+        for i in 0..31 {
+            let r = Quaternion::squad_spline(&keys, i as f32 * 0.1);
+            assert!(almost_eq(r.magnitude(), 1.0, 1e-4));
+        }
+    }
+
+    #[test]
+    pub fn test_quaterniond(){
+        let q = Quaterniond::from_axis_angle(Vector3d::new(0.0, 0.0, 1.0), Rad(std::f64::consts::FRAC_PI_2));
+        let v = q.rotate(Vector3d::new(1.0, 0.0, 0.0));
+        assert!((v.x).abs() < 1e-15);
+        assert!((v.y - 1.0).abs() < 1e-15);
+
+        let qf = Quaternion::from_axis_angle(Vector3f::new(0.0, 1.0, 0.0), Rad(HALF_PI));
+        let qd: Quaterniond = qf.into();
+        assert_eq!(qd.w, qf.w as f64);
+        assert_eq!(qd.y, qf.y as f64);
+
+        assert_eq!(qd.cast::<f32>(), qf);
+        let qf = q.cast::<f32>();
+        assert_eq!(qf.z, q.z as f32);
+        assert_eq!(qf.w, q.w as f32);
+    }
+}
\ No newline at end of file
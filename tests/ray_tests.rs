@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Rayf, Aabbf, Planef};
+    use ember_math::core::{Vector2f, Vector3f, Matrix4f, MathError};
+    use ember_math::assert_approx_eq;
+
+    static EPS:f32 = 1e-5;
+
+    pub fn down_z() -> Rayf {
+        Rayf::new(Vector3f::new(0.2, 0.3, 5.0), Vector3f::new(0.0, 0.0, -2.0))
+    }
+
+    #[test]
+    pub fn test_new(){
+        let r = down_z();
+        assert_eq!(r.direction, Vector3f::new(0.0, 0.0, -1.0));
+        assert_eq!(r.at(2.0), Vector3f::new(0.2, 0.3, 3.0));
+        assert_eq!(Rayf::try_new(Vector3f::zero(), Vector3f::zero()), Err(MathError::ZeroLengthVector));
+    }
+
+    #[test]
+    pub fn test_intersect_plane(){
+        let r = down_z();
+        let hit = r.intersect_plane(&Planef::from_point_normal(Vector3f::new(0.0, 0.0, 1.0), Vector3f::new(0.0, 0.0, 1.0))).unwrap();
+        assert_approx_eq!(hit.distance, 4.0);
+        assert_eq!(hit.normal, Vector3f::new(0.0, 0.0, 1.0));
+
+        // the normal is flipped to face the ray
+        let hit = r.intersect_plane(&Planef::new(Vector3f::new(0.0, 0.0, -3.0), 0.0)).unwrap();
+        assert_approx_eq!(hit.distance, 5.0);
+        assert_eq!(hit.normal, Vector3f::new(0.0, 0.0, 1.0));
+
+        // behind the origin, and parallel
+        assert!(r.intersect_plane(&Planef::from_point_normal(Vector3f::new(0.0, 0.0, 6.0), Vector3f::new(0.0, 0.0, 1.0))).is_none());
+        assert!(r.intersect_plane(&Planef::new(Vector3f::new(1.0, 0.0, 0.0), 0.0)).is_none());
+    }
+
+    #[test]
+    pub fn test_intersect_sphere(){
+        let r = Rayf::new(Vector3f::new(0.0, 0.0, 5.0), Vector3f::new(0.0, 0.0, -1.0));
+        let hit = r.intersect_sphere(Vector3f::new(0.0, 0.0, 1.0), 2.0).unwrap();
+        assert_approx_eq!(hit.distance, 2.0);
+        assert_approx_eq!(hit.normal, Vector3f::new(0.0, 0.0, 1.0));
+
+        // from inside, the exit point
+        let inside = Rayf::new(Vector3f::new(0.0, 0.0, 1.0), Vector3f::new(1.0, 0.0, 0.0));
+        let hit = inside.intersect_sphere(Vector3f::new(0.0, 0.0, 1.0), 2.0).unwrap();
+        assert_approx_eq!(hit.distance, 2.0);
+        assert_approx_eq!(hit.normal, Vector3f::new(1.0, 0.0, 0.0));
+
+        assert!(r.intersect_sphere(Vector3f::new(3.0, 0.0, 0.0), 2.0).is_none());
+        assert!(r.intersect_sphere(Vector3f::new(0.0, 0.0, 10.0), 2.0).is_none());
+    }
+
+    #[test]
+    pub fn test_intersect_aabb(){
+        let b = Aabbf::new(Vector3f::new(-1.0, -1.0, -1.0), Vector3f::new(1.0, 1.0, 1.0));
+        let hit = down_z().intersect_aabb(&b).unwrap();
+        assert_approx_eq!(hit.distance, 4.0);
+        assert_eq!(hit.normal, Vector3f::new(0.0, 0.0, 1.0));
+
+        let diagonal = Rayf::new(Vector3f::new(-3.0, 0.5, 0.0), Vector3f::new(1.0, 0.1, 0.0));
+        let hit = diagonal.intersect_aabb(&b).unwrap();
+        assert_approx_eq!(diagonal.at(hit.distance).x, -1.0, epsilon = EPS);
+        assert_eq!(hit.normal, Vector3f::new(-1.0, 0.0, 0.0));
+
+        // from inside, the exit face
+        let inside = Rayf::new(Vector3f::zero(), Vector3f::new(0.0, -1.0, 0.0));
+        let hit = inside.intersect_aabb(&b).unwrap();
+        assert_approx_eq!(hit.distance, 1.0);
+        assert_eq!(hit.normal, Vector3f::new(0.0, -1.0, 0.0));
+
+        // parallel to a slab and outside it, pointing away, and passing by
+        assert!(Rayf::new(Vector3f::new(0.0, 2.0, 5.0), Vector3f::new(0.0, 0.0, -1.0)).intersect_aabb(&b).is_none());
+        assert!(Rayf::new(Vector3f::new(0.0, 0.0, 5.0), Vector3f::new(0.0, 0.0, 1.0)).intersect_aabb(&b).is_none());
+        assert!(Rayf::new(Vector3f::new(-3.0, 0.0, 0.0), Vector3f::new(1.0, 2.0, 0.0)).intersect_aabb(&b).is_none());
+    }
+
+    #[test]
+    pub fn test_intersect_triangle(){
+        let a = Vector3f::new(-1.0, -1.0, 0.0);
+        let b = Vector3f::new(1.0, -1.0, 0.0);
+        let c = Vector3f::new(0.0, 1.0, 0.0);
+
+        let hit = down_z().intersect_triangle(a, b, c).unwrap();
+        assert_approx_eq!(hit.distance, 5.0);
+        assert_approx_eq!(hit.normal, Vector3f::new(0.0, 0.0, 1.0));
+
+        // two-sided: from below the normal faces down
+        let up = Rayf::new(Vector3f::new(0.0, 0.0, -2.0), Vector3f::new(0.0, 0.0, 1.0));
+        let hit = up.intersect_triangle(a, b, c).unwrap();
+        assert_approx_eq!(hit.distance, 2.0);
+        assert_approx_eq!(hit.normal, Vector3f::new(0.0, 0.0, -1.0));
+
+        // outside the edges, parallel, and degenerate
+        assert!(Rayf::new(Vector3f::new(0.9, 0.9, 5.0), Vector3f::new(0.0, 0.0, -1.0)).intersect_triangle(a, b, c).is_none());
+        assert!(Rayf::new(Vector3f::new(0.0, 0.0, 1.0), Vector3f::new(1.0, 0.0, 0.0)).intersect_triangle(a, b, c).is_none());
+        assert!(down_z().intersect_triangle(a, b, a).is_none());
+    }
+
+    #[test]
+    pub fn test_picking_ray(){
+        let eye = Vector3f::new(2.0, 3.0, 10.0);
+        let target = Vector3f::new(0.0, 1.0, 0.0);
+        let view_proj = Matrix4f::perspective_gl(1.0, 1.6, 0.1, 100.0) * Matrix4f::look_at_rh(eye, target, Vector3f::new(0.0, 1.0, 0.0));
+        let viewport = Vector2f::new(1600.0, 1000.0);
+
+        // the center of the screen looks at the target
+        let r = Rayf::from_screen(Vector2f::new(800.0, 500.0), viewport, &view_proj);
+        assert_approx_eq!(r.direction, (target - eye).normalize(), epsilon = 1e-4);
+
+        // a ray through the projection of a point passes through that point
+        let p = Vector3f::new(-1.5, 2.0, 1.0);
+        let ndc = view_proj.project_point3(p);
+        let screen = Vector2f::new((ndc.x + 1.0) * 0.5 * viewport.x, (1.0 - ndc.y) * 0.5 * viewport.y);
+        let r = Rayf::try_from_screen(screen, viewport, &view_proj).unwrap();
+        let along = (p - r.origin).dot(r.direction);
+        assert_approx_eq!(r.at(along), p, epsilon = 1e-3);
+
+        let hit = r.intersect_sphere(p, 0.01).unwrap();
+        assert!((hit.distance - along).abs() < 0.02);
+
+        assert_eq!(Rayf::try_from_screen(screen, viewport, &Matrix4f::zero()), Err(MathError::SingularMatrix));
+    }
+
+    #[test]
+    pub fn test_picking_ray_zero_to_one(){
+        let eye = Vector3f::new(2.0, 3.0, 10.0);
+        let target = Vector3f::new(0.0, 1.0, 0.0);
+        let view = Matrix4f::look_at_rh(eye, target, Vector3f::new(0.0, 1.0, 0.0));
+        let view_proj = Matrix4f::perspective_rh(1.0, 1.6, 0.1, 100.0) * view;
+        let viewport = Vector2f::new(1600.0, 1000.0);
+
+        // starts on the near plane and looks at the target
+        let r = Rayf::from_screen_zero_to_one(Vector2f::new(800.0, 500.0), viewport, &view_proj);
+        assert_approx_eq!(r.direction, (target - eye).normalize(), epsilon = 1e-4);
+        assert_approx_eq!((r.origin - eye).magnitude(), 0.1, epsilon = 1e-4);
+
+        let p = Vector3f::new(-1.5, 2.0, 1.0);
+        let ndc = view_proj.project_point3(p);
+        let screen = Vector2f::new((ndc.x + 1.0) * 0.5 * viewport.x, (1.0 - ndc.y) * 0.5 * viewport.y);
+        let r = Rayf::try_from_screen_zero_to_one(screen, viewport, &view_proj).unwrap();
+        let along = (p - r.origin).dot(r.direction);
+        assert!(along > 0.0);
+        assert_approx_eq!(r.at(along), p, epsilon = 1e-3);
+
+        assert_eq!(Rayf::try_from_screen_zero_to_one(screen, viewport, &Matrix4f::zero()), Err(MathError::SingularMatrix));
+    }
+}
@@ -0,0 +1,281 @@
+// Checks matrix products, rotations and camera matrices against independent
+// f64 reference computations written out by hand, over randomized inputs.
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Matrix3f, Matrix4f, Vector3f, Vector4f, Quaternion, Rad};
+    use crate::common::Lcg;
+
+    const CASES: usize = 1000;
+
+    type V3 = [f64; 3];
+
+    fn v3(v: Vector3f) -> V3 {
+        [v.x as f64, v.y as f64, v.z as f64]
+    }
+
+    fn dot(a: V3, b: V3) -> f64 {
+        a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+    }
+
+    fn cross(a: V3, b: V3) -> V3 {
+        [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+    }
+
+    fn sub(a: V3, b: V3) -> V3 {
+        [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+    }
+
+    fn normalize(a: V3) -> V3 {
+        let m = dot(a, a).sqrt();
+        [a[0] / m, a[1] / m, a[2] / m]
+    }
+
+    // Rodrigues' rotation of `v` about the unit axis `k`.
+    fn rotate(k: V3, angle: f64, v: V3) -> V3 {
+        let (s, c) = angle.sin_cos();
+        let kv = cross(k, v);
+        let d = dot(k, v) * (1.0 - c);
+        [0, 1, 2].map(|i| v[i] * c + kv[i] * s + k[i] * d)
+    }
+
+    // `got` matches `want` up to f32 rounding of terms of size `scale`.
+    fn assert_close(got: f32, want: f64, scale: f64) {
+        let tol = 1e-5 * (1.0 + scale);
+        assert!((got as f64 - want).abs() <= tol, "got {}, want {} (tolerance {})", got, want, tol);
+    }
+
+    fn assert_close3(got: Vector3f, want: V3, scale: f64) {
+        assert_close(got.x, want[0], scale);
+        assert_close(got.y, want[1], scale);
+        assert_close(got.z, want[2], scale);
+    }
+
+    // Row-major n x n product, with the sum of |a_ik * b_kj| as the scale of
+    // each element.
+    fn product(a: &[f32], b: &[f32], n: usize) -> Vec<(f64, f64)> {
+        let mut out = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                let mut sum = 0.0;
+                let mut scale = 0.0;
+                for k in 0..n {
+                    let t = a[i * n + k] as f64 * b[k * n + j] as f64;
+                    sum += t;
+                    scale += t.abs();
+                }
+                out.push((sum, scale));
+            }
+        }
+        out
+    }
+
+    #[test]
+    pub fn test_matrix4_product(){
+        let mut rng = Lcg(11);
+        for _ in 0..CASES {
+            let a = rng.matrix4(-10.0, 10.0);
+            let b = rng.matrix4(-10.0, 10.0);
+            let ab = a * b;
+            for (got, (want, scale)) in ab.data.iter().zip(product(&a.data, &b.data, 4)) {
+                assert_close(*got, want, scale);
+            }
+
+            let mut c = a;
+            c *= b;
+            assert_eq!(c, ab);
+
+            // (ab)v == a(bv)
+            let v = rng.vector4(-10.0, 10.0);
+            let lhs = ab.transform(v);
+            let rhs = a.transform(b.transform(v));
+            for (x, y) in lhs.iter().zip(rhs.iter()) {
+                assert!((x - y).abs() <= 1e-3 * (1.0 + y.abs()), "{:?} != {:?}", lhs, rhs);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_matrix3_product(){
+        let mut rng = Lcg(12);
+        for _ in 0..CASES {
+            let a = rng.matrix3(-10.0, 10.0);
+            let b = rng.matrix3(-10.0, 10.0);
+            let ab = a * b;
+            for (got, (want, scale)) in ab.data.iter().zip(product(&a.data, &b.data, 3)) {
+                assert_close(*got, want, scale);
+            }
+
+            let mut c = a;
+            c *= b;
+            assert_eq!(c, ab);
+        }
+    }
+
+    #[test]
+    pub fn test_axis_angle_rotations(){
+        let mut rng = Lcg(13);
+        for _ in 0..CASES {
+            let axis = rng.unit();
+            let angle = rng.range(-6.0, 6.0);
+            let v = rng.vector(-10.0, 10.0);
+            let want = rotate(v3(axis), angle as f64, v3(v));
+            let scale = dot(v3(v), v3(v)).sqrt();
+
+            assert_close3(Matrix3f::from_axis_angle(axis, Rad(angle)).transform(v), want, scale);
+            assert_close3(Matrix4f::from_axis_angle(axis, Rad(angle)).transform_point3(v), want, scale);
+            assert_close3(Matrix4f::from_axis_angle_comp(axis.x, axis.y, axis.z, Rad(angle)).transform_point3(v), want, scale);
+            assert_close3(Quaternion::from_axis_angle(axis, Rad(angle)).rotate(v), want, scale);
+            assert_close3(Quaternion::from_axis_angle(axis, Rad(angle)).to_matrix4().transform_point3(v), want, scale);
+        }
+    }
+
+    #[test]
+    pub fn test_axis_rotations(){
+        let mut rng = Lcg(14);
+        let axes = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        for _ in 0..CASES {
+            let angle = rng.range(-6.0, 6.0);
+            let v = rng.vector(-10.0, 10.0);
+            let scale = dot(v3(v), v3(v)).sqrt();
+            let m3 = [Matrix3f::from_angle_x(Rad(angle)), Matrix3f::from_angle_y(Rad(angle)), Matrix3f::from_angle_z(Rad(angle))];
+            let m4 = [Matrix4f::from_angle_x(Rad(angle)), Matrix4f::from_angle_y(Rad(angle)), Matrix4f::from_angle_z(Rad(angle))];
+            for i in 0..3 {
+                let want = rotate(axes[i], angle as f64, v3(v));
+                assert_close3(m3[i].transform(v), want, scale);
+                assert_close3(m4[i].transform_point3(v), want, scale);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_composed_rotations(){
+        let mut rng = Lcg(15);
+        for _ in 0..CASES {
+            let (k1, a1) = (rng.unit(), rng.range(-3.0, 3.0));
+            let (k2, a2) = (rng.unit(), rng.range(-3.0, 3.0));
+            let t = rng.vector(-10.0, 10.0);
+            let v = rng.vector(-10.0, 10.0);
+
+            // translate after rotating by k2, then by k1
+            let m = Matrix4f::from_translation(t) * Matrix4f::from_axis_angle(k1, Rad(a1)) * Matrix4f::from_axis_angle(k2, Rad(a2));
+            let r = rotate(v3(k1), a1 as f64, rotate(v3(k2), a2 as f64, v3(v)));
+            let want = [r[0] + t.x as f64, r[1] + t.y as f64, r[2] + t.z as f64];
+            assert_close3(m.transform_point3(v), want, 20.0);
+
+            let m3 = Matrix3f::from_axis_angle(k1, Rad(a1)) * Matrix3f::from_axis_angle(k2, Rad(a2));
+            assert_close3(m3.transform(v), r, 20.0);
+        }
+    }
+
+    // The view-space position of `p` for a right-handed camera.
+    fn view_ref(eye: V3, center: V3, up: V3, p: V3) -> V3 {
+        let f = normalize(sub(center, eye));
+        let s = normalize(cross(f, up));
+        let u = cross(s, f);
+        let d = sub(p, eye);
+        [dot(s, d), dot(u, d), -dot(f, d)]
+    }
+
+    // OpenGL clip-space position of the view-space point `v`.
+    fn clip_ref(fovy: f64, aspect: f64, near: f64, far: f64, v: V3) -> [f64; 4] {
+        let f = 1.0 / (fovy / 2.0).tan();
+        [
+            v[0] * f / aspect,
+            v[1] * f,
+            (v[2] * (far + near) + 2.0 * far * near) / (near - far),
+            -v[2],
+        ]
+    }
+
+    pub struct Camera {
+        eye: Vector3f,
+        center: Vector3f,
+        up: Vector3f,
+        fovy: f32,
+        aspect: f32,
+        near: f32,
+        far: f32,
+    }
+
+    impl Camera {
+        pub fn random(rng: &mut Lcg) -> Camera {
+            let eye = rng.vector(-20.0, 20.0);
+            let center = eye + rng.unit() * rng.range(1.0, 20.0);
+            let dir = (center - eye).normalize();
+            // keep `up` well away from the view direction
+            let mut up = rng.unit();
+            while dir.cross(up).magnitude() < 0.3 {
+                up = rng.unit();
+            }
+            Camera{
+                eye,
+                center,
+                up,
+                fovy: rng.range(0.3, 2.5),
+                aspect: rng.range(0.5, 2.5),
+                near: rng.range(0.1, 1.0),
+                far: rng.range(10.0, 100.0),
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_look_at_rh(){
+        let mut rng = Lcg(16);
+        for _ in 0..CASES {
+            let c = Camera::random(&mut rng);
+            let m = Matrix4f::look_at_rh(c.eye, c.center, c.up);
+            let p = rng.vector(-50.0, 50.0);
+            let want = view_ref(v3(c.eye), v3(c.center), v3(c.up), v3(p));
+            assert_close3(m.transform_point3(p), want, 100.0);
+
+            let bottom = Vector4f::new(m.data[12], m.data[13], m.data[14], m.data[15]);
+            assert_eq!(bottom, Vector4f::new(0.0, 0.0, 0.0, 1.0));
+        }
+    }
+
+    #[test]
+    pub fn test_perspective(){
+        let mut rng = Lcg(17);
+        for _ in 0..CASES {
+            let c = Camera::random(&mut rng);
+            let m = Matrix4f::perspective_gl(c.fovy, c.aspect, c.near, c.far);
+            let v = Vector3f::new(rng.range(-10.0, 10.0), rng.range(-10.0, 10.0), rng.range(-c.far, -c.near));
+            let want = clip_ref(c.fovy as f64, c.aspect as f64, c.near as f64, c.far as f64, v3(v));
+            let got = m.transform(v.extend(1.0));
+            let scale = 10.0 / (c.fovy as f64 / 2.0).tan() / c.aspect.min(1.0) as f64 + 4.0 * c.far as f64;
+            for (g, w) in got.iter().zip(want) {
+                assert_close(g, w, scale);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_view_projection(){
+        let mut rng = Lcg(18);
+        for _ in 0..CASES {
+            let c = Camera::random(&mut rng);
+            let m = Matrix4f::perspective_gl(c.fovy, c.aspect, c.near, c.far) * Matrix4f::look_at_rh(c.eye, c.center, c.up);
+
+            // a point inside the view volume
+            let depth = rng.range(c.near, c.far);
+            let half_h = depth * (c.fovy / 2.0).tan();
+            let x = rng.range(-1.0, 1.0) * half_h * c.aspect;
+            let y = rng.range(-1.0, 1.0) * half_h;
+            let view = Matrix4f::look_at_rh(c.eye, c.center, c.up);
+            let p = view.inverse_affine().transform_point3(Vector3f::new(x, y, -depth));
+
+            let v = view_ref(v3(c.eye), v3(c.center), v3(c.up), v3(p));
+            let clip = clip_ref(c.fovy as f64, c.aspect as f64, c.near as f64, c.far as f64, v);
+            let ndc = m.project_point3(p);
+            // depth close to the near plane amplifies f32 rounding in the
+            // product, hence the looser scale
+            for (i, n) in ndc.iter().enumerate() {
+                assert_close(n, clip[i] / clip[3], 10.0);
+                assert!(n.abs() <= 1.0 + 1e-3);
+            }
+        }
+    }
+}
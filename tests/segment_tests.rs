@@ -0,0 +1,81 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ember_math::core::Segmentf;
+    use ember_math::core::Vector3f;
+    use ember_math::assert_approx_eq;
+    use crate::common::Lcg;
+
+    static EPS:f32 = 1e-5;
+
+    #[test]
+    pub fn test_point_queries(){
+        let s = Segmentf::new(Vector3f::new(0.0, 0.0, 0.0), Vector3f::new(4.0, 0.0, 0.0));
+        assert_eq!(s.length(), 4.0);
+        assert_eq!(s.direction(), Vector3f::new(4.0, 0.0, 0.0));
+        assert_eq!(s.at(0.25), Vector3f::new(1.0, 0.0, 0.0));
+
+        assert_approx_eq!(s.closest_parameter(Vector3f::new(3.0, 2.0, 0.0)), 0.75, epsilon = EPS);
+        assert_eq!(s.closest_point(Vector3f::new(-2.0, 1.0, 0.0)), s.start);
+        assert_eq!(s.closest_point(Vector3f::new(6.0, 1.0, 0.0)), s.end);
+        assert_approx_eq!(s.distance_to_point(Vector3f::new(2.0, 3.0, 4.0)), 5.0, epsilon = EPS);
+        assert_approx_eq!(s.distance_squared_to_point(Vector3f::new(7.0, 4.0, 0.0)), 25.0, epsilon = EPS);
+
+        let point = Segmentf::new(Vector3f::one(), Vector3f::one());
+        assert_eq!(point.closest_point(Vector3f::zero()), Vector3f::one());
+    }
+
+    #[test]
+    pub fn test_closest_points(){
+        let s = Segmentf::new(Vector3f::new(-1.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0));
+
+        // skew, crossing above the middle
+        let (p, q) = s.closest_points(&Segmentf::new(Vector3f::new(0.5, -1.0, 2.0), Vector3f::new(0.5, 1.0, 2.0)));
+        assert_approx_eq!(p, Vector3f::new(0.5, 0.0, 0.0), epsilon = EPS);
+        assert_approx_eq!(q, Vector3f::new(0.5, 0.0, 2.0), epsilon = EPS);
+
+        // the lines meet beyond the ends, so the ends are closest
+        let (p, q) = s.closest_points(&Segmentf::new(Vector3f::new(3.0, 1.0, 0.0), Vector3f::new(3.0, 4.0, 0.0)));
+        assert_approx_eq!(p, s.end, epsilon = EPS);
+        assert_approx_eq!(q, Vector3f::new(3.0, 1.0, 0.0), epsilon = EPS);
+
+        // parallel and overlapping
+        let (p, q) = s.closest_points(&Segmentf::new(Vector3f::new(0.0, 2.0, 0.0), Vector3f::new(3.0, 2.0, 0.0)));
+        assert_approx_eq!((q - p).magnitude(), 2.0, epsilon = EPS);
+        assert_approx_eq!(q - p, Vector3f::new(0.0, 2.0, 0.0), epsilon = EPS);
+
+        // crossing
+        let (p, q) = s.closest_points(&Segmentf::new(Vector3f::new(0.0, -1.0, 0.0), Vector3f::new(0.0, 1.0, 0.0)));
+        assert_approx_eq!(p, Vector3f::zero(), epsilon = EPS);
+        assert_approx_eq!(q, Vector3f::zero(), epsilon = EPS);
+
+        // either one a single point
+        let point = Segmentf::new(Vector3f::new(0.3, 2.0, 0.0), Vector3f::new(0.3, 2.0, 0.0));
+        let (p, q) = s.closest_points(&point);
+        assert_approx_eq!(p, Vector3f::new(0.3, 0.0, 0.0), epsilon = EPS);
+        assert_eq!(q, point.start);
+        let (p, q) = point.closest_points(&s);
+        assert_eq!(p, point.start);
+        assert_approx_eq!(q, Vector3f::new(0.3, 0.0, 0.0), epsilon = EPS);
+        assert_eq!(point.closest_points(&point), (point.start, point.start));
+    }
+
+    #[test]
+    pub fn test_closest_points_random(){
+        // no sampled pair of points is closer than the pair found
+        let mut rng = Lcg(5);
+        for _ in 0..200 {
+            let a = Segmentf::new(rng.vector(-5.0, 5.0), rng.vector(-5.0, 5.0));
+            let b = Segmentf::new(rng.vector(-5.0, 5.0), rng.vector(-5.0, 5.0));
+            let (p, q) = a.closest_points(&b);
+            let best = (q - p).magnitude();
+            assert_approx_eq!(a.distance_to_point(p), 0.0, epsilon = 1e-4);
+            assert_approx_eq!(b.distance_to_point(q), 0.0, epsilon = 1e-4);
+            for _ in 0..50 {
+                let (s, t) = (rng.next(), rng.next());
+                assert!(best <= (b.at(t) - a.at(s)).magnitude() + 1e-4);
+            }
+        }
+    }
+}
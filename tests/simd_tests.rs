@@ -0,0 +1,119 @@
+// Checks the operators that the `simd` feature backs with SSE2 against
+// scalar code. Passes with and without the feature.
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Matrix4f, Vector3f, Vector3d, Vector4f};
+    use std::mem::{align_of, size_of};
+    use crate::common::Lcg;
+
+    fn bits<const N: usize>(a: [f32; N]) -> [u32; N] {
+        a.map(f32::to_bits)
+    }
+
+    // Bound on the difference between two summation orders of the products.
+    fn dot_tolerance(products: &[f32]) -> f32 {
+        2.0 * f32::EPSILON * products.iter().map(|p| p.abs()).sum::<f32>()
+    }
+
+    #[test]
+    pub fn test_vector4_elementwise_bit_identical(){
+        let mut rng = Lcg(1);
+        for _ in 0..1000 {
+            let a = rng.vector4(-100.0, 100.0);
+            let b = rng.vector4(-100.0, 100.0);
+            let s = rng.range(-10.0, 10.0);
+            let (x, y) = (a.to_array(), b.to_array());
+
+            assert_eq!(bits((a + b).to_array()), bits([x[0] + y[0], x[1] + y[1], x[2] + y[2], x[3] + y[3]]));
+            assert_eq!(bits((a - b).to_array()), bits([x[0] - y[0], x[1] - y[1], x[2] - y[2], x[3] - y[3]]));
+            assert_eq!(bits((a * b).to_array()), bits([x[0] * y[0], x[1] * y[1], x[2] * y[2], x[3] * y[3]]));
+            assert_eq!(bits((a / b).to_array()), bits([x[0] / y[0], x[1] / y[1], x[2] / y[2], x[3] / y[3]]));
+            assert_eq!(bits((a * s).to_array()), bits([x[0] * s, x[1] * s, x[2] * s, x[3] * s]));
+
+            let mut c = a;
+            c += b;
+            assert_eq!(bits(c.to_array()), bits((a + b).to_array()));
+        }
+    }
+
+    #[test]
+    pub fn test_vector3_elementwise_bit_identical(){
+        let mut rng = Lcg(2);
+        for _ in 0..1000 {
+            let a = rng.vector(-100.0, 100.0);
+            let b = rng.vector(-100.0, 100.0);
+            let s = rng.range(-10.0, 10.0);
+            let (x, y) = (a.to_array(), b.to_array());
+
+            assert_eq!(bits((a + b).to_array()), bits([x[0] + y[0], x[1] + y[1], x[2] + y[2]]));
+            assert_eq!(bits((a - b).to_array()), bits([x[0] - y[0], x[1] - y[1], x[2] - y[2]]));
+            assert_eq!(bits((a * b).to_array()), bits([x[0] * y[0], x[1] * y[1], x[2] * y[2]]));
+            assert_eq!(bits((a / b).to_array()), bits([x[0] / y[0], x[1] / y[1], x[2] / y[2]]));
+            assert_eq!(bits((a * s).to_array()), bits([x[0] * s, x[1] * s, x[2] * s]));
+        }
+    }
+
+    #[test]
+    pub fn test_dot_within_tolerance(){
+        let mut rng = Lcg(3);
+        for _ in 0..1000 {
+            let a = rng.vector4(-100.0, 100.0);
+            let b = rng.vector4(-100.0, 100.0);
+            let p = (a * b).to_array();
+            let expected = p[0] + p[1] + p[2] + p[3];
+            assert!((a.dot(b) - expected).abs() <= dot_tolerance(&p));
+
+            let (a, b) = (a.truncate(), b.truncate());
+            let expected = p[0] + p[1] + p[2];
+            assert!((a.dot(b) - expected).abs() <= dot_tolerance(&p[..3]));
+        }
+    }
+
+    #[test]
+    pub fn test_transform_bit_identical(){
+        let mut rng = Lcg(4);
+        for _ in 0..1000 {
+            let m = rng.matrix4(-10.0, 10.0);
+            let v = rng.vector4(-100.0, 100.0).to_array();
+            let d = m.data;
+            let expected: [f32; 4] = std::array::from_fn(|i| {
+                d[4 * i] * v[0] + d[4 * i + 1] * v[1] + d[4 * i + 2] * v[2] + d[4 * i + 3] * v[3]
+            });
+            assert_eq!(bits(m.transform(Vector4f::from(v)).to_array()), bits(expected));
+        }
+    }
+
+    #[test]
+    pub fn test_mul_bit_identical(){
+        let mut rng = Lcg(5);
+        for _ in 0..1000 {
+            let a = rng.matrix4(-10.0, 10.0);
+            let b = rng.matrix4(-10.0, 10.0);
+            let (x, y) = (a.data, b.data);
+            let expected: [f32; 16] = std::array::from_fn(|k| {
+                let (i, j) = (k / 4, k % 4);
+                x[4 * i] * y[j] + x[4 * i + 1] * y[4 + j] + x[4 * i + 2] * y[8 + j] + x[4 * i + 3] * y[12 + j]
+            });
+            assert_eq!(bits((a * b).data), bits(expected));
+
+            let mut c = a;
+            c *= b;
+            assert_eq!(bits(c.data), bits(expected));
+        }
+    }
+
+    // vertex and uniform buffers rely on the layout, so the feature must
+    // leave it alone
+    #[test]
+    pub fn test_layout_unchanged(){
+        assert_eq!(size_of::<Vector3f>(), 12);
+        assert_eq!(align_of::<Vector3f>(), 4);
+        assert_eq!(size_of::<Vector3d>(), 24);
+        assert_eq!(size_of::<Vector4f>(), 16);
+        assert_eq!(align_of::<Vector4f>(), 4);
+        assert_eq!(size_of::<Matrix4f>(), 64);
+        assert_eq!(align_of::<Matrix4f>(), 4);
+    }
+}
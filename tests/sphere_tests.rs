@@ -0,0 +1,142 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Spheref, Sphered, Aabbf};
+    use ember_math::core::{Vector3f, Vector3d, Matrix4f, Quaternion, Rad};
+    use ember_math::assert_approx_eq;
+    use crate::common::Lcg;
+
+    static EPS:f32 = 1e-5;
+
+    pub fn encloses(s: &Spheref, points: &[Vector3f]) -> bool {
+        points.iter().all(|&p| (p - s.center).magnitude() <= s.radius * (1.0 + 1e-4) + 1e-4)
+    }
+
+    #[test]
+    pub fn test_queries(){
+        let s = Spheref::new(Vector3f::new(1.0, 0.0, 0.0), 2.0);
+        assert!(s.contains_point(Vector3f::new(3.0, 0.0, 0.0)));
+        assert!(!s.contains_point(Vector3f::new(2.0, 2.0, 0.0)));
+        assert!(s.contains(&Spheref::new(Vector3f::new(0.0, 0.0, 0.0), 1.0)));
+        assert!(!s.contains(&Spheref::new(Vector3f::new(-0.5, 0.0, 0.0), 1.0)));
+        assert!(s.intersects(&Spheref::new(Vector3f::new(4.0, 0.0, 0.0), 1.0)));
+        assert!(!s.intersects(&Spheref::new(Vector3f::new(4.1, 0.0, 0.0), 1.0)));
+
+        let b = Aabbf::new(Vector3f::new(2.5, 1.0, -1.0), Vector3f::new(4.0, 3.0, 1.0));
+        assert!(s.intersects_aabb(&b));
+        assert!(!Spheref::new(Vector3f::new(1.0, -1.0, 0.0), 2.0).intersects_aabb(&b));
+        assert!(!s.intersects_aabb(&Aabbf::empty()));
+        assert_eq!(s.to_aabb(), Aabbf::new(Vector3f::new(-1.0, -2.0, -2.0), Vector3f::new(3.0, 2.0, 2.0)));
+
+        let grown = s.expand_to_point(Vector3f::new(7.0, 0.0, 0.0));
+        assert_approx_eq!(grown, Spheref::new(Vector3f::new(3.0, 0.0, 0.0), 4.0), epsilon = EPS);
+        assert_eq!(s.expand_to_point(Vector3f::new(2.0, 0.0, 0.0)), s);
+    }
+
+    #[test]
+    pub fn test_minimal_known(){
+        assert!(Spheref::from_points_minimal(&[]).is_none());
+        assert!(Spheref::from_points_ritter(&[]).is_none());
+
+        let p = Vector3f::new(1.0, 2.0, 3.0);
+        assert_eq!(Spheref::from_points_minimal(&[p, p, p]).unwrap(), Spheref::new(p, 0.0));
+
+        // an obtuse triangle is bounded by its longest edge
+        let s = Spheref::from_points_minimal(&[
+            Vector3f::new(-2.0, 0.0, 0.0),
+            Vector3f::new(2.0, 0.0, 0.0),
+            Vector3f::new(0.5, 0.5, 0.0),
+        ]).unwrap();
+        assert_approx_eq!(s, Spheref::new(Vector3f::zero(), 2.0), epsilon = EPS);
+
+        // collinear and coplanar points
+        let line = [0.0, 3.0, 1.0, -1.0, 2.0].map(|t| Vector3f::new(t, t, 0.0));
+        let s = Spheref::from_points_minimal(&line).unwrap();
+        assert_approx_eq!(s, Spheref::new(Vector3f::new(1.0, 1.0, 0.0), 2.0 * 2.0f32.sqrt()), epsilon = EPS);
+        let square = [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0), (0.5, 0.0)].map(|(x, y)| Vector3f::new(x, y, 4.0));
+        let s = Spheref::from_points_minimal(&square).unwrap();
+        assert_approx_eq!(s, Spheref::new(Vector3f::new(0.0, 0.0, 4.0), 2.0f32.sqrt()), epsilon = EPS);
+
+        // the corners of a regular tetrahedron lie on its circumsphere
+        let tetra = [
+            Vector3f::new(1.0, 1.0, 1.0),
+            Vector3f::new(1.0, -1.0, -1.0),
+            Vector3f::new(-1.0, 1.0, -1.0),
+            Vector3f::new(-1.0, -1.0, 1.0),
+            Vector3f::new(0.2, -0.1, 0.3),
+        ];
+        let s = Spheref::from_points_minimal(&tetra).unwrap();
+        assert_approx_eq!(s, Spheref::new(Vector3f::zero(), 3.0f32.sqrt()), epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_fitting_random(){
+        let mut rng = Lcg(7);
+        for _ in 0..200 {
+            let center = rng.vector(-50.0, 50.0);
+            let count = 1 + (rng.next() * 60.0) as usize;
+            let points = (0..count).map(|_| center + rng.vector(-5.0, 5.0)).collect::<Vec<_>>();
+            let ritter = Spheref::from_points_ritter(&points).unwrap();
+            let minimal = Spheref::from_points_minimal(&points).unwrap();
+            assert!(encloses(&ritter, &points));
+            assert!(encloses(&minimal, &points));
+            assert!(minimal.radius <= ritter.radius * (1.0 + 1e-5));
+            // no enclosing sphere fits inside a smaller one around the
+            // farthest pair
+            let diameter = points.iter()
+                .flat_map(|&a| points.iter().map(move |&b| (a - b).magnitude()))
+                .fold(0.0f32, f32::max);
+            assert!(minimal.radius >= diameter * 0.5 - 1e-4);
+        }
+    }
+
+    #[test]
+    pub fn test_minimal_on_surface(){
+        // every point is on the boundary, the worst case for support updates
+        let mut rng = Lcg(11);
+        let center = Vector3d::new(3.0, -2.0, 1.0);
+        let mut points = Vec::new();
+        while points.len() < 500 {
+            let v = rng.vector(-1.0, 1.0);
+            let m = v.magnitude();
+            if m > 0.1 && m <= 1.0 {
+                let v = Vector3d::new(v.x as f64, v.y as f64, v.z as f64).normalize();
+                points.push(center + v * 4.0);
+                points.push(center - v * 4.0);
+            }
+        }
+        let s = Sphered::from_points_minimal(&points).unwrap();
+        assert_approx_eq!(s, Sphered::new(center, 4.0), epsilon = 1e-9);
+    }
+
+    #[test]
+    pub fn test_minimal_sorted_input(){
+        // each point lies outside the sphere around the ones before it
+        let mut points = (0..2000)
+            .map(|i| {
+                let t = i as f32 * 0.01;
+                Vector3f::new(t, t.sin(), t.cos()) * t
+            })
+            .collect::<Vec<_>>();
+        let forward = Spheref::from_points_minimal(&points).unwrap();
+        assert!(encloses(&forward, &points));
+        points.reverse();
+        let backward = Spheref::from_points_minimal(&points).unwrap();
+        assert!(encloses(&backward, &points));
+        assert!((backward.radius - forward.radius).abs() <= forward.radius * 1e-5);
+    }
+
+    #[test]
+    pub fn test_transform(){
+        let s = Spheref::new(Vector3f::new(1.0, 2.0, 3.0), 2.0);
+        let q = Quaternion::from_axis_angle(Vector3f::new(0.0, 1.0, 0.0), Rad(std::f32::consts::FRAC_PI_2));
+        let m = Matrix4f::from_translation(Vector3f::new(0.0, 0.0, -1.0)) * q.to_matrix4() * Matrix4f::from_scale(3.0);
+        let moved = s.transform(&m);
+        assert_approx_eq!(moved, Spheref::new(Vector3f::new(9.0, 6.0, -4.0), 6.0), epsilon = 1e-4);
+
+        // non-uniform scales bound the ellipsoid by its longest axis
+        let stretched = s.transform(&Matrix4f::from_scale_vec(Vector3f::new(1.0, 5.0, 2.0)));
+        assert_approx_eq!(stretched, Spheref::new(Vector3f::new(1.0, 10.0, 6.0), 10.0), epsilon = EPS);
+    }
+}
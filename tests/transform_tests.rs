@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Transform, GlobalTransform, Parent, Children, TransformBundle};
+    use ember_math::core::{Vector3f, Matrix4f, Quaternion, Rad};
+    use ember_math::core::transform::{add_transform_systems, set_parent, remove_parent};
+    use ember_math::assert_approx_eq;
+    use bevy_ecs::prelude::{Schedule, World, Entity};
+
+    static EPS:f32 = 1e-5;
+
+    pub fn run(world: &mut World) {
+        let mut schedule = Schedule::default();
+        add_transform_systems(&mut schedule);
+        schedule.run(world);
+    }
+
+    pub fn global(world: &World, e: Entity) -> GlobalTransform {
+        *world.get::<GlobalTransform>(e).unwrap()
+    }
+
+    #[test]
+    pub fn test_transform(){
+        let t = Transform::identity()
+            .with_translation(Vector3f::new(1.0, 2.0, 3.0))
+            .with_rotation(Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(std::f32::consts::FRAC_PI_2)))
+            .with_scale(Vector3f::new(2.0, 2.0, 2.0));
+        let p = Vector3f::new(1.0, 0.0, 0.0);
+        assert_approx_eq!(t.transform_point(p), Vector3f::new(1.0, 4.0, 3.0), epsilon = EPS);
+        assert_approx_eq!(GlobalTransform::from(t).transform_point(p), t.transform_point(p), epsilon = EPS);
+
+        let back = Transform::from_matrix(&t.compute_matrix());
+        assert_approx_eq!(back.translation, t.translation, epsilon = EPS);
+        assert_approx_eq!(back.rotation, t.rotation, epsilon = EPS);
+        assert_approx_eq!(back.scale, t.scale, epsilon = EPS);
+
+        assert_eq!(Transform::default(), Transform::identity());
+        assert_eq!(GlobalTransform::default().matrix(), Matrix4f::identity());
+    }
+
+    #[test]
+    pub fn test_propagate_root(){
+        let mut world = World::new();
+        let root = world.spawn(TransformBundle::from(Transform::identity())).id();
+        world.get_mut::<Transform>(root).unwrap().translation = Vector3f::new(5.0, 0.0, 0.0);
+
+        run(&mut world);
+        assert_eq!(global(&world, root).translation(), Vector3f::new(5.0, 0.0, 0.0));
+    }
+
+    #[test]
+    pub fn test_propagate_hierarchy(){
+        let mut world = World::new();
+        let quarter = Quaternion::from_axis_angle(Vector3f::new(0.0, 0.0, 1.0), Rad(std::f32::consts::FRAC_PI_2));
+
+        let root = world.spawn(TransformBundle::from(
+            Transform::from_translation(Vector3f::new(10.0, 0.0, 0.0)).with_rotation(quarter)
+        )).id();
+        let child = world.spawn(TransformBundle::from(
+            Transform::from_translation(Vector3f::new(1.0, 0.0, 0.0)).with_scale(Vector3f::new(2.0, 2.0, 2.0))
+        )).id();
+        let grandchild = world.spawn(TransformBundle::from(
+            Transform::from_translation(Vector3f::new(0.0, 1.0, 0.0))
+        )).id();
+        set_parent(&mut world, child, root);
+        set_parent(&mut world, grandchild, child);
+
+        run(&mut world);
+        // root rotates the child's +x offset onto +y
+        assert_approx_eq!(global(&world, child).translation(), Vector3f::new(10.0, 1.0, 0.0), epsilon = EPS);
+        // the child's scale doubles the grandchild's offset, and +y maps to -x
+        assert_approx_eq!(global(&world, grandchild).translation(), Vector3f::new(8.0, 1.0, 0.0), epsilon = EPS);
+        assert_approx_eq!(
+            global(&world, grandchild).transform_point(Vector3f::new(1.0, 0.0, 0.0)),
+            Vector3f::new(8.0, 3.0, 0.0),
+            epsilon = EPS
+        );
+
+        // moving the root moves the whole subtree on the next run
+        world.get_mut::<Transform>(root).unwrap().translation = Vector3f::zero();
+        run(&mut world);
+        assert_approx_eq!(global(&world, grandchild).translation(), Vector3f::new(-2.0, 1.0, 0.0), epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_reparent(){
+        let mut world = World::new();
+        let a = world.spawn(TransformBundle::from(Transform::from_translation(Vector3f::new(1.0, 0.0, 0.0)))).id();
+        let b = world.spawn(TransformBundle::from(Transform::from_translation(Vector3f::new(0.0, 5.0, 0.0)))).id();
+        let c = world.spawn(TransformBundle::from(Transform::from_translation(Vector3f::new(0.0, 0.0, 1.0)))).id();
+
+        set_parent(&mut world, c, a);
+        assert_eq!(world.get::<Parent>(c), Some(&Parent(a)));
+        assert_eq!(world.get::<Children>(a), Some(&Children(vec![c])));
+        run(&mut world);
+        assert_approx_eq!(global(&world, c).translation(), Vector3f::new(1.0, 0.0, 1.0), epsilon = EPS);
+
+        set_parent(&mut world, c, b);
+        assert_eq!(world.get::<Children>(a), Some(&Children(vec![])));
+        run(&mut world);
+        assert_approx_eq!(global(&world, c).translation(), Vector3f::new(0.0, 5.0, 1.0), epsilon = EPS);
+
+        remove_parent(&mut world, c);
+        assert!(world.get::<Parent>(c).is_none());
+        run(&mut world);
+        assert_approx_eq!(global(&world, c).translation(), Vector3f::new(0.0, 0.0, 1.0), epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_missing_global_transform(){
+        let mut world = World::new();
+        // the middle entity has no GlobalTransform but still passes its
+        // transform down
+        let root = world.spawn(TransformBundle::from(Transform::from_translation(Vector3f::new(1.0, 0.0, 0.0)))).id();
+        let middle = world.spawn(Transform::from_translation(Vector3f::new(0.0, 1.0, 0.0))).id();
+        let leaf = world.spawn(TransformBundle::default()).id();
+        set_parent(&mut world, middle, root);
+        set_parent(&mut world, leaf, middle);
+
+        run(&mut world);
+        assert!(world.get::<GlobalTransform>(middle).is_none());
+        assert_approx_eq!(global(&world, leaf).translation(), Vector3f::new(1.0, 1.0, 0.0), epsilon = EPS);
+    }
+}
\ No newline at end of file
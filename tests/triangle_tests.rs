@@ -0,0 +1,134 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ember_math::core::{Trianglef, Triangle2f, Aabbf, Aabb2f, MathError};
+    use ember_math::core::{Vector2f, Vector3f};
+    use ember_math::assert_approx_eq;
+    use crate::common::Lcg;
+
+    static EPS:f32 = 1e-5;
+
+    // right triangle in the plane z = 1, counter-clockwise from +z
+    pub fn tri() -> Trianglef {
+        Trianglef::new(Vector3f::new(0.0, 0.0, 1.0), Vector3f::new(4.0, 0.0, 1.0), Vector3f::new(0.0, 2.0, 1.0))
+    }
+
+    #[test]
+    pub fn test_area_and_normal(){
+        let t = tri();
+        assert_approx_eq!(t.area(), 4.0, epsilon = EPS);
+        assert_approx_eq!(t.normal(), Vector3f::new(0.0, 0.0, 1.0), epsilon = EPS);
+        let flipped = Trianglef::new(t.a, t.c, t.b);
+        assert_approx_eq!(flipped.normal(), Vector3f::new(0.0, 0.0, -1.0), epsilon = EPS);
+        assert_approx_eq!(t.try_normal().unwrap(), t.normal(), epsilon = EPS);
+        assert_approx_eq!(t.centroid(), Vector3f::new(4.0 / 3.0, 2.0 / 3.0, 1.0), epsilon = EPS);
+
+        let line = Trianglef::new(Vector3f::zero(), Vector3f::new(1.0, 1.0, 1.0), Vector3f::new(3.0, 3.0, 3.0));
+        assert_eq!(line.try_normal(), Err(MathError::DegenerateGeometry));
+        assert_eq!(line.try_barycentric(Vector3f::zero()), Err(MathError::DegenerateGeometry));
+        assert_approx_eq!(line.area(), 0.0, epsilon = EPS);
+    }
+
+    #[test]
+    pub fn test_barycentric(){
+        let t = tri();
+        assert_approx_eq!(t.barycentric(t.a), Vector3f::new(1.0, 0.0, 0.0), epsilon = EPS);
+        assert_approx_eq!(t.barycentric(t.b), Vector3f::new(0.0, 1.0, 0.0), epsilon = EPS);
+        assert_approx_eq!(t.barycentric(t.c), Vector3f::new(0.0, 0.0, 1.0), epsilon = EPS);
+        assert_approx_eq!(t.barycentric(t.centroid()), Vector3f::one() / 3.0, epsilon = EPS);
+        // off the plane, the weights are those of the projection
+        assert_approx_eq!(t.barycentric(Vector3f::new(2.0, 0.5, 7.0)), Vector3f::new(0.25, 0.5, 0.25), epsilon = EPS);
+
+        let w = Vector3f::new(0.2, -0.3, 1.1);
+        assert_approx_eq!(t.barycentric(t.point_from_barycentric(w)), w, epsilon = EPS);
+        assert_approx_eq!(t.try_barycentric(t.point_from_barycentric(w)).unwrap(), w, epsilon = EPS);
+
+        assert!(t.contains_point(Vector3f::new(1.0, 1.0, -3.0)));
+        assert!(t.contains_point(Vector3f::new(2.0, 0.0, 1.0)));
+        assert!(!t.contains_point(Vector3f::new(3.0, 1.0, 1.0)));
+        assert!(!t.contains_point(Vector3f::new(-0.1, 1.0, 1.0)));
+    }
+
+    #[test]
+    pub fn test_closest_point(){
+        let t = tri();
+        // each corner, edge and face region
+        assert_approx_eq!(t.closest_point(Vector3f::new(-1.0, -1.0, 0.0)), t.a, epsilon = EPS);
+        assert_approx_eq!(t.closest_point(Vector3f::new(6.0, -1.0, 3.0)), t.b, epsilon = EPS);
+        assert_approx_eq!(t.closest_point(Vector3f::new(-1.0, 3.0, 1.0)), t.c, epsilon = EPS);
+        assert_approx_eq!(t.closest_point(Vector3f::new(2.0, -3.0, 1.0)), Vector3f::new(2.0, 0.0, 1.0), epsilon = EPS);
+        assert_approx_eq!(t.closest_point(Vector3f::new(-2.0, 1.0, 5.0)), Vector3f::new(0.0, 1.0, 1.0), epsilon = EPS);
+        assert_approx_eq!(t.closest_point(Vector3f::new(4.0, 2.0, 1.0)), Vector3f::new(3.2, 0.4, 1.0), epsilon = EPS);
+        assert_approx_eq!(t.closest_point(Vector3f::new(1.0, 1.0, -2.0)), Vector3f::new(1.0, 1.0, 1.0), epsilon = EPS);
+
+        // no point of the triangle is closer than the one found
+        let mut rng = Lcg(3);
+        for _ in 0..200 {
+            let t = Trianglef::new(rng.vector(-5.0, 5.0), rng.vector(-5.0, 5.0), rng.vector(-5.0, 5.0));
+            let p = rng.vector(-10.0, 10.0);
+            let q = t.closest_point(p);
+            assert!(t.barycentric(q).iter().all(|w| w >= -1e-3));
+            let best = (p - q).magnitude();
+            for _ in 0..50 {
+                let (u, v) = (rng.next(), rng.next());
+                let (u, v) = if u + v > 1.0 { (1.0 - u, 1.0 - v) } else { (u, v) };
+                let s = t.point_from_barycentric(Vector3f::new(1.0 - u - v, u, v));
+                assert!(best <= (p - s).magnitude() + 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_intersects_aabb(){
+        let unit = Aabbf::new(Vector3f::new(-1.0, -1.0, -1.0), Vector3f::new(1.0, 1.0, 1.0));
+        assert!(tri().intersects_aabb(&unit));
+        assert!(!tri().intersects_aabb(&Aabbf::new(Vector3f::new(-1.0, -1.0, -1.0), Vector3f::new(1.0, 1.0, 0.9))));
+        assert!(!tri().intersects_aabb(&Aabbf::empty()));
+
+        // a large triangle slicing through the box with every corner outside
+        let big = Trianglef::new(Vector3f::new(-10.0, -10.0, 0.5), Vector3f::new(10.0, -10.0, 0.0), Vector3f::new(0.0, 10.0, -0.5));
+        assert!(big.intersects_aabb(&unit));
+
+        // the box's bounds overlap the triangle's, but its plane misses
+        let slanted = Trianglef::new(Vector3f::new(3.3, 0.0, 0.0), Vector3f::new(0.0, 3.3, 0.0), Vector3f::new(0.0, 0.0, 3.3));
+        assert!(!slanted.intersects_aabb(&unit));
+        assert!(slanted.intersects_aabb(&unit.expand(0.11)));
+
+        // near a box edge, separated only by an edge cross product
+        let corner = Trianglef::new(Vector3f::new(2.2, 0.0, 0.0), Vector3f::new(0.0, 2.2, 0.0), Vector3f::new(3.0, 3.0, 5.0));
+        assert!(!corner.intersects_aabb(&unit));
+        let corner = Trianglef::new(Vector3f::new(1.8, 0.0, 0.0), Vector3f::new(0.0, 1.8, 0.0), Vector3f::new(3.0, 3.0, 5.0));
+        assert!(corner.intersects_aabb(&unit));
+    }
+
+    #[test]
+    pub fn test_triangle2(){
+        let t = Triangle2f::new(Vector2f::new(0.0, 0.0), Vector2f::new(1.0, 0.0), Vector2f::new(0.0, 1.0));
+        assert_approx_eq!(t.signed_area(), 0.5, epsilon = EPS);
+        assert_approx_eq!(Triangle2f::new(t.a, t.c, t.b).signed_area(), -0.5, epsilon = EPS);
+        assert_approx_eq!(Triangle2f::new(t.a, t.c, t.b).area(), 0.5, epsilon = EPS);
+
+        let uv = Vector2f::new(0.25, 0.5);
+        let w = t.barycentric(uv);
+        assert_approx_eq!(w, Vector3f::new(0.25, 0.25, 0.5), epsilon = EPS);
+        assert_approx_eq!(t.point_from_barycentric(w), uv, epsilon = EPS);
+        assert!(t.contains_point(uv));
+        assert!(t.contains_point(Vector2f::new(0.5, 0.5)));
+        assert!(!t.contains_point(Vector2f::new(0.6, 0.6)));
+
+        assert_approx_eq!(t.closest_point(Vector2f::new(1.0, 1.0)), Vector2f::new(0.5, 0.5), epsilon = EPS);
+        assert_approx_eq!(t.closest_point(Vector2f::new(-1.0, 0.5)), Vector2f::new(0.0, 0.5), epsilon = EPS);
+        assert_approx_eq!(t.closest_point(Vector2f::new(2.0, -1.0)), t.b, epsilon = EPS);
+        assert_approx_eq!(t.closest_point(uv), uv, epsilon = EPS);
+
+        let degenerate = Triangle2f::new(t.a, t.b, t.b * 2.0);
+        assert_eq!(degenerate.try_barycentric(uv), Err(MathError::DegenerateGeometry));
+
+        assert!(t.intersects_aabb(&Aabb2f::new(Vector2f::new(0.4, 0.4), Vector2f::new(1.0, 1.0))));
+        assert!(!t.intersects_aabb(&Aabb2f::new(Vector2f::new(0.6, 0.6), Vector2f::new(1.0, 1.0))));
+        assert!(t.intersects_aabb(&Aabb2f::new(Vector2f::new(-1.0, -1.0), Vector2f::new(2.0, 2.0))));
+        assert!(!t.intersects_aabb(&Aabb2f::new(Vector2f::new(1.1, 0.0), Vector2f::new(2.0, 1.0))));
+        assert!(!t.intersects_aabb(&Aabb2f::empty()));
+    }
+}
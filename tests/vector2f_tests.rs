@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
     use ember_math::core::Vector2f;
+    use ember_math::core::{Vector2d, Vector2i, Vector2u};
+    use ember_math::core::MathError;
+    use ember_math::core::{Vector3f, Vector4f};
 
     static EPS:f32 = 0.0000001;
     static PI:f32 = std::f32::consts::PI;
@@ -21,6 +24,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     pub fn test_vector2f_magnitude(){
         let a = Vector2f::new(1.0, 0.0);
         let b = Vector2f::new(1.0, 1.0);
@@ -71,6 +75,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     pub fn test_normalize(){
         let a = Vector2f::new(100.0, 0.0);
         let b = Vector2f::new(0.0, 100.0);
@@ -102,4 +107,113 @@ mod tests {
         let b = Vector2f::new(-1.0, 1.0);
         assert_eq!(a+b, Vector2f::new(0.0, 0.0));
     }
+
+    #[test]
+    pub fn test_try_normalize(){
+        let a = Vector2f::new(3.0, 4.0);
+        assert_eq!(a.try_normalize(), Ok(a.normalize()));
+        assert_eq!(Vector2f::new(0.0, 0.0).try_normalize(), Err(MathError::ZeroLengthVector));
+    }
+
+    #[test]
+    pub fn test_neg(){
+        let a = Vector2f::new(1.0, -2.0);
+        assert_eq!(-a, Vector2f::new(-1.0, 2.0));
+        assert_eq!(-&a, Vector2f::new(-1.0, 2.0));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_scalar_ops(){
+        let a = Vector2f::new(1.0, -2.0);
+        assert_eq!(a * 2.0, Vector2f::new(2.0, -4.0));
+        assert_eq!(2.0 * a, Vector2f::new(2.0, -4.0));
+        assert_eq!(a / 2.0, Vector2f::new(0.5, -1.0));
+        assert_eq!(&a * 2.0, a.scale(2.0));
+        assert_eq!(2.0 * &a, a.scale(2.0));
+
+        let mut b = a;
+        b *= 3.0;
+        assert_eq!(b, Vector2f::new(3.0, -6.0));
+        b /= 3.0;
+        assert_eq!(b, a);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_componentwise_ops(){
+        let a = Vector2f::new(2.0, -3.0);
+        let b = Vector2f::new(4.0, 6.0);
+        assert_eq!(a * b, Vector2f::new(8.0, -18.0));
+        assert_eq!(b / a, Vector2f::new(2.0, -2.0));
+
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+        c /= &b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_ref_ops(){
+        let a = Vector2f::new(1.0, 2.0);
+        let b = Vector2f::new(3.0, -1.0);
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(a - &b, a - b);
+        assert_eq!(&a * b, a * b);
+        assert_eq!(&a / &b, a / b);
+
+        let mut c = a;
+        c += &b;
+        c -= &a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    pub fn test_other_precisions(){
+        let a = Vector2d::new(3.0, 4.0);
+        assert_eq!(a.magnitude(), 5.0);
+        assert_eq!(Vector2d::from(Vector2f::new(0.5, 1.5)), Vector2d::new(0.5, 1.5));
+
+        let b = Vector2i::new(-3, 7);
+        assert_eq!(b * Vector2i::new(2, 2), Vector2i::new(-6, 14));
+        assert_eq!(b.cast::<f32>(), Vector2f::new(-3.0, 7.0));
+        assert_eq!(Vector2u::new(2, 3).dot(Vector2u::new(4, 5)), 23);
+    }
+
+    #[test]
+    pub fn test_swizzle(){
+        let a = Vector2f::new(1.0, 2.0);
+        assert_eq!(a.yx(), Vector2f::new(2.0, 1.0));
+        assert_eq!(a.xxy(), Vector3f::new(1.0, 1.0, 2.0));
+        assert_eq!(a.yyxy(), Vector4f::new(2.0, 2.0, 1.0, 2.0));
+        assert_eq!(a.extend(3.0), Vector3f::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    pub fn test_components(){
+        let mut a = Vector2i::new(4, 5);
+        assert_eq!(a[0], 4);
+        assert_eq!(a[1], 5);
+        a[1] = 7;
+        assert_eq!(a, Vector2i::new(4, 7));
+
+        assert_eq!(Vector2i::from([1, 2]), Vector2i::new(1, 2));
+        assert_eq!(Vector2i::from((1, 2)), Vector2i::new(1, 2));
+        let t: (i32, i32) = a.into();
+        assert_eq!(t, (4, 7));
+        assert_eq!(a.iter().sum::<i32>(), 11);
+        for c in &mut a {
+            *c *= 2;
+        }
+        assert_eq!(a.to_array(), [8, 14]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector2 has 2 components but the index is 2")]
+    pub fn test_index_out_of_bounds(){
+        let a = Vector2f::new(1.0, 2.0);
+        let _ = a[2];
+    }
 }
\ No newline at end of file
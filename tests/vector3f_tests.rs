@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod tests {
     use ember_math::core::Vector3f;
+    use ember_math::core::{Vector3, Vector3d, Vector3i, Vector3u};
+    use std::collections::HashSet;
+    use ember_math::core::MathError;
+    use ember_math::core::{Vector2f, Vector2i, Vector4f};
 
     static EPS:f32 = 0.0000001;
 
@@ -78,6 +82,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     pub fn test_normalize(){
         let a = Vector3f::new(100.0, 0.0, 0.0);
         let b = Vector3f::new(0.0, 100.0, 0.0);
@@ -113,4 +118,162 @@ mod tests {
         assert_eq!(c.y, 1.5);
         assert_eq!(c.z, 0.9);
     }
-}
\ No newline at end of file
+
+    #[test]
+    pub fn test_try_normalize(){
+        let a = Vector3f::new(0.0, 3.0, 4.0);
+        assert_eq!(a.try_normalize(), Ok(a.normalize()));
+        assert_eq!(Vector3f::new(0.0, 0.0, 0.0).try_normalize(), Err(MathError::ZeroLengthVector));
+    }
+
+    #[test]
+    pub fn test_neg(){
+        let a = Vector3f::new(1.0, -2.0, 3.0);
+        assert_eq!(-a, Vector3f::new(-1.0, 2.0, -3.0));
+        assert_eq!(-&a, Vector3f::new(-1.0, 2.0, -3.0));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_scalar_ops(){
+        let a = Vector3f::new(1.0, -2.0, 4.0);
+        assert_eq!(a * 2.0, Vector3f::new(2.0, -4.0, 8.0));
+        assert_eq!(2.0 * a, Vector3f::new(2.0, -4.0, 8.0));
+        assert_eq!(a / 2.0, Vector3f::new(0.5, -1.0, 2.0));
+        assert_eq!(&a * 2.0, a.scale(2.0));
+        assert_eq!(2.0 * &a, a.scale(2.0));
+
+        let mut b = a;
+        b *= 3.0;
+        assert_eq!(b, Vector3f::new(3.0, -6.0, 12.0));
+        b /= 3.0;
+        assert_eq!(b, a);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_componentwise_ops(){
+        let a = Vector3f::new(2.0, -3.0, 1.0);
+        let b = Vector3f::new(4.0, 6.0, -5.0);
+        assert_eq!(a * b, Vector3f::new(8.0, -18.0, -5.0));
+        assert_eq!(b / a, Vector3f::new(2.0, -2.0, -5.0));
+
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+        c /= &b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_ref_ops(){
+        let a = Vector3f::new(1.0, 2.0, 3.0);
+        let b = Vector3f::new(3.0, -1.0, 0.5);
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(a - &b, a - b);
+        assert_eq!(&a * b, a * b);
+        assert_eq!(&a / &b, a / b);
+
+        let mut c = a;
+        c += &b;
+        c -= &a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    pub fn test_vector3d(){
+        let a = Vector3d::new(1.0e9, 2.0, 3.0);
+        let b = Vector3d::new(0.5, -1.0, 0.25);
+        // 1e9 + 0.5 is not representable in f32
+        assert_eq!((a + b).x, 1_000_000_000.5);
+        assert_eq!(a.cross(b), Vector3d::new(3.5, 1.5 - 2.5e8, -1.0e9 - 1.0));
+        assert!((Vector3d::new(3.0, 4.0, 0.0).normalize().magnitude() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    pub fn test_vector3i(){
+        let a = Vector3i::new(1, -2, 3);
+        let b = Vector3i::new(4, 5, -6);
+        assert_eq!(a + b, Vector3i::new(5, 3, -3));
+        assert_eq!(a.dot(b), -24);
+        assert_eq!(a.cross(b), Vector3i::new(-3, 18, 13));
+        assert_eq!(-a, Vector3i::new(-1, 2, -3));
+        assert_eq!(2 * a, Vector3i::new(2, -4, 6));
+        assert_eq!(b / 2, Vector3i::new(2, 2, -3));
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        set.insert(Vector3i::new(1, -2, 3));
+        set.insert(b);
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Vector3i::new(4, 5, -6)));
+    }
+
+    #[test]
+    pub fn test_vector3u(){
+        let a = Vector3u::new(1, 2, 3);
+        assert_eq!(a * 3, Vector3u::new(3, 6, 9));
+        assert_eq!(a.magnitude_squared(), 14);
+        assert_eq!(Vector3u::one() + Vector3u::zero(), Vector3u::new(1, 1, 1));
+    }
+
+    #[test]
+    pub fn test_conversions(){
+        let a = Vector3f::new(1.75, -2.5, 3.0);
+        let d: Vector3d = a.into();
+        assert_eq!(d, Vector3d::new(1.75, -2.5, 3.0));
+        assert_eq!(Vector3d::from(Vector3i::new(-1, 2, 3)), Vector3d::new(-1.0, 2.0, 3.0));
+        assert_eq!(Vector3d::from(Vector3u::new(1, 2, 3)), Vector3d::new(1.0, 2.0, 3.0));
+
+        // cast truncates toward zero and saturates like `as`
+        assert_eq!(a.cast::<i32>(), Vector3i::new(1, -2, 3));
+        assert_eq!(a.cast::<u32>(), Vector3u::new(1, 0, 3));
+        assert_eq!(d.cast::<f32>(), a);
+        let v: Vector3<f32> = Vector3i::new(7, 8, 9).cast();
+        assert_eq!(v, Vector3f::new(7.0, 8.0, 9.0));
+    }
+
+    #[test]
+    pub fn test_swizzle(){
+        let a = Vector3f::new(1.0, 2.0, 3.0);
+        assert_eq!(a.xy(), Vector2f::new(1.0, 2.0));
+        assert_eq!(a.xz(), Vector2f::new(1.0, 3.0));
+        assert_eq!(a.zyx(), Vector3f::new(3.0, 2.0, 1.0));
+        assert_eq!(a.zzzz(), Vector4f::new(3.0, 3.0, 3.0, 3.0));
+        assert_eq!(a.xyzx(), Vector4f::new(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(a.truncate(), a.xy());
+        assert_eq!(Vector3i::new(1, 2, 3).yz(), Vector2i::new(2, 3));
+    }
+
+    #[test]
+    pub fn test_components(){
+        let mut a = Vector3f::new(1.0, 2.0, 3.0);
+        assert_eq!((a[0], a[1], a[2]), (1.0, 2.0, 3.0));
+        a[2] = -3.0;
+        assert_eq!(a.z, -3.0);
+
+        assert_eq!(Vector3f::from([1.0, 2.0, 3.0]), Vector3f::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3f::from((1.0, 2.0, 3.0)), Vector3f::new(1.0, 2.0, 3.0));
+        let t: (f32, f32, f32) = a.into();
+        assert_eq!(t, (1.0, 2.0, -3.0));
+        let arr: [f32; 3] = a.into();
+        assert_eq!(arr, a.to_array());
+
+        assert_eq!(a.iter().fold(0.0, f32::max), 2.0);
+        assert_eq!(a.into_iter().collect::<Vec<f32>>(), vec![1.0, 2.0, -3.0]);
+        for c in a.iter_mut() {
+            *c = c.abs();
+        }
+        assert_eq!(a, Vector3f::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    pub fn test_min_max_abs(){
+        let a = Vector3f::new(1.0, -2.0, 3.0);
+        let b = Vector3f::new(-1.0, 4.0, 3.0);
+        assert_eq!(a.min(b), Vector3f::new(-1.0, -2.0, 3.0));
+        assert_eq!(a.max(b), Vector3f::new(1.0, 4.0, 3.0));
+        assert_eq!(a.abs(), Vector3f::new(1.0, 2.0, 3.0));
+    }
+}
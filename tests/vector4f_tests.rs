@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
     use ember_math::core::Vector4f;
+    use ember_math::core::{Vector4d, Vector4i, Vector4u};
+    use ember_math::core::MathError;
+    use ember_math::core::{Vector2f, Vector3f};
 
     static EPS:f32 = 0.0000001;
 
@@ -70,6 +73,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     pub fn test_normalize(){
         let a = Vector4f::new(100.0, 0.0, 0.0, 0.0);
         let b = Vector4f::new(0.0, 100.0, 0.0, 0.0);
@@ -86,4 +90,105 @@ mod tests {
         assert!((cn.z - 0.707107).abs() < EPS);
         assert!((cn.w - 0.707107).abs() < EPS);
     }
+
+    #[test]
+    pub fn test_try_normalize(){
+        let a = Vector4f::new(0.0, 3.0, 0.0, 4.0);
+        assert_eq!(a.try_normalize(), Ok(a.normalize()));
+        assert_eq!(Vector4f::new(0.0, 0.0, 0.0, 0.0).try_normalize(), Err(MathError::ZeroLengthVector));
+    }
+
+    #[test]
+    pub fn test_neg(){
+        let a = Vector4f::new(1.0, -2.0, 3.0, -4.0);
+        assert_eq!(-a, Vector4f::new(-1.0, 2.0, -3.0, 4.0));
+        assert_eq!(-&a, Vector4f::new(-1.0, 2.0, -3.0, 4.0));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_scalar_ops(){
+        let a = Vector4f::new(1.0, -2.0, 4.0, 0.5);
+        assert_eq!(a * 2.0, Vector4f::new(2.0, -4.0, 8.0, 1.0));
+        assert_eq!(2.0 * a, Vector4f::new(2.0, -4.0, 8.0, 1.0));
+        assert_eq!(a / 2.0, Vector4f::new(0.5, -1.0, 2.0, 0.25));
+        assert_eq!(&a * 2.0, a.scale(2.0));
+        assert_eq!(2.0 * &a, a.scale(2.0));
+
+        let mut b = a;
+        b *= 4.0;
+        assert_eq!(b, Vector4f::new(4.0, -8.0, 16.0, 2.0));
+        b /= 4.0;
+        assert_eq!(b, a);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_componentwise_ops(){
+        let a = Vector4f::new(2.0, -3.0, 1.0, 0.5);
+        let b = Vector4f::new(4.0, 6.0, -5.0, 2.0);
+        assert_eq!(a * b, Vector4f::new(8.0, -18.0, -5.0, 1.0));
+        assert_eq!(b / a, Vector4f::new(2.0, -2.0, -5.0, 4.0));
+
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+        c /= &b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    pub fn test_ref_ops(){
+        let a = Vector4f::new(1.0, 2.0, 3.0, 4.0);
+        let b = Vector4f::new(3.0, -1.0, 0.5, 2.0);
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(a - &b, a - b);
+        assert_eq!(&a * b, a * b);
+        assert_eq!(&a / &b, a / b);
+
+        let mut c = a;
+        c += &b;
+        c -= &a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    pub fn test_other_precisions(){
+        let a = Vector4d::new(1.0, 2.0, 2.0, 4.0);
+        assert_eq!(a.magnitude(), 5.0);
+        assert_eq!(Vector4d::from(Vector4f::new(0.5, 1.5, 2.5, 3.5)), Vector4d::new(0.5, 1.5, 2.5, 3.5));
+
+        let b = Vector4i::new(1, -1, 2, -2);
+        assert_eq!(-b, Vector4i::new(-1, 1, -2, 2));
+        assert_eq!(Vector4u::new(1, 2, 3, 4).cast::<i32>(), Vector4i::new(1, 2, 3, 4));
+        assert_eq!(Vector4f::new(-0.9, 0.9, 1.9, -1.9).cast::<i32>(), Vector4i::new(0, 0, 1, -1));
+    }
+
+    #[test]
+    pub fn test_swizzle(){
+        let a = Vector4f::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(a.xy(), Vector2f::new(1.0, 2.0));
+        assert_eq!(a.wz(), Vector2f::new(4.0, 3.0));
+        assert_eq!(a.xyz(), a.truncate());
+        assert_eq!(a.wzy(), Vector3f::new(4.0, 3.0, 2.0));
+        assert_eq!(a.wzyx(), Vector4f::new(4.0, 3.0, 2.0, 1.0));
+        assert_eq!(a.xyzw(), a);
+        assert_eq!(Vector4u::new(1, 2, 3, 4).wwxx(), Vector4u::new(4, 4, 1, 1));
+    }
+
+    #[test]
+    pub fn test_components(){
+        let mut a = Vector4f::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(a[3], 4.0);
+        a[0] += 10.0;
+        assert_eq!(a.x, 11.0);
+
+        assert_eq!(Vector4f::from([1.0, 2.0, 3.0, 4.0]), Vector4f::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Vector4f::from((1.0, 2.0, 3.0, 4.0)), Vector4f::new(1.0, 2.0, 3.0, 4.0));
+        let t: (f32, f32, f32, f32) = a.into();
+        assert_eq!(t, (11.0, 2.0, 3.0, 4.0));
+        assert_eq!(a.iter().map(|c| c * 2.0).collect::<Vec<f32>>(), vec![22.0, 4.0, 6.0, 8.0]);
+        assert_eq!((0..4).map(|i| a[i]).sum::<f32>(), 20.0);
+    }
 }
\ No newline at end of file